@@ -1,50 +1,129 @@
+use chrono::Local;
 use colored::Colorize;
 
-use super::super::utils::commands::parse_arg_or_get_from_input;
-use super::super::utils::sorting::{SortBy, SortDirection};
+use super::super::utils::allocation::build_allocation;
+use super::super::utils::commands::{
+    confirm_cash_spending, parse_arg_or_choose, parse_arg_or_get_from_input,
+    parse_valid_arg_or_get_from_input,
+};
+use super::super::utils::dashboard::build_dashboard;
+use super::super::utils::filtering::PositionsFilter;
+use super::super::utils::income_report::{IncomeReport, ReportGrouping, ReportPeriod};
+use super::super::utils::parser::CommandArgs;
+use super::super::utils::sorting::{parse_sorting_methods, SortBy, SortDirection, SORTING_METHODS};
+use super::super::utils::tax_report::TaxReport;
 use super::super::ChangeEditMode;
 use super::super::CommandResult;
+use super::PositionCommandManager;
 use crate::commands::ui::render;
 use crate::commands::utils::sorting::PositionsSorter;
-use crate::models::{Action, Order, Position};
+use crate::i18n::t;
+use crate::models::action::POSITION_TYPES;
+use crate::models::validation::{validate_name, validate_order_values, validate_positive};
+use crate::models::{Action, CashEntry, CashEntryKind, Order, Portfolio, Position, TargetWeight};
 use crate::options::get_options;
-use crate::storage::{load_storage, update_storage};
+use crate::storage::{
+    load_portfolios, load_storage, update_current_portfolio, update_storage,
+    NO_PORTFOLIO_SELECTED_ERROR,
+};
 use crate::utils::console::{
-    ask_confirmation, ask_for_input, clear_screen, wait_for_enter, ConfirmationStatus,
+    ask_confirmation, ask_for_choice, ask_for_input, ask_for_valid_input, clear_screen,
+    print_error, print_warning, wait_for_enter, ConfirmationStatus,
 };
+use crate::utils::line_editor::{get_argument_pair, Completions};
 use crate::utils::pagination::get_pages_count;
-use crate::{exit_with_error, storage};
+use crate::utils::theme::{Paint, Role};
+use crate::{exit_with_error, storage, tf};
 
 pub struct GlobalCommandManager {
     positions: Vec<Position>,
+    // None means that all accounts view is shown. Positions of the portfolio are kept
+    // in separate field, as only they are changed by commands.
+    portfolio: Option<Portfolio>,
     sorter: PositionsSorter,
+    filter: Option<PositionsFilter>,
+    // Saved filter, which failed to parse, is shown until filter is changed
+    filter_warning: Option<String>,
     page: i32,
 }
 
+// Commands, which are completed by tab in the REPL
+const COMMANDS: [&str; 24] = [
+    "a", "d", "e", "n", "p", "cs", "f", "pa", "ps", "pr", "pd", "dep", "wd", "cash", "margin", "r",
+    "tax", "ch", "db", "mp", "tw", "alloc", "h", "q",
+];
+
 impl GlobalCommandManager {
-    pub fn new(initial_positions: &Vec<Position>) -> GlobalCommandManager {
+    pub fn new(initial_positions: &[Position]) -> GlobalCommandManager {
         let storage = load_storage().expect("load storage");
         let options = get_options();
+        let current_portfolio = storage.current_portfolio().cloned();
+        let portfolio = current_portfolio
+            .clone()
+            .unwrap_or_else(|| Portfolio::new(-1, String::new()));
+        let (filter, filter_warning) = match portfolio.positions_filter {
+            Some(expression) => match PositionsFilter::parse(&expression, options.locale) {
+                Ok(filter) => (Some(filter), None),
+                Err(error) => (
+                    None,
+                    Some(tf!("warning.invalid_saved_filter", expression, error)),
+                ),
+            },
+            None => (None, None),
+        };
+
         GlobalCommandManager {
             positions: initial_positions.to_vec(),
+            portfolio: current_portfolio,
             sorter: PositionsSorter {
-                sort_by: storage.sort_positions_by,
+                sort_by: portfolio.sort_positions_by,
                 hide_closed: options.hide_closed_positions,
-                move_closed_to_bottom: storage.move_closed_positions_to_bottom,
+                then_sort_by: portfolio.then_sort_positions_by,
+                move_closed_to_bottom: portfolio.move_closed_positions_to_bottom,
             },
+            filter,
+            filter_warning,
             page: 1,
         }
     }
 
-    pub fn handle_command(&mut self, command: String, arg: Option<&String>) -> CommandResult {
-        match command.trim() {
+    pub fn handle_command(&mut self, command: String, args: &CommandArgs) -> CommandResult {
+        let command = command.trim();
+        let requires_portfolio = matches!(
+            command,
+            "a" | "d" | "e" | "cs" | "f" | "dep" | "wd" | "cash" | "margin" | "mp" | "tw" | "alloc"
+        );
+        if requires_portfolio && self.portfolio.is_none() {
+            return CommandResult::Error(t(NO_PORTFOLIO_SELECTED_ERROR));
+        }
+
+        match command {
             "q" => std::process::exit(0),
             "n" => self.handle_next_page(),
             "p" => self.handle_previous_page(),
-            "a" => self.handle_add_position(),
-            "d" => self.handle_delete_position(arg),
-            "e" => self.handle_edit_position(arg),
+            "a" => self.handle_add_position(args),
+            "d" => self.handle_delete_position(args.get(0, "id")),
+            "e" => self.handle_edit_position(args.get(0, "id")),
             "cs" => self.handle_change_sorting(),
+            "f" => self.handle_change_filter(args.join_from(0, "filter")),
+            "pa" => self.handle_add_portfolio(),
+            "ps" => self.handle_switch_portfolio(args.get(0, "id")),
+            "pr" => {
+                let [id, name] = args.resolve(["id", "name"]);
+                self.handle_rename_portfolio(id, name)
+            }
+            "pd" => self.handle_delete_portfolio(args.get(0, "id")),
+            "dep" => self.handle_add_cash_entry(CashEntryKind::Deposit, args.get(0, "amount")),
+            "wd" => self.handle_add_cash_entry(CashEntryKind::Withdrawal, args.get(0, "amount")),
+            "cash" => self.handle_cash_ledger(),
+            "margin" => self.handle_toggle_margin(),
+            "r" => self.handle_income_report(args.get(0, "period")),
+            "tax" => self.handle_tax_report(args.get(0, "year")),
+            "ch" => self.handle_charts(),
+            "db" => self.handle_dashboard(),
+            "mp" => self.handle_set_market_price(args.get(0, "name")),
+            "tw" => self.handle_set_target_weights(args.join_from(0, "weights")),
+            "alloc" => self.handle_allocation(),
             "h" => self.handle_help(),
             _ => {
                 self.show_ui();
@@ -54,36 +133,145 @@ impl GlobalCommandManager {
     }
 
     pub fn show_ui(&self) {
-        let sorted_positions = self.sorter.sort(&self.positions);
-        render::render_positions_table(&sorted_positions, self.page);
+        match self.get_current_portfolio() {
+            Some(portfolio) => render::render_positions_table(
+                &self.get_visible_positions(),
+                self.page,
+                self.filter.as_ref(),
+                &portfolio,
+            ),
+            None => match load_portfolios() {
+                Ok(portfolios) => render::render_portfolios_table(&portfolios),
+                Err(error) => exit_with_error(error),
+            },
+        }
+        for warning in self.get_warnings() {
+            print_warning(&warning);
+        }
         render::render_help_tooltip();
     }
 
-    fn handle_add_position(&mut self) -> CommandResult {
-        let name = match ask_for_input::<String>("Enter position name") {
-            Ok(value) => value,
-            Err(error) => return CommandResult::Error(error),
-        };
+    pub fn get_completions(&self) -> Completions {
+        let positions = self
+            .positions
+            .iter()
+            .map(|position| get_argument_pair(position.id.to_string(), &position.name))
+            .collect();
 
-        let order_type_input = match ask_for_input::<String>("Enter order type (long/short)") {
-            Ok(value) => value,
+        Completions::new(&COMMANDS).with_arguments(vec!["e", "d"], positions)
+    }
+
+    pub fn get_sorting_description(&self) -> String {
+        self.sorter.describe()
+    }
+
+    /// Problems of loaded data, which don't prevent using the app
+    pub fn get_warnings(&self) -> Vec<String> {
+        let invalid_orders: Vec<String> = self
+            .positions
+            .iter()
+            .flat_map(|position| position.find_invalid_orders())
+            .collect();
+
+        let mut warnings: Vec<String> = self.filter_warning.iter().cloned().collect();
+        if !invalid_orders.is_empty() {
+            warnings.push(tf!("warning.invalid_orders", invalid_orders.join("; ")));
+        }
+        warnings
+    }
+
+    pub fn get_filter_expression(&self) -> Option<&str> {
+        self.filter
+            .as_ref()
+            .map(|filter| filter.expression.as_str())
+    }
+
+    pub fn get_current_portfolio(&self) -> Option<Portfolio> {
+        self.portfolio.as_ref().map(|portfolio| Portfolio {
+            positions: self.positions.clone(),
+            ..portfolio.clone()
+        })
+    }
+
+    /// Sorted and filtered positions, the positions table renders them in reversed order
+    pub fn get_visible_positions(&self) -> Vec<Position> {
+        let sorted_positions = self.sorter.sort(&self.positions);
+        match &self.filter {
+            Some(filter) => filter.apply(&sorted_positions),
+            None => sorted_positions,
+        }
+    }
+
+    // Values, which are not passed as arguments, are asked one by one
+    fn handle_add_position(&mut self, args: &CommandArgs) -> CommandResult {
+        let [name_arg, type_arg, amount_arg, value_arg] =
+            args.resolve(["name", "type", "amount", "value"]);
+        let name = match parse_valid_arg_or_get_from_input::<String, _, _>(
+            name_arg,
+            &t("prompt.position_name"),
+            |name| validate_name(name),
+        ) {
+            Ok(value) => value.trim().to_string(),
             Err(error) => return CommandResult::Error(error),
         };
 
+        let order_type_input =
+            match parse_arg_or_choose::<String>(type_arg, &t("prompt.order_type"), &POSITION_TYPES)
+            {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            };
+
         let order_type = match Action::from_string(order_type_input) {
             Ok(action) => action,
             Err(error) => return CommandResult::Error(error),
         };
 
-        let amount = match ask_for_input::<f64>("Enter position amount") {
+        let amount = match parse_valid_arg_or_get_from_input(
+            amount_arg,
+            &t("prompt.position_amount"),
+            |amount| validate_positive("amount", *amount),
+        ) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
-        let value = match ask_for_input::<f64>("Enter position value") {
+        let value = match parse_valid_arg_or_get_from_input(
+            value_arg,
+            &t("prompt.position_value"),
+            |value| validate_positive("value", *value),
+        ) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
 
+        if order_type == Action::Long {
+            match confirm_cash_spending(value) {
+                Ok(ConfirmationStatus::Confirmed) => {}
+                Ok(ConfirmationStatus::Rejected) => return CommandResult::Ok,
+                Err(error) => return CommandResult::Error(error),
+            }
+        }
+
+        self.add_position(name, order_type, amount, value)
+    }
+
+    /// Opens new position with the first order. Cash balance is expected to be confirmed
+    /// by the caller.
+    pub fn add_position(
+        &mut self,
+        name: String,
+        action: Action,
+        amount: f64,
+        value: f64,
+    ) -> CommandResult {
+        if self.portfolio.is_none() {
+            return CommandResult::Error(t(NO_PORTFOLIO_SELECTED_ERROR));
+        }
+
+        if let Err(error) = validate_name(&name).and(validate_order_values(amount, value)) {
+            return CommandResult::Error(tf!("error.invalid_position", error));
+        }
+
         let id = if let Some(last_position) = self.positions.last() {
             last_position.id + 1
         } else {
@@ -92,15 +280,19 @@ impl GlobalCommandManager {
 
         let first_order = Order {
             id: 0,
-            action: order_type,
+            action,
             amount,
             value,
             price: value / amount,
             income: 0f64,
+            date: Local::now(),
         };
 
-        self.positions
-            .push(Position::new(id, name, vec![first_order]));
+        self.positions.push(Position::new(
+            id,
+            name.trim().to_string(),
+            vec![first_order],
+        ));
 
         if let Err(error) = storage::save_positions(&self.positions) {
             exit_with_error(error);
@@ -111,9 +303,9 @@ impl GlobalCommandManager {
 
     fn handle_next_page(&mut self) -> CommandResult {
         let positions_per_page = get_options().positions_per_page;
-        let max_page = get_pages_count(self.positions.len(), positions_per_page);
+        let max_page = get_pages_count(self.get_visible_positions().len(), positions_per_page);
         if (self.page + 1) as f64 > max_page {
-            CommandResult::Error(String::from("Already at last page"))
+            CommandResult::Error(t("error.already_at_last_page"))
         } else {
             self.page += 1;
             CommandResult::Ok
@@ -122,7 +314,7 @@ impl GlobalCommandManager {
 
     fn handle_previous_page(&mut self) -> CommandResult {
         if self.page == 1 {
-            CommandResult::Error(String::from("Already at first page"))
+            CommandResult::Error(t("error.already_at_first_page"))
         } else {
             self.page -= 1;
             CommandResult::Ok
@@ -130,20 +322,20 @@ impl GlobalCommandManager {
     }
 
     fn handle_delete_position(&mut self, arg: Option<&String>) -> CommandResult {
-        let id = match parse_arg_or_get_from_input::<i32>(arg, "Enter position id") {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.position_id")) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
 
         let position = match self.positions.iter().find(|pos| pos.id == id) {
             Some(pos) => pos,
-            None => return CommandResult::Error(format!("Position with id {} not found", id)),
+            None => return CommandResult::Error(tf!("error.position_not_found", id)),
         };
 
         render::render_single_position(position);
 
         let confirmation = match ask_confirmation(
-            format!("Are you sure want to delete position {}? (y,N)", id).as_str(),
+            tf!("confirm.delete_position", id).as_str(),
             ConfirmationStatus::Rejected,
         ) {
             Ok(value) => value,
@@ -154,12 +346,14 @@ impl GlobalCommandManager {
             return CommandResult::Ok;
         }
 
-        let pos_index_option = self.positions.iter().position(|pos| pos.id == id);
-        if pos_index_option.is_none() {
-            return CommandResult::Error(format!("Position with id {} not found", id));
-        }
+        self.delete_position(id)
+    }
 
-        let pos_index = pos_index_option.unwrap();
+    pub fn delete_position(&mut self, id: i32) -> CommandResult {
+        let pos_index = match self.positions.iter().position(|pos| pos.id == id) {
+            Some(index) => index,
+            None => return CommandResult::Error(tf!("error.position_not_found", id)),
+        };
 
         let mut new_positions = self.positions.to_vec();
         new_positions.remove(pos_index);
@@ -168,21 +362,25 @@ impl GlobalCommandManager {
             exit_with_error(error);
         }
 
-        CommandResult::Ok
+        CommandResult::UpdatePositions(self.positions.to_vec())
     }
 
     fn handle_edit_position(&self, arg: Option<&String>) -> CommandResult {
-        let id = match parse_arg_or_get_from_input::<i32>(arg, "Enter position id") {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.position_id")) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
 
+        self.edit_position(id)
+    }
+
+    pub fn edit_position(&self, id: i32) -> CommandResult {
         let position = match self.positions.iter().find(|pos| pos.id == id) {
             Some(pos) => pos.to_owned(),
-            None => return CommandResult::Error(format!("Position with id '{}' not found", id)),
+            None => return CommandResult::Error(tf!("error.position_not_found_quoted", id)),
         };
 
-        CommandResult::ChangeEditMode(ChangeEditMode::EditPosition(position))
+        CommandResult::ChangeEditMode(ChangeEditMode::EditPosition(Box::new(position)))
     }
 
     fn handle_help(&self) -> CommandResult {
@@ -194,42 +392,57 @@ impl GlobalCommandManager {
         CommandResult::Ok
     }
 
-    fn update_positions(&mut self, positions: &Vec<Position>) -> Result<(), String> {
+    fn update_positions(&mut self, positions: &[Position]) -> Result<(), String> {
         self.positions = positions.to_vec();
-        storage::save_positions(&positions)
+        storage::save_positions(positions)
     }
 
     fn handle_change_sorting(&mut self) -> CommandResult {
         clear_screen().expect("clear screen");
 
         println!(
-            "Current sorting method: {}",
-            self.sorter.sort_by.to_string().yellow()
+            "{}",
+            tf!(
+                "info.current_sorting",
+                self.sorter.describe().paint(Role::Accent)
+            )
         );
 
         println!();
 
-        println!("{}", "Available sorting methods: ".bold());
-        println!("{}{}", "1".yellow(), ". By id");
-        println!("{}{}", "2".yellow(), ". By avg value");
-        println!("{}{}", "3".yellow(), ". By avg price");
-        println!("{}{}", "4".yellow(), ". By income");
-        println!("{}{}", "5".yellow(), ". By last change");
+        println!("{}", t("info.available_sorting_methods").bold());
+        SORTING_METHODS
+            .iter()
+            .enumerate()
+            .for_each(|(index, (label, _))| {
+                println!(
+                    "{}. {}",
+                    (index + 1).to_string().paint(Role::Accent),
+                    t(label)
+                )
+            });
 
         println!();
 
         println!(
-            "{}{} ({})",
-            "cb".yellow(),
-            " - Move closed positions to bottom",
-            match self.sorter.move_closed_to_bottom {
-                true => "enabled",
-                false => "disabled",
-            }
+            "{} - {}",
+            "cb".paint(Role::Accent),
+            tf!(
+                "info.move_closed_to_bottom",
+                match self.sorter.move_closed_to_bottom {
+                    true => t("info.enabled"),
+                    false => t("info.disabled"),
+                }
+            )
         );
-        println!("{}{}", "q".yellow(), " - Exit");
+        println!("{} - {}", "q".paint(Role::Accent), t("info.exit"));
 
-        let choice = match ask_for_input::<String>("\nChoose the number of preferred sorting:") {
+        let numbers: Vec<String> = (1..=SORTING_METHODS.len())
+            .map(|number| number.to_string())
+            .chain([String::from("cb"), String::from("q")])
+            .collect();
+        let choices: Vec<&str> = numbers.iter().map(|number| number.as_str()).collect();
+        let choice = match ask_for_choice::<String>(&t("prompt.sorting_numbers"), &choices) {
             Ok(answer) => answer.to_lowercase(),
             Err(error) => return CommandResult::Error(error),
         };
@@ -239,50 +452,676 @@ impl GlobalCommandManager {
         }
 
         if choice.trim() == "cb" {
-            self.sorter.move_closed_to_bottom = !self.sorter.move_closed_to_bottom;
+            return self.toggle_move_closed_to_bottom();
+        }
 
-            if let Err(error) = update_storage(|storage| {
-                storage.move_closed_positions_to_bottom = self.sorter.move_closed_to_bottom
-            }) {
-                return CommandResult::Error(error);
+        let methods = match parse_sorting_methods(&choice) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let mut sort_keys = vec![];
+        for (label, to_sort_by) in methods {
+            let direction = match ask_for_choice::<String>(
+                tf!("prompt.sorting_direction", t(label).to_lowercase()).as_str(),
+                &["asc", "desc"],
+            )
+            .and_then(|answer| SortDirection::from_string(&answer))
+            {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            };
+
+            sort_keys.push(to_sort_by(direction));
+        }
+
+        self.set_sorting(sort_keys)
+    }
+
+    /// Sorts positions by the first key, ties are sorted by the next keys
+    pub fn set_sorting(&mut self, mut sort_keys: Vec<SortBy>) -> CommandResult {
+        if sort_keys.is_empty() {
+            return CommandResult::Error(t("error.no_sorting_method"));
+        }
+
+        self.sorter.sort_by = sort_keys.remove(0);
+        self.sorter.then_sort_by = sort_keys;
+
+        if let Err(error) = update_current_portfolio(|portfolio| {
+            portfolio.sort_positions_by = self.sorter.sort_by;
+            portfolio.then_sort_positions_by = self.sorter.then_sort_by.clone();
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    pub fn toggle_move_closed_to_bottom(&mut self) -> CommandResult {
+        self.sorter.move_closed_to_bottom = !self.sorter.move_closed_to_bottom;
+
+        if let Err(error) = update_current_portfolio(|portfolio| {
+            portfolio.move_closed_positions_to_bottom = self.sorter.move_closed_to_bottom
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    fn handle_change_filter(&mut self, arg: Option<String>) -> CommandResult {
+        let expression = match arg {
+            Some(value) => value.trim().to_string(),
+            None => {
+                if let Some(filter) = &self.filter {
+                    println!(
+                        "{}",
+                        tf!("info.current_filter", filter.expression.paint(Role::Accent))
+                    );
+                }
+
+                match ask_for_input::<String>(&t("prompt.filter_expression")) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                }
             }
+        };
 
-            return CommandResult::Ok;
+        self.filter = if expression.is_empty() {
+            None
+        } else {
+            match PositionsFilter::parse(&expression, get_options().locale) {
+                Ok(filter) => Some(filter),
+                Err(error) => return CommandResult::Error(error),
+            }
+        };
+        self.filter_warning = None;
+        self.page = 1;
+
+        let filter_expression = self.filter.as_ref().map(|filter| filter.expression.clone());
+        if let Err(error) = update_current_portfolio(|portfolio| {
+            portfolio.positions_filter = filter_expression.clone()
+        }) {
+            return CommandResult::Error(error);
         }
 
-        let direction_str = match ask_for_input::<String>("Choose direction (asc, desc): ") {
-            Ok(answer) => answer.to_lowercase(),
+        CommandResult::Ok
+    }
+
+    fn handle_add_portfolio(&mut self) -> CommandResult {
+        let name = match ask_for_input::<String>(&t("prompt.portfolio_name")) {
+            Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
 
-        let direction = match direction_str.trim() {
-            "asc" | "a" => SortDirection::Ascending,
-            "desc" | "d" => SortDirection::Descending,
-            _ => {
-                return CommandResult::Error(format!(
-                    "Failed to parse direction '{}'",
-                    direction_str
-                ))
+        if name.is_empty() {
+            return CommandResult::Error(t("error.empty_portfolio_name"));
+        }
+
+        if let Err(error) = update_storage(|storage| {
+            let id = match storage
+                .portfolios
+                .iter()
+                .map(|portfolio| portfolio.id)
+                .max()
+            {
+                Some(last_id) => last_id + 1,
+                None => 0,
+            };
+
+            storage.portfolios.push(Portfolio::new(id, name.clone()));
+            storage.current_portfolio_id = Some(id);
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    fn handle_switch_portfolio(&mut self, arg: Option<&String>) -> CommandResult {
+        let portfolios = match load_portfolios() {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if arg.is_none() {
+            render::render_portfolios_table(&portfolios);
+        }
+
+        let answer =
+            match parse_arg_or_get_from_input::<String>(arg, &t("prompt.portfolio_id_or_all")) {
+                Ok(value) => value.to_lowercase(),
+                Err(error) => return CommandResult::Error(error),
+            };
+
+        let portfolio_id = if answer == "all" {
+            None
+        } else {
+            match answer.parse::<i32>() {
+                Ok(id) if portfolios.iter().any(|portfolio| portfolio.id == id) => Some(id),
+                _ => return CommandResult::Error(tf!("error.portfolio_not_found_by_name", answer)),
             }
         };
 
-        self.sorter.sort_by = match choice.trim() {
-            "1" => SortBy::Id(direction),
-            "2" => SortBy::AvgValue(direction),
-            "3" => SortBy::AvgPrice(direction),
-            "4" => SortBy::Income(direction),
-            "5" => SortBy::LastChange(direction),
-            _ => {
-                return CommandResult::Error(format!("Failed to parse sorting method '{}'", choice))
+        if let Err(error) = update_storage(|storage| storage.current_portfolio_id = portfolio_id) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    fn handle_rename_portfolio(
+        &mut self,
+        arg: Option<&String>,
+        name_arg: Option<&String>,
+    ) -> CommandResult {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.portfolio_id")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        match load_portfolios() {
+            Ok(portfolios) if portfolios.iter().any(|portfolio| portfolio.id == id) => {}
+            Ok(_) => return CommandResult::Error(tf!("error.portfolio_not_found", id)),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let name = match parse_arg_or_get_from_input::<String>(
+            name_arg,
+            &t("prompt.new_portfolio_name"),
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if name.is_empty() {
+            return CommandResult::Error(t("error.empty_portfolio_name"));
+        }
+
+        if let Err(error) = update_storage(|storage| {
+            if let Some(portfolio) = storage
+                .portfolios
+                .iter_mut()
+                .find(|portfolio| portfolio.id == id)
+            {
+                portfolio.name = name.clone();
+            }
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    fn handle_delete_portfolio(&mut self, arg: Option<&String>) -> CommandResult {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.portfolio_id")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let portfolios = match load_portfolios() {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let portfolio = match portfolios.iter().find(|portfolio| portfolio.id == id) {
+            Some(portfolio) => portfolio,
+            None => return CommandResult::Error(tf!("error.portfolio_not_found", id)),
+        };
+
+        if portfolios.len() == 1 {
+            return CommandResult::Error(t("error.delete_only_portfolio"));
+        }
+
+        let confirmation = match ask_confirmation(
+            tf!(
+                "confirm.delete_portfolio",
+                portfolio.name,
+                portfolio.positions.len()
+            )
+            .as_str(),
+            ConfirmationStatus::Rejected,
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if confirmation == ConfirmationStatus::Rejected {
+            return CommandResult::Ok;
+        }
+
+        if let Err(error) = update_storage(|storage| {
+            storage.portfolios.retain(|portfolio| portfolio.id != id);
+            if storage.current_portfolio_id == Some(id) {
+                storage.current_portfolio_id = None;
+            }
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    // Recreates manager with data of the current portfolio after it was switched or changed
+    fn reload_portfolio(&mut self) -> CommandResult {
+        let positions = match storage::load_positions() {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        *self = GlobalCommandManager::new(&positions);
+        CommandResult::UpdatePositions(positions)
+    }
+
+    fn handle_add_cash_entry(
+        &mut self,
+        kind: CashEntryKind,
+        arg: Option<&String>,
+    ) -> CommandResult {
+        let question = match kind {
+            CashEntryKind::Deposit => t("prompt.deposit_amount"),
+            CashEntryKind::Withdrawal => t("prompt.withdrawal_amount"),
+        };
+
+        let amount = match parse_valid_arg_or_get_from_input(arg, &question, |amount| {
+            validate_positive("amount", *amount)
+        }) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if kind == CashEntryKind::Withdrawal {
+            match confirm_cash_spending(amount) {
+                Ok(ConfirmationStatus::Confirmed) => {}
+                Ok(ConfirmationStatus::Rejected) => return CommandResult::Ok,
+                Err(error) => return CommandResult::Error(error),
             }
+        }
+
+        if let Err(error) = update_current_portfolio(|portfolio| {
+            let entry = CashEntry::new(&portfolio.cash_entries, kind, amount);
+            portfolio.cash_entries.push(entry);
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    fn handle_cash_ledger(&mut self) -> CommandResult {
+        let portfolio = match self.get_current_portfolio() {
+            Some(portfolio) => portfolio,
+            None => return CommandResult::Error(t(NO_PORTFOLIO_SELECTED_ERROR)),
+        };
+
+        render::render_cash_ledger(&portfolio);
+
+        let id = match ask_for_input::<String>(&t("prompt.cash_entry_id")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if id.is_empty() {
+            return CommandResult::Ok;
+        }
+
+        let id = match id.parse::<i32>() {
+            Ok(id) if portfolio.cash_entries.iter().any(|entry| entry.id == id) => id,
+            _ => return CommandResult::Error(tf!("error.cash_entry_not_found", id)),
+        };
+
+        let confirmation = match ask_confirmation(
+            tf!("confirm.delete_cash_entry", id).as_str(),
+            ConfirmationStatus::Rejected,
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
         };
 
+        if confirmation == ConfirmationStatus::Rejected {
+            return CommandResult::Ok;
+        }
+
+        if let Err(error) = update_current_portfolio(|portfolio| {
+            portfolio.cash_entries.retain(|entry| entry.id != id)
+        }) {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    fn handle_toggle_margin(&mut self) -> CommandResult {
         if let Err(error) =
-            update_storage(|storage| storage.sort_positions_by = self.sorter.sort_by)
+            update_current_portfolio(|portfolio| portfolio.allow_margin = !portfolio.allow_margin)
+        {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    // Report includes visible positions of the current portfolio or positions of all portfolios
+    fn handle_income_report(&self, arg: Option<&String>) -> CommandResult {
+        let period = match parse_arg_or_get_from_input::<String>(arg, &t("prompt.report_period")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+        let period = match ReportPeriod::from_string(&period, get_options().locale.first_weekday())
         {
+            Ok(period) => period,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let grouping =
+            match ask_for_choice::<String>(&t("prompt.report_grouping"), &["position", "tag"]) {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            };
+        let grouping = match ReportGrouping::from_string(&grouping) {
+            Ok(grouping) => grouping,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let format =
+            match ask_for_choice::<String>(&t("prompt.report_format"), &["table", "csv", "json"]) {
+                Ok(value) => value.trim().to_lowercase(),
+                Err(error) => return CommandResult::Error(error),
+            };
+
+        let positions = match self.portfolio {
+            Some(_) => self.get_visible_positions(),
+            None => match load_portfolios() {
+                Ok(portfolios) => portfolios
+                    .into_iter()
+                    .flat_map(|portfolio| portfolio.positions)
+                    .collect(),
+                Err(error) => exit_with_error(error),
+            },
+        };
+        let report = IncomeReport::build(&positions, period, grouping);
+
+        let content = match format.as_str() {
+            "table" | "t" | "" => {
+                render::render_income_report(&report);
+                if let Err(error) = wait_for_enter() {
+                    return CommandResult::Error(error);
+                }
+                return CommandResult::Ok;
+            }
+            "csv" => report.to_csv(),
+            "json" => report.to_json(),
+            _ => return CommandResult::Error(tf!("error.unknown_report_format", format)),
+        };
+
+        let path = match ask_for_input::<String>(&t("prompt.report_path")) {
+            Ok(value) => value.trim().to_string(),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if path.is_empty() {
+            clear_screen().expect("clear screen");
+            println!("{}", content);
+        } else {
+            if let Err(error) = std::fs::write(&path, content) {
+                return CommandResult::Error(tf!("error.write_report", path, error));
+            }
+            println!("{}", tf!("info.report_saved", path));
+        }
+
+        if let Err(error) = wait_for_enter() {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    // All accounts view counts positions of all portfolios
+    pub fn handle_dashboard(&self) -> CommandResult {
+        let portfolios = match self.get_current_portfolio() {
+            Some(portfolio) => vec![portfolio],
+            None => match load_portfolios() {
+                Ok(portfolios) => portfolios,
+                Err(error) => return CommandResult::Error(error),
+            },
+        };
+        render::render_dashboard(&build_dashboard(&portfolios));
+
+        if let Err(error) = wait_for_enter() {
             return CommandResult::Error(error);
         }
 
         CommandResult::Ok
     }
+
+    fn handle_charts(&self) -> CommandResult {
+        let positions = match self.portfolio {
+            Some(_) => self.positions.clone(),
+            None => match load_portfolios() {
+                Ok(portfolios) => portfolios
+                    .into_iter()
+                    .flat_map(|portfolio| portfolio.positions)
+                    .collect(),
+                Err(error) => exit_with_error(error),
+            },
+        };
+        render::render_portfolio_charts(&positions);
+
+        if let Err(error) = wait_for_enter() {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    // Tax report ignores positions filter, as every disposal has to be reported
+    fn handle_tax_report(&self, arg: Option<&String>) -> CommandResult {
+        let year = match parse_arg_or_get_from_input::<String>(arg, &t("prompt.tax_year")) {
+            Ok(value) => value.trim().to_string(),
+            Err(error) => return CommandResult::Error(error),
+        };
+        let year = if year.is_empty() {
+            None
+        } else {
+            match year.parse::<i32>() {
+                Ok(year) => Some(year),
+                Err(_) => return CommandResult::Error(tf!("error.parse_year", year)),
+            }
+        };
+
+        let positions = match self.portfolio {
+            Some(_) => self.positions.clone(),
+            None => match load_portfolios() {
+                Ok(portfolios) => portfolios
+                    .into_iter()
+                    .flat_map(|portfolio| portfolio.positions)
+                    .collect(),
+                Err(error) => exit_with_error(error),
+            },
+        };
+        let report = TaxReport::build(&positions, year, get_options().long_term_holding_days);
+        render::render_tax_report(&report);
+
+        let path = match ask_for_input::<String>(&t("prompt.tax_report_path")) {
+            Ok(value) => value.trim().to_string(),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if path.is_empty() {
+            return CommandResult::Ok;
+        }
+
+        if let Err(error) = std::fs::write(&path, report.to_csv()) {
+            return CommandResult::Error(tf!("error.write_report", path, error));
+        }
+
+        CommandResult::Ok
+    }
+
+    // Price is set for every active position with the given name, e.g. for a long and a short
+    fn handle_set_market_price(&mut self, arg: Option<&String>) -> CommandResult {
+        let name = match parse_arg_or_get_from_input::<String>(arg, &t("prompt.position_name")) {
+            Ok(value) => value.trim().to_lowercase(),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let is_updated =
+            |position: &Position| !position.is_closed() && position.name.to_lowercase() == name;
+        if !self.positions.iter().any(is_updated) {
+            return CommandResult::Error(tf!("error.no_active_positions_named", name));
+        }
+
+        let price = match ask_for_valid_input(&t("prompt.market_price"), |price| {
+            validate_positive("price", *price)
+        }) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        self.positions
+            .iter_mut()
+            .filter(|position| is_updated(position))
+            .for_each(|position| position.set_market_price(price));
+
+        if let Err(error) = storage::save_positions(&self.positions) {
+            exit_with_error(error);
+        }
+
+        CommandResult::UpdatePositions(self.positions.to_vec())
+    }
+
+    fn handle_set_target_weights(&mut self, arg: Option<String>) -> CommandResult {
+        let current_weights = match &self.portfolio {
+            Some(portfolio) => portfolio
+                .target_weights
+                .iter()
+                .map(|weight| weight.to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+            None => return CommandResult::Error(t(NO_PORTFOLIO_SELECTED_ERROR)),
+        };
+        if !current_weights.is_empty() {
+            println!(
+                "{}",
+                tf!("info.current_target_weights", current_weights.bold())
+            );
+        }
+
+        let input = match arg {
+            Some(value) => value,
+            None => match ask_for_input::<String>(&t("prompt.target_weights")) {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            },
+        };
+
+        let target_weights = match TargetWeight::parse_list(&input) {
+            Ok(weights) => weights,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if let Err(error) =
+            update_current_portfolio(|portfolio| portfolio.target_weights = target_weights.clone())
+        {
+            return CommandResult::Error(error);
+        }
+
+        self.reload_portfolio()
+    }
+
+    // Shows allocation and offers to place orders, which bring positions to target weights
+    fn handle_allocation(&mut self) -> CommandResult {
+        let portfolio = match self.get_current_portfolio() {
+            Some(portfolio) => portfolio,
+            None => return CommandResult::Error(t(NO_PORTFOLIO_SELECTED_ERROR)),
+        };
+
+        if portfolio.target_weights.is_empty() {
+            return CommandResult::Error(t("error.target_weights_not_set"));
+        }
+
+        let rows = build_allocation(&portfolio);
+        render::render_allocation(&rows);
+
+        let orders: Vec<_> = rows.iter().flat_map(|row| row.orders.clone()).collect();
+        if orders.is_empty() {
+            if let Err(error) = wait_for_enter() {
+                return CommandResult::Error(error);
+            }
+            return CommandResult::Ok;
+        }
+
+        let confirmation = match ask_confirmation(
+            &t("confirm.place_rebalancing_orders"),
+            ConfirmationStatus::Rejected,
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+        if confirmation == ConfirmationStatus::Rejected {
+            return CommandResult::Ok;
+        }
+
+        let spent_value: f64 = orders
+            .iter()
+            .map(|order| match order.action {
+                Action::Long => order.value(),
+                Action::Short => -order.value(),
+            })
+            .sum();
+        if spent_value > 0f64 {
+            match confirm_cash_spending(spent_value) {
+                Ok(ConfirmationStatus::Confirmed) => {}
+                Ok(ConfirmationStatus::Rejected) => return CommandResult::Ok,
+                Err(error) => return CommandResult::Error(error),
+            }
+        }
+
+        // Orders are added like ones entered in position mode, so they are validated and
+        // follow over-close behavior
+        let mut errors = vec![];
+        for rebalancing_order in orders {
+            let position = match self
+                .positions
+                .iter()
+                .find(|position| position.id == rebalancing_order.position_id)
+            {
+                Some(position) => position,
+                None => continue,
+            };
+
+            // Rounding of weights can make sell amount slightly larger than the held one
+            let amount = match rebalancing_order.action {
+                Action::Long => rebalancing_order.amount,
+                Action::Short => rebalancing_order.amount.min(position.amount),
+            };
+            let mut manager = PositionCommandManager::new(position);
+            manager.position.edited_at = Local::now();
+            let result = manager.add_order(
+                rebalancing_order.action,
+                amount,
+                amount * rebalancing_order.price,
+            );
+            if let CommandResult::Error(error) = result {
+                errors.push(tf!(
+                    "error.rebalancing_order_failed",
+                    rebalancing_order.name,
+                    error
+                ));
+            }
+        }
+
+        self.positions = match storage::load_positions() {
+            Ok(positions) => positions,
+            Err(error) => exit_with_error(error),
+        };
+        if !errors.is_empty() {
+            errors.into_iter().for_each(print_error);
+            if let Err(error) = wait_for_enter() {
+                return CommandResult::Error(error);
+            }
+        }
+
+        CommandResult::UpdatePositions(self.positions.to_vec())
+    }
 }
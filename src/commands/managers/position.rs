@@ -1,11 +1,27 @@
-use super::super::utils::commands::parse_arg_or_get_from_input;
+use super::super::utils::commands::{
+    ask_for_optional_price, confirm_cash_spending, parse_arg_or_choose,
+    parse_arg_or_get_from_input, parse_valid_arg_or_get_from_input,
+};
+use super::super::utils::parser::CommandArgs;
 use super::super::ChangeEditMode;
 use crate::commands::ui::render;
-use crate::models::{Action, Order, Position};
+use crate::i18n::t;
+use crate::models::action::ORDER_TYPES;
+use crate::models::validation::{validate_order_values, validate_positive};
+use crate::models::{
+    Action, CorporateAction, CorporateActionKind, IncomeEvent, IncomeEventKind, Order, Position,
+    ValidationError,
+};
 use crate::options::get_options;
-use crate::utils::console::{ask_confirmation, ask_for_input, wait_for_enter, ConfirmationStatus};
+use crate::options::model::OverCloseBehavior;
+use crate::utils::console::{
+    ask_confirmation, ask_for_choice, ask_for_input, ask_for_valid_input, wait_for_enter,
+    ConfirmationStatus,
+};
+use crate::utils::line_editor::{get_argument_pair, Completions};
 use crate::utils::pagination::get_pages_count;
-use crate::{exit_with_error, storage};
+use crate::{exit_with_error, storage, tf};
+use chrono::Local;
 
 use super::super::CommandResult;
 
@@ -15,6 +31,11 @@ pub struct PositionCommandManager {
     page: i32,
 }
 
+// Commands, which are completed by tab in the REPL
+const COMMANDS: [&str; 16] = [
+    "a", "d", "ai", "di", "ca", "dc", "t", "s", "avg", "lv", "mp", "ch", "n", "p", "h", "q",
+];
+
 impl PositionCommandManager {
     pub fn new(position: &Position) -> PositionCommandManager {
         PositionCommandManager {
@@ -23,13 +44,21 @@ impl PositionCommandManager {
         }
     }
 
-    pub fn handle_command(&mut self, command: String, arg: Option<&String>) -> CommandResult {
+    pub fn handle_command(&mut self, command: String, args: &CommandArgs) -> CommandResult {
         match command.trim() {
-            "q" => CommandResult::ChangeEditMode(ChangeEditMode::PositionChanged(
-                self.position.clone(),
-            )),
-            "a" => self.handle_add_order(),
-            "d" => self.handle_delete_order(arg),
+            "q" => self.close(),
+            "a" => self.handle_add_order(args),
+            "d" => self.handle_delete_order(args.get(0, "id")),
+            "ai" => self.handle_add_income_event(),
+            "di" => self.handle_delete_income_event(args.get(0, "id")),
+            "ca" => self.handle_add_corporate_action(),
+            "dc" => self.handle_delete_corporate_action(args.get(0, "id")),
+            "t" => self.handle_set_tags(args.join_from(0, "tags").as_ref()),
+            "s" => self.handle_simulate(args.get(0, "kind")),
+            "avg" => self.handle_averaging(),
+            "lv" => self.handle_set_price_levels(),
+            "mp" => self.handle_set_market_price(args.get(0, "price")),
+            "ch" => self.handle_chart(),
             "h" => self.handle_help(),
             "n" => self.handle_next_page(),
             "p" => self.handle_previous_page(),
@@ -37,13 +66,37 @@ impl PositionCommandManager {
         }
     }
 
+    pub fn get_completions(&self) -> Completions {
+        let orders = self
+            .position
+            .orders
+            .iter()
+            .map(|order| {
+                let action = match order.action {
+                    Action::Long => t("label.buy"),
+                    Action::Short => t("label.sell"),
+                };
+                let description = tf!("info.order_description", action, order.amount, order.value);
+                get_argument_pair(order.id.to_string(), &description)
+            })
+            .collect();
+
+        Completions::new(&COMMANDS).with_arguments(vec!["d"], orders)
+    }
+
     pub fn show_ui(&self) {
         render::render_position_info(&self.position, self.page);
         render::render_help_tooltip();
     }
 
-    fn handle_add_order(&mut self) -> CommandResult {
-        let action_input = match ask_for_input::<String>("Enter order type (buy/sell)") {
+    // Values, which are not passed as arguments, are asked one by one
+    fn handle_add_order(&mut self, args: &CommandArgs) -> CommandResult {
+        let [type_arg, amount_arg, value_arg] = args.resolve(["type", "amount", "value"]);
+        let action_input = match parse_arg_or_choose::<String>(
+            type_arg,
+            &t("prompt.order_type_buy_sell"),
+            &ORDER_TYPES,
+        ) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
@@ -53,8 +106,25 @@ impl PositionCommandManager {
             Err(error) => return CommandResult::Error(error),
         };
 
-        let amount = match ask_for_input::<f64>(
-            "Enter position amount.\nEnter 0 if you're fully closing position.",
+        // Over-closing orders are asked again unless they are allowed to flip position.
+        // Zero amount closes the whole position, so it's allowed only for closing orders.
+        let reject_over_close = get_options().over_close_behavior == OverCloseBehavior::Reject;
+        let is_closing = action != self.position.action;
+        let amount = match parse_valid_arg_or_get_from_input::<f64, ValidationError, _>(
+            amount_arg,
+            &t("prompt.order_amount_or_close"),
+            |amount| {
+                if is_closing && *amount == 0f64 {
+                    return Ok(());
+                }
+
+                validate_positive("amount", *amount)?;
+                if reject_over_close && is_closing {
+                    self.position.validate_order(action, *amount, 1f64)?;
+                }
+
+                Ok(())
+            },
         ) {
             Ok(value) => {
                 if value == 0f64 {
@@ -66,11 +136,49 @@ impl PositionCommandManager {
             Err(error) => return CommandResult::Error(error),
         };
 
-        let value = match ask_for_input::<f64>("Enter position value") {
+        let value = match parse_valid_arg_or_get_from_input(
+            value_arg,
+            &t("prompt.position_value"),
+            |value| validate_positive("value", *value),
+        ) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
 
+        if action == Action::Long {
+            match confirm_cash_spending(value) {
+                Ok(ConfirmationStatus::Confirmed) => {}
+                Ok(ConfirmationStatus::Rejected) => return CommandResult::Ok,
+                Err(error) => return CommandResult::Error(error),
+            }
+        }
+
+        self.add_order(action, amount, value)
+    }
+
+    /// Adds order to the position. Cash balance is expected to be confirmed by the caller.
+    pub fn add_order(&mut self, action: Action, amount: f64, value: f64) -> CommandResult {
+        if let Err(error) = validate_order_values(amount, value) {
+            return CommandResult::Error(tf!("error.invalid_order", error));
+        }
+
+        if let Some((closing_order, rest_amount, rest_value)) =
+            self.position.split_over_close(action, amount, value)
+        {
+            return match get_options().over_close_behavior {
+                OverCloseBehavior::Reject => CommandResult::Error(tf!(
+                    "error.over_close",
+                    ValidationError::OverClose {
+                        requested: amount,
+                        available: self.position.amount
+                    }
+                )),
+                OverCloseBehavior::Flip => {
+                    self.flip_position(closing_order, action, rest_amount, rest_value)
+                }
+            };
+        }
+
         let order = Order::new(&self.position, action, amount, value);
         self.position.add_order(order);
 
@@ -81,8 +189,56 @@ impl PositionCommandManager {
         CommandResult::Ok
     }
 
+    // Closes position with the given order and opens new position of opposite direction
+    fn flip_position(
+        &mut self,
+        closing_order: Order,
+        action: Action,
+        amount: f64,
+        value: f64,
+    ) -> CommandResult {
+        self.position.add_order(closing_order);
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        let mut positions = match storage::load_positions() {
+            Ok(value) => value,
+            Err(error) => exit_with_error(error),
+        };
+
+        let id = match positions.last() {
+            Some(last_position) => last_position.id + 1,
+            None => 0,
+        };
+
+        let opened_position = Position::new(
+            id,
+            self.position.name.clone(),
+            vec![Order {
+                id: 0,
+                action,
+                amount,
+                value,
+                price: value / amount,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+
+        positions.push(opened_position.clone());
+        if let Err(error) = storage::save_positions(&positions) {
+            exit_with_error(error);
+        }
+
+        CommandResult::ChangeEditMode(ChangeEditMode::PositionFlipped(
+            Box::new(self.position.clone()),
+            Box::new(opened_position),
+        ))
+    }
+
     fn handle_delete_order(&mut self, arg: Option<&String>) -> CommandResult {
-        let id = match parse_arg_or_get_from_input::<i32>(arg, "Enter position id") {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.order_id")) {
             Ok(value) => value,
             Err(error) => return CommandResult::Error(error),
         };
@@ -90,17 +246,14 @@ impl PositionCommandManager {
         let order = match self.position.orders.iter().find(|order| order.id == id) {
             Some(order) => order.to_owned(),
             None => {
-                return CommandResult::Error(format!(
-                    "Cannot find order with id {} in position {}",
-                    id, self.position.id
-                ))
+                return CommandResult::Error(tf!("error.order_not_found", id, self.position.id))
             }
         };
 
         render::render_single_order(&self.position, &order);
 
         let confirmation = match ask_confirmation(
-            format!("Are you sure want to delete order {}? (y,N)", { id }).as_str(),
+            tf!("confirm.delete_order", id).as_str(),
             ConfirmationStatus::Confirmed,
         ) {
             Ok(value) => value,
@@ -111,7 +264,11 @@ impl PositionCommandManager {
             return CommandResult::Ok;
         }
 
-        if let Err(error) = self.position.remove_order(order.id) {
+        self.delete_order(order.id)
+    }
+
+    pub fn delete_order(&mut self, id: i32) -> CommandResult {
+        if let Err(error) = self.position.remove_order(id) {
             return CommandResult::Error(error);
         }
 
@@ -122,6 +279,13 @@ impl PositionCommandManager {
         CommandResult::Ok
     }
 
+    /// Returns to the positions list with changes of the position
+    pub fn close(&self) -> CommandResult {
+        CommandResult::ChangeEditMode(ChangeEditMode::PositionChanged(Box::new(
+            self.position.clone(),
+        )))
+    }
+
     fn handle_help(&self) -> CommandResult {
         render::render_edit_position_help_page();
         if let Err(error) = wait_for_enter() {
@@ -135,7 +299,7 @@ impl PositionCommandManager {
         let orders_per_page = get_options().orders_per_page;
         let max_page = get_pages_count(self.position.orders.len(), orders_per_page);
         if (self.page + 1) as f64 > max_page {
-            CommandResult::Error(String::from("Already at last page"))
+            CommandResult::Error(t("error.already_at_last_page"))
         } else {
             self.page += 1;
             CommandResult::Ok
@@ -144,10 +308,411 @@ impl PositionCommandManager {
 
     fn handle_previous_page(&mut self) -> CommandResult {
         if self.page == 1 {
-            CommandResult::Error(String::from("Already at first page"))
+            CommandResult::Error(t("error.already_at_first_page"))
         } else {
             self.page -= 1;
             CommandResult::Ok
         }
     }
+
+    fn handle_add_income_event(&mut self) -> CommandResult {
+        let kind_input = match ask_for_choice::<String>(
+            &t("prompt.income_type"),
+            &["dividend", "interest", "reward"],
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let kind = match IncomeEventKind::from_string(kind_input) {
+            Ok(kind) => kind,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        // Zero cash means that income is paid in units
+        let cash = match ask_for_valid_input(&t("prompt.income_cash"), |cash: &f64| {
+            if *cash == 0f64 {
+                return Ok(());
+            }
+
+            validate_positive("cash", *cash)
+        }) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let units = if cash == 0f64 {
+            if self.position.action == Action::Short {
+                return CommandResult::Error(t("error.income_units_short"));
+            }
+
+            match ask_for_valid_input(&t("prompt.income_units"), |units: &f64| {
+                validate_positive("units", *units)
+            }) {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            }
+        } else {
+            0f64
+        };
+
+        let event = IncomeEvent {
+            id: self.position.get_next_income_event_id(),
+            kind,
+            after_order_id: self.position.orders.last().unwrap().id,
+            cash,
+            units,
+            date: chrono::Local::now(),
+        };
+        self.position.add_income_event(event);
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    fn handle_delete_income_event(&mut self, arg: Option<&String>) -> CommandResult {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.income_event_id")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let event = match self
+            .position
+            .income_events
+            .iter()
+            .find(|event| event.id == id)
+        {
+            Some(event) => event.to_owned(),
+            None => {
+                return CommandResult::Error(tf!(
+                    "error.income_event_not_found",
+                    id,
+                    self.position.id
+                ))
+            }
+        };
+
+        render::render_single_income_event(&event);
+
+        let confirmation = match ask_confirmation(
+            tf!("confirm.delete_income_event", id).as_str(),
+            ConfirmationStatus::Rejected,
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if confirmation == ConfirmationStatus::Rejected {
+            return CommandResult::Ok;
+        }
+
+        if let Err(error) = self.position.remove_income_event(id) {
+            return CommandResult::Error(error);
+        }
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    fn handle_add_corporate_action(&mut self) -> CommandResult {
+        let kind_input = match ask_for_choice::<String>(
+            &t("prompt.corporate_action_type"),
+            &["split", "reverse", "rename"],
+        ) {
+            Ok(value) => value.to_lowercase(),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let kind = match kind_input.as_str() {
+            "s" | "split" | "reverse" | "r" | "reverse split" => {
+                let is_reverse = kind_input.starts_with('r');
+                let ratio = match ask_for_input::<f64>(&t(if is_reverse {
+                    "prompt.reverse_split_ratio"
+                } else {
+                    "prompt.split_ratio"
+                })) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                if !ratio.is_finite() || ratio <= 0f64 {
+                    return CommandResult::Error(t("error.invalid_split_ratio"));
+                }
+
+                if is_reverse {
+                    CorporateActionKind::ReverseSplit(ratio)
+                } else {
+                    CorporateActionKind::Split(ratio)
+                }
+            }
+            "n" | "rename" => {
+                let new_name = match ask_for_input::<String>(&t("prompt.new_position_name")) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                if new_name.is_empty() {
+                    return CommandResult::Error(t("error.empty_position_name"));
+                }
+
+                CorporateActionKind::Rename {
+                    old_name: self.position.name.clone(),
+                    new_name,
+                }
+            }
+            _ => {
+                return CommandResult::Error(tf!("error.invalid_corporate_action_type", kind_input))
+            }
+        };
+
+        let action = CorporateAction {
+            id: self.position.get_next_corporate_action_id(),
+            kind,
+            after_order_id: self.position.orders.last().unwrap().id,
+            date: chrono::Local::now(),
+        };
+        self.position.add_corporate_action(action);
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    fn handle_delete_corporate_action(&mut self, arg: Option<&String>) -> CommandResult {
+        let id = match parse_arg_or_get_from_input::<i32>(arg, &t("prompt.corporate_action_id")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let action = match self
+            .position
+            .corporate_actions
+            .iter()
+            .find(|action| action.id == id)
+        {
+            Some(action) => action.to_owned(),
+            None => {
+                return CommandResult::Error(tf!(
+                    "error.corporate_action_not_found",
+                    id,
+                    self.position.id
+                ))
+            }
+        };
+
+        render::render_corporate_actions_table(&[action]);
+
+        let confirmation = match ask_confirmation(
+            tf!("confirm.delete_corporate_action", id).as_str(),
+            ConfirmationStatus::Rejected,
+        ) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        if confirmation == ConfirmationStatus::Rejected {
+            return CommandResult::Ok;
+        }
+
+        if let Err(error) = self.position.remove_corporate_action(id) {
+            return CommandResult::Error(error);
+        }
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    fn handle_set_tags(&mut self, arg: Option<&String>) -> CommandResult {
+        let tags = match parse_arg_or_get_from_input::<String>(arg, &t("prompt.tags")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        self.position.set_tags(&tags);
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    // Shows outcome of an order on a copy of position, nothing is saved
+    fn handle_simulate(&self, arg: Option<&String>) -> CommandResult {
+        let simulation = match parse_arg_or_get_from_input::<String>(arg, &t("prompt.simulation")) {
+            Ok(value) => value.trim().to_lowercase(),
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let is_closing_all = matches!(simulation.as_str(), "a" | "all" | "t" | "target");
+        if is_closing_all && self.position.is_closed() {
+            return CommandResult::Error(t("error.position_closed"));
+        }
+
+        let (action, amount, price) = match simulation.as_str() {
+            "o" | "order" => {
+                let action_input = match ask_for_choice::<String>(
+                    &t("prompt.order_type_buy_sell"),
+                    &ORDER_TYPES,
+                ) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+                let action = match Action::from_string(action_input) {
+                    Ok(action) => action,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                let amount = match ask_for_valid_input(&t("prompt.order_amount"), |amount| {
+                    self.position.validate_order(action, *amount, 1f64)
+                }) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                let price = match ask_for_valid_input(&t("prompt.order_price"), |price| {
+                    validate_positive("price", *price)
+                }) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                (action, amount, price)
+            }
+            "a" | "all" => {
+                let price = match ask_for_valid_input(&t("prompt.close_price"), |price| {
+                    validate_positive("price", *price)
+                }) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                (self.position.action.opposite(), self.position.amount, price)
+            }
+            "t" | "target" => {
+                let percent = match ask_for_input::<f64>(&t("prompt.target_income_percent")) {
+                    Ok(value) => value,
+                    Err(error) => return CommandResult::Error(error),
+                };
+
+                let price = match self.position.calculate_price_for_income_percent(percent) {
+                    Some(price) => price,
+                    None => return CommandResult::Error(tf!("error.unreachable_income", percent)),
+                };
+
+                (self.position.action.opposite(), self.position.amount, price)
+            }
+            _ => return CommandResult::Error(tf!("error.unknown_simulation", simulation)),
+        };
+
+        let (simulated, order) = self.position.simulate_order(action, amount, price);
+        render::render_simulation(&self.position, &simulated, &order);
+
+        if let Err(error) = wait_for_enter() {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    fn handle_chart(&self) -> CommandResult {
+        render::render_position_chart(&self.position);
+
+        if let Err(error) = wait_for_enter() {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    fn handle_averaging(&self) -> CommandResult {
+        if self.position.is_closed() {
+            return CommandResult::Error(t("error.position_closed"));
+        }
+
+        let price = match ask_for_valid_input(&t("prompt.new_order_price"), |price| {
+            validate_positive("price", *price)
+        }) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let desired_avg_price = match ask_for_valid_input(&t("prompt.desired_avg_price"), |price| {
+            validate_positive("avg price", *price)
+        }) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        match self
+            .position
+            .calculate_amount_to_average(price, desired_avg_price)
+        {
+            Some(amount) => render::render_averaging(&self.position, price, amount),
+            None => {
+                return CommandResult::Error(tf!(
+                    "error.unreachable_avg_price",
+                    desired_avg_price,
+                    self.position.avg_price,
+                    price
+                ))
+            }
+        }
+
+        if let Err(error) = wait_for_enter() {
+            return CommandResult::Error(error);
+        }
+
+        CommandResult::Ok
+    }
+
+    fn handle_set_price_levels(&mut self) -> CommandResult {
+        let stop_loss = match ask_for_optional_price(&t("prompt.stop_loss")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        let take_profit = match ask_for_optional_price(&t("prompt.take_profit")) {
+            Ok(value) => value,
+            Err(error) => return CommandResult::Error(error),
+        };
+
+        self.position.stop_loss = stop_loss;
+        self.position.take_profit = take_profit;
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
+
+    fn handle_set_market_price(&mut self, arg: Option<&String>) -> CommandResult {
+        let price =
+            match parse_valid_arg_or_get_from_input(arg, &t("prompt.market_price"), |price| {
+                validate_positive("price", *price)
+            }) {
+                Ok(value) => value,
+                Err(error) => return CommandResult::Error(error),
+            };
+
+        self.position.set_market_price(price);
+
+        if let Err(error) = storage::save_position(self.position.clone()) {
+            exit_with_error(error);
+        };
+
+        CommandResult::Ok
+    }
 }
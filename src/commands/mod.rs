@@ -1,21 +1,29 @@
 pub mod managers;
+pub mod ui;
 pub mod utils;
 
-mod ui;
-
 use chrono::Local;
 use managers::{GlobalCommandManager, PositionCommandManager};
+use utils::aliases::{resolve_command, ResolvedCommand};
+use utils::parser::{parse_command, ParsedCommand};
 
-use crate::{exit_with_error, Position};
+use crate::i18n::t;
+use crate::options::get_options;
+use crate::storage::load_portfolios;
+use crate::utils::console::{clear_screen, wait_for_enter};
+use crate::utils::line_editor::Completions;
+use crate::{exit_with_error, tf, Position};
 
 pub enum ChangeEditMode {
-    EditPosition(Position),
-    PositionChanged(Position),
+    EditPosition(Box<Position>),
+    PositionChanged(Box<Position>),
+    // Position was closed by order larger than its amount, rest of the order opened new position
+    PositionFlipped(Box<Position>, Box<Position>),
 }
 
 pub enum EditMode {
     Global,
-    Position(Position),
+    Position(Box<Position>),
 }
 
 pub enum CommandResult {
@@ -36,7 +44,7 @@ pub struct CommandHandler {
 const DEFAULT_EDIT_MODE: EditMode = EditMode::Global;
 
 impl CommandHandler {
-    pub fn new(initial_positions: &Vec<Position>) -> CommandHandler {
+    pub fn new(initial_positions: &[Position]) -> CommandHandler {
         CommandHandler {
             global_handler: GlobalCommandManager::new(initial_positions),
             position_handler: None,
@@ -52,7 +60,7 @@ impl CommandHandler {
             }
             EditMode::Position(_) => {
                 if self.position_handler.is_none() {
-                    exit_with_error(String::from("Failed to draw position data"));
+                    exit_with_error(t("error.draw_position"));
                 }
 
                 self.position_handler.as_ref().unwrap().show_ui();
@@ -60,37 +68,120 @@ impl CommandHandler {
         }
     }
 
+    pub fn show_dashboard(&mut self) -> Result<(), String> {
+        let result = self.global_handler.handle_dashboard();
+        self.apply_result(result)
+    }
+
+    // Alerts are checked in all portfolios, not only in the current one
+    pub fn show_triggered_alerts(&self) -> Result<(), String> {
+        let alerts: Vec<(String, Position)> = load_portfolios()?
+            .into_iter()
+            .flat_map(|portfolio| {
+                let name = portfolio.name;
+                portfolio
+                    .positions
+                    .into_iter()
+                    .filter(|position| position.get_triggered_level().is_some())
+                    .map(move |position| (name.clone(), position))
+            })
+            .collect();
+
+        if alerts.is_empty() {
+            return Ok(());
+        }
+
+        clear_screen().expect("clear screen");
+        ui::render::render_triggered_alerts(&alerts);
+        wait_for_enter()
+    }
+
+    // Aliases and macros from options are resolved before command is dispatched to manager
     pub fn handle_command(&mut self, input: String) -> Result<(), String> {
-        let input_parts = input
-            .split(" ")
-            .map(|part| part.to_string())
-            .collect::<Vec<String>>();
-
-        let command = match input_parts.first() {
-            Some(value) => value.to_owned(),
-            None => return Err(String::from("No command specified")),
-        };
+        let options = get_options();
 
-        let arg = match input_parts.len() {
-            1 => None,
-            2 => input_parts.last(),
-            _ => return Err(String::from("Command can accept only 1 argument")),
+        let command = match parse_command(&input)? {
+            Some(parsed) => parsed,
+            None => return Ok(()),
         };
 
+        match resolve_command(command, &options.aliases, &options.macros)? {
+            ResolvedCommand::Command(command) => self.dispatch_command(command, false),
+            ResolvedCommand::Macro(name, lines) => {
+                for line in lines {
+                    let command = match parse_command(&line)? {
+                        Some(parsed) => parsed,
+                        None => continue,
+                    };
+
+                    let result = match resolve_command(command, &options.aliases, &options.macros)?
+                    {
+                        ResolvedCommand::Command(command) => self.dispatch_command(command, true),
+                        ResolvedCommand::Macro(other, _) => Err(tf!("error.nested_macro", other)),
+                    };
+
+                    if let Err(error) = result {
+                        return Err(tf!("error.macro_stopped", name, line, error));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    // Unknown commands are ignored in REPL, but stop macros
+    fn dispatch_command(&mut self, command: ParsedCommand, is_macro: bool) -> Result<(), String> {
+        let ParsedCommand { name, args } = command;
+        let unknown_error = tf!("error.unknown_command", name);
         let result = match self.edit_mode {
-            EditMode::Global => self.global_handler.handle_command(command, arg),
+            EditMode::Global => self.global_handler.handle_command(name, &args),
             EditMode::Position(_) => {
                 if self.position_handler.is_none() {
-                    exit_with_error(String::from("Failed to draw position data"));
+                    exit_with_error(t("error.draw_position"));
                 }
 
                 self.position_handler
                     .as_mut()
                     .unwrap()
-                    .handle_command(command, arg)
+                    .handle_command(name, &args)
             }
         };
 
+        if is_macro && matches!(result, CommandResult::CommandNotFound) {
+            return Err(unknown_error);
+        }
+
+        self.apply_result(result)
+    }
+
+    // Completions depend on edit mode, e.g. order ids are completed only in position mode
+    pub fn get_completions(&self) -> Completions {
+        let mut completions = match (&self.edit_mode, &self.position_handler) {
+            (EditMode::Position(_), Some(position_handler)) => position_handler.get_completions(),
+            _ => self.global_handler.get_completions(),
+        };
+
+        let options = get_options();
+        completions.commands.extend(options.aliases.into_keys());
+        completions.commands.extend(options.macros.into_keys());
+        completions
+    }
+
+    pub fn global_manager(&mut self) -> &mut GlobalCommandManager {
+        &mut self.global_handler
+    }
+
+    // Position manager exists only while position is edited
+    pub fn position_manager(&mut self) -> Option<&mut PositionCommandManager> {
+        match self.edit_mode {
+            EditMode::Global => None,
+            EditMode::Position(_) => self.position_handler.as_mut(),
+        }
+    }
+
+    /// Applies result of manager's command, e.g. switches edit mode
+    pub fn apply_result(&mut self, result: CommandResult) -> Result<(), String> {
         match result {
             CommandResult::Ok => Ok(()),
             CommandResult::CommandNotFound => Ok(()),
@@ -113,19 +204,30 @@ impl CommandHandler {
                 self.edit_mode = EditMode::Position(pos);
             }
             ChangeEditMode::PositionChanged(position) => {
-                let index = self
-                    .positions
-                    .iter()
-                    .position(|pos| pos.id == position.id)
-                    .expect("get index of changed position");
-
-                let mut pos = position.clone();
-                pos.edited_at = Local::now();
-
-                self.positions[index] = pos;
+                self.replace_changed_position(*position);
                 self.global_handler = GlobalCommandManager::new(&self.positions);
                 self.edit_mode = EditMode::Global;
             }
+            ChangeEditMode::PositionFlipped(closed_position, opened_position) => {
+                self.replace_changed_position(*closed_position);
+                self.positions.push(*opened_position.clone());
+                self.global_handler = GlobalCommandManager::new(&self.positions);
+                self.position_handler = Some(PositionCommandManager::new(&opened_position));
+                self.edit_mode = EditMode::Position(opened_position);
+            }
         };
     }
+
+    fn replace_changed_position(&mut self, position: Position) {
+        let index = self
+            .positions
+            .iter()
+            .position(|pos| pos.id == position.id)
+            .expect("get index of changed position");
+
+        let mut pos = position.clone();
+        pos.edited_at = Local::now();
+
+        self.positions[index] = pos;
+    }
 }
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Local};
+
+use crate::i18n::t;
+use crate::models::{Action, Order, Position};
+use crate::options::model::{Options, OrderColumn, PositionColumn};
+use crate::tf;
+use crate::utils::format::{add_currency_symbol, format_number};
+
+#[derive(Clone, Copy)]
+pub enum NumberKind {
+    Amount,
+    Money,
+    Percent,
+}
+
+/// Text of table cell, styled by each view on its own
+pub enum ColumnValue {
+    Text(String),
+    // Colored by sign of the number, positive numbers get plus sign
+    Signed(f64, String),
+    Status,
+}
+
+// Percents are rounded to 2 decimals, unless their column has its own rule
+pub fn format_column_number(
+    options: &Options,
+    column: &str,
+    kind: NumberKind,
+    number: f64,
+) -> String {
+    let tables = &options.tables;
+    let precision = tables.get_precision(column);
+    let number = match (kind, tables.precision.get(column)) {
+        (NumberKind::Percent, None) => round::round(number, 2),
+        _ => number,
+    };
+
+    let text = options.locale.localize_number(&format_number(
+        number,
+        precision,
+        tables.thousands_separator,
+    ));
+    match kind {
+        NumberKind::Amount => text,
+        NumberKind::Money => add_currency_symbol(&text, &tables.currency_symbol),
+        NumberKind::Percent => format!("{}%", text),
+    }
+}
+
+pub fn get_position_column_title(column: PositionColumn, options: &Options) -> String {
+    match column {
+        PositionColumn::Id => t("label.id"),
+        PositionColumn::Name => t("label.name"),
+        PositionColumn::Amount => t("label.amount"),
+        PositionColumn::AvgValue => t("label.avg_value"),
+        PositionColumn::AvgPrice => t("label.avg_price"),
+        PositionColumn::Income => t("label.income"),
+        PositionColumn::Payouts => t("label.payouts"),
+        PositionColumn::IncomePercent => String::from("%"),
+        PositionColumn::Status => t("label.status"),
+        PositionColumn::AnnualizedReturn => t("label.yearly_percent"),
+        PositionColumn::Xirr => t("label.xirr_percent"),
+        PositionColumn::BreakEven => t("label.break_even"),
+        PositionColumn::TargetPrice => tf!("label.target_price", options.income_target_percent),
+    }
+}
+
+pub fn get_position_column_value(
+    position: &Position,
+    column: PositionColumn,
+    options: &Options,
+    now: DateTime<Local>,
+) -> ColumnValue {
+    let format =
+        |kind: NumberKind, number: f64| format_column_number(options, column.name(), kind, number);
+    let income_value = |income: f64| ColumnValue::Signed(income, format(NumberKind::Money, income));
+    // Returns are unknown for some positions, e.g. ones opened today
+    let percent_value = |percent: Option<f64>| match percent {
+        Some(percent) => ColumnValue::Signed(percent, format(NumberKind::Percent, percent)),
+        None => ColumnValue::Text(String::from("-")),
+    };
+    // Prices are unknown for closed positions
+    let price_value = |price: Option<f64>| match price {
+        Some(price) => ColumnValue::Text(format(NumberKind::Money, price)),
+        None => ColumnValue::Text(String::from("-")),
+    };
+
+    match column {
+        PositionColumn::Id => ColumnValue::Text(position.id.to_string()),
+        PositionColumn::Name => ColumnValue::Text(position.name.clone()),
+        PositionColumn::Amount => ColumnValue::Text(format(NumberKind::Amount, position.amount)),
+        PositionColumn::AvgValue => {
+            ColumnValue::Text(format(NumberKind::Money, position.avg_value))
+        }
+        PositionColumn::AvgPrice => {
+            ColumnValue::Text(format(NumberKind::Money, position.avg_price))
+        }
+        PositionColumn::Income => income_value(position.income),
+        PositionColumn::Payouts => income_value(position.events_income),
+        PositionColumn::IncomePercent => percent_value(Some(position.calculate_income_percent())),
+        PositionColumn::Status => ColumnValue::Status,
+        PositionColumn::AnnualizedReturn => {
+            percent_value(position.calculate_annualized_return(now))
+        }
+        PositionColumn::Xirr => percent_value(position.calculate_xirr(now)),
+        PositionColumn::BreakEven => price_value(position.calculate_break_even_price()),
+        PositionColumn::TargetPrice => {
+            price_value(position.calculate_price_for_income_percent(options.income_target_percent))
+        }
+    }
+}
+
+// Orders keep original amounts, so amounts in current units are shown next to them,
+// if position has splits
+pub fn get_order_columns(position: &Position, options: &Options) -> Vec<OrderColumn> {
+    let has_splits = position
+        .corporate_actions
+        .iter()
+        .any(|action| action.get_amount_factor() != 1f64);
+
+    options
+        .get_order_columns()
+        .into_iter()
+        .filter(|column| {
+            has_splits
+                || !matches!(
+                    column,
+                    OrderColumn::AdjustedAmount | OrderColumn::AdjustedPrice
+                )
+        })
+        .collect()
+}
+
+pub fn get_order_column_title(column: OrderColumn) -> String {
+    match column {
+        OrderColumn::Id => t("label.id"),
+        OrderColumn::Date => t("label.date"),
+        OrderColumn::Type => t("label.type"),
+        OrderColumn::Amount => t("label.amount"),
+        OrderColumn::Value => t("label.value"),
+        OrderColumn::Price => t("label.price"),
+        OrderColumn::Income => t("label.income"),
+        OrderColumn::AdjustedAmount => t("label.adjusted_amount"),
+        OrderColumn::AdjustedPrice => t("label.adjusted_price"),
+    }
+}
+
+pub fn get_order_type(order: &Order) -> String {
+    match order.action {
+        Action::Long => t("label.buy"),
+        Action::Short => t("label.sell"),
+    }
+}
+
+pub fn get_order_column_value(
+    position: &Position,
+    order: &Order,
+    column: OrderColumn,
+    options: &Options,
+) -> ColumnValue {
+    let format =
+        |kind: NumberKind, number: f64| format_column_number(options, column.name(), kind, number);
+
+    match column {
+        OrderColumn::Id => ColumnValue::Text(order.id.to_string()),
+        OrderColumn::Date => ColumnValue::Text(options.locale.format_date_time(&order.date)),
+        OrderColumn::Type => ColumnValue::Text(get_order_type(order)),
+        OrderColumn::Amount => ColumnValue::Text(format(NumberKind::Amount, order.amount)),
+        OrderColumn::Value => ColumnValue::Text(format(NumberKind::Money, order.value)),
+        OrderColumn::Price => ColumnValue::Text(format(NumberKind::Money, order.price)),
+        OrderColumn::Income => {
+            if position.action == order.action {
+                ColumnValue::Text(String::from("-"))
+            } else {
+                ColumnValue::Signed(order.income, format(NumberKind::Money, order.income))
+            }
+        }
+        OrderColumn::AdjustedAmount => {
+            let factor = position.get_split_factor_after(order.id);
+            ColumnValue::Text(format(NumberKind::Amount, order.amount * factor))
+        }
+        OrderColumn::AdjustedPrice => {
+            let factor = position.get_split_factor_after(order.id);
+            ColumnValue::Text(format(NumberKind::Money, order.price / factor))
+        }
+    }
+}
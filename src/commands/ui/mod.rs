@@ -1 +1,2 @@
+pub mod columns;
 pub mod render;
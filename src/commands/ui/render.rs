@@ -1,246 +1,1170 @@
+use chrono::{DateTime, Local};
 use colored::Colorize;
-use prettytable::{cell, color, row, Attr, Cell, Row, Table};
+use prettytable::{cell, format, row, Attr, Cell, Row, Table};
 use std::cmp::Ordering;
 
-use crate::models::{Action, Order, Position};
+use super::columns::{
+    format_column_number, get_order_column_title, get_order_column_value, get_order_columns,
+    get_position_column_title, get_position_column_value, ColumnValue, NumberKind,
+};
+use crate::commands::utils::allocation::{AllocationRow, RebalancingOrder, OTHER_ALLOCATION_LABEL};
+use crate::commands::utils::dashboard::{Dashboard, RankedPosition};
+use crate::commands::utils::filtering::PositionsFilter;
+use crate::commands::utils::income_report::IncomeReport;
+use crate::commands::utils::tax_report::TaxReport;
+use crate::i18n::t;
+use crate::models::{
+    Action, CashEntryKind, CorporateAction, IncomeEvent, Order, Portfolio, Position, PriceLevel,
+};
 use crate::options::get_options;
-use crate::utils::console::clear_screen;
-use crate::utils::math::round;
+use crate::options::model::{Options, OrderColumn, PositionColumn, Precision};
+use crate::tf;
+use crate::utils::chart::{draw_bar_chart, draw_line_chart, ChartSeries};
+use crate::utils::console::{clear_screen, get_terminal_width, print_error};
+use crate::utils::format::format_number;
+use crate::utils::locale::Locale;
 use crate::utils::pagination::{draw_page_counter, get_pages_count, select_items_for_page};
+use crate::utils::theme::{cell_colors, is_plain_output, is_styled_output, Paint, Role};
 
-pub fn render_positions_table(positions: &Vec<Position>, page: i32) {
+// Rows of line charts, without axes and labels
+const CHART_HEIGHT: usize = 12;
+
+pub fn render_positions_table(
+    positions: &Vec<Position>,
+    page: i32,
+    filter: Option<&PositionsFilter>,
+    portfolio: &Portfolio,
+) {
     clear_screen().expect("clean screen");
+
+    println!(
+        "{}{}",
+        t("label.portfolio_prefix").bold().paint(Role::Muted),
+        portfolio.name.bold()
+    );
+
+    if let Some(filter) = filter {
+        println!(
+            "{}{}",
+            t("label.filter_prefix").bold().paint(Role::Muted),
+            filter.expression.bold().paint(Role::Accent)
+        );
+    }
+
+    let options = get_options();
+    let locale = options.locale;
+    let now = Local::now();
+    let columns = options.get_position_columns();
+
     let mut table = Table::new();
-    table.add_row(row![
-        "Id",
-        "Name",
-        "Amount",
-        "Avg value",
-        "Avg price",
-        "Income",
-        "%",
-        "Status"
-    ]);
+    table.add_row(get_positions_header(&columns, &options));
 
     let mut reversed_positions = positions.to_vec();
     reversed_positions.reverse();
 
-    let positions_per_page = get_options().positions_per_page;
+    let positions_per_page = options.positions_per_page;
     let positions_to_draw = select_items_for_page(reversed_positions, page, positions_per_page);
 
     positions_to_draw.iter().for_each(|position| {
-        table.add_row(Row::new(vec![
-            cell!(position.id),
-            cell!(position.name),
-            cell!(round(position.amount).unwrap()),
-            cell!(round(position.avg_value).unwrap()),
-            cell!(round(position.avg_price).unwrap()),
-            get_styled_income_cell(round(position.income).unwrap(), None),
-            get_styled_income_cell(
-                round::round(position.calculate_income_percent(), 2),
-                Some(String::from("%")),
-            ),
-            get_status_cell(position),
-        ]));
+        table.add_row(Row::new(
+            columns
+                .iter()
+                .map(|column| get_position_cell(position, *column, &options, now))
+                .collect(),
+        ));
     });
 
+    let format_value = |number: f64| {
+        format_column_number(
+            &options,
+            PositionColumn::AvgValue.name(),
+            NumberKind::Money,
+            number,
+        )
+    };
+
     // Add total row
     let (value, income) = calculate_total(positions);
+    let events_income: f64 = positions
+        .iter()
+        .map(|position| position.events_income)
+        .sum();
+    let income_cell = |column: PositionColumn, income: f64| {
+        let text = format_column_number(&options, column.name(), NumberKind::Money, income);
+        (column, get_styled_number_cell(income, text))
+    };
+    table.add_row(get_summary_row(
+        &columns,
+        &t("label.total"),
+        vec![
+            (PositionColumn::AvgValue, cell!(format_value(value))),
+            income_cell(PositionColumn::Income, income),
+            income_cell(PositionColumn::Payouts, events_income),
+        ],
+    ));
+
+    // Cash and equity are calculated for the whole portfolio, regardless of filter
+    if portfolio.is_cash_tracked() {
+        let cash = portfolio.calculate_cash_balance();
+        let margin = if portfolio.allow_margin {
+            t("label.margin")
+        } else {
+            String::from("-")
+        };
+        table.add_row(get_summary_row(
+            &columns,
+            &t("label.cash"),
+            vec![
+                (PositionColumn::Name, cell!(margin)),
+                (
+                    PositionColumn::AvgValue,
+                    get_cash_cell(cash, format_value(cash)),
+                ),
+            ],
+        ));
+        table.add_row(get_summary_row(
+            &columns,
+            &t("label.equity"),
+            vec![(
+                PositionColumn::AvgValue,
+                cell!(format_value(portfolio.calculate_equity())),
+            )],
+        ));
+        table.add_row(get_summary_row(
+            &columns,
+            &t("label.twr"),
+            vec![(
+                PositionColumn::AvgValue,
+                get_optional_percent_cell(portfolio.calculate_time_weighted_return(), locale),
+            )],
+        ));
+    }
+
+    print_table(&mut table);
+
+    draw_page_counter(page, get_pages_count(positions.len(), positions_per_page));
+}
+
+pub fn render_portfolios_table(portfolios: &[Portfolio]) {
+    let locale = get_options().locale;
+    clear_screen().expect("clean screen");
+    println!("{}", t("label.all_accounts").bold());
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.id"),
+        t("label.name"),
+        t("label.positions"),
+        t("label.active"),
+        t("label.avg_value"),
+        t("label.income"),
+        t("label.cash"),
+        t("label.equity"),
+        t("label.twr")
+    ]);
+
+    let mut all_positions = vec![];
+    let mut total_cash = 0f64;
+    let mut total_equity = 0f64;
+    portfolios.iter().for_each(|portfolio| {
+        let (value, income) = calculate_total(&portfolio.positions);
+        let (cash_cell, equity_cell) = if portfolio.is_cash_tracked() {
+            let cash = portfolio.calculate_cash_balance();
+            let equity = portfolio.calculate_equity();
+            total_cash += cash;
+            total_equity += equity;
+            (
+                get_cash_cell(cash, format_rounded(cash, locale)),
+                cell!(format_rounded(equity, locale)),
+            )
+        } else {
+            total_equity += value;
+            (cell!("-"), cell!(format_rounded(value, locale)))
+        };
+
+        table.add_row(Row::new(vec![
+            cell!(portfolio.id),
+            cell!(portfolio.name),
+            cell!(portfolio.positions.len()),
+            cell!(portfolio.count_active_positions()),
+            cell!(format_rounded(value, locale)),
+            get_styled_income_cell(income, None, locale),
+            cash_cell,
+            equity_cell,
+            get_optional_percent_cell(portfolio.calculate_time_weighted_return(), locale),
+        ]));
+        all_positions.extend(portfolio.positions.iter().cloned());
+    });
+
+    let (value, income) = calculate_total(&all_positions);
     table.add_row(Row::new(vec![
-        cell!("Total"),
+        cell!(t("label.total")),
         cell!("-"),
+        cell!(all_positions.len()),
+        cell!(all_positions
+            .iter()
+            .filter(|position| !position.is_closed())
+            .count()),
+        cell!(format_rounded(value, locale)),
+        get_styled_income_cell(income, None, locale),
+        get_cash_cell(total_cash, format_rounded(total_cash, locale)),
+        cell!(format_rounded(total_equity, locale)),
         cell!("-"),
-        cell!(round(value).unwrap()),
+    ]));
+
+    print_table(&mut table);
+}
+
+pub fn render_cash_ledger(portfolio: &Portfolio) {
+    let locale = get_options().locale;
+    clear_screen().expect("clear screen");
+    println!("{}", tf!("label.cash_ledger_title", portfolio.name.bold()));
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.id"),
+        t("label.date"),
+        t("label.type"),
+        t("label.amount")
+    ]);
+
+    portfolio.cash_entries.iter().for_each(|entry| {
+        table.add_row(Row::new(vec![
+            cell!(entry.id),
+            cell!(locale.format_date_time(&entry.date)),
+            cell!(match entry.kind {
+                CashEntryKind::Deposit => t("label.deposit"),
+                CashEntryKind::Withdrawal => t("label.withdrawal"),
+            }),
+            get_styled_income_cell(entry.signed_amount(), None, locale),
+        ]));
+    });
+
+    table.add_row(Row::new(vec![
+        cell!(t("label.deposited")),
         cell!("-"),
-        get_styled_income_cell(round(income).unwrap(), None),
         cell!("-"),
+        cell!(format_rounded(portfolio.calculate_net_deposits(), locale)),
+    ]));
+    table.add_row(Row::new(vec![
+        cell!(t("label.cash")),
+        cell!("-"),
+        cell!("-"),
+        get_cash_cell(
+            portfolio.calculate_cash_balance(),
+            format_rounded(portfolio.calculate_cash_balance(), locale),
+        ),
     ]));
 
-    table.printstd();
+    print_table(&mut table);
 
-    draw_page_counter(page, get_pages_count(positions.len(), positions_per_page));
+    println!(
+        "{}",
+        match portfolio.allow_margin {
+            true => t("info.margin_allowed"),
+            false => t("info.margin_not_allowed"),
+        }
+    );
 }
 
 pub fn render_help_tooltip() {
-    println!("{}", "Type 'h' for help".italic().bright_black());
+    println!("{}", t("help.tooltip").italic().paint(Role::Muted));
 }
 
+// Command, its arguments and message key of description
+type HelpEntry = (&'static str, &'static str, &'static str);
+
+const GLOBAL_HELP_SECTIONS: [(&str, &[HelpEntry]); 5] = [
+    (
+        "help.available_commands",
+        &[
+            ("h", "", "help.show_help"),
+            ("q", "", "help.exit"),
+            ("a", "[name] [type] [amount] [value]", "help.add_position"),
+            ("e", "[id]", "help.edit_position"),
+            ("d", "[id]", "help.delete_position"),
+            ("cs", "", "help.change_sorting"),
+            ("f", "[expression]", "help.filter_positions"),
+            ("n", "", "help.next_page"),
+            ("p", "", "help.previous_page"),
+        ],
+    ),
+    (
+        "help.portfolios",
+        &[
+            ("pa", "", "help.add_portfolio"),
+            ("ps", "[id/all]", "help.switch_portfolio"),
+            ("pr", "[id] [name]", "help.rename_portfolio"),
+            ("pd", "[id]", "help.delete_portfolio"),
+        ],
+    ),
+    (
+        "help.cash",
+        &[
+            ("dep", "[amount]", "help.deposit"),
+            ("wd", "[amount]", "help.withdraw"),
+            ("cash", "", "help.cash_ledger"),
+            ("mp", "[name]", "help.set_market_prices"),
+        ],
+    ),
+    (
+        "help.allocation",
+        &[
+            ("tw", "[weights]", "help.target_weights"),
+            ("alloc", "", "help.show_allocation"),
+            ("margin", "", "help.toggle_margin"),
+        ],
+    ),
+    (
+        "help.reports",
+        &[
+            ("r", "[period]", "help.income_report"),
+            ("tax", "[year]", "help.tax_report"),
+            ("ch", "", "help.portfolio_charts"),
+            ("db", "", "help.dashboard"),
+        ],
+    ),
+];
+
+const POSITION_HELP_SECTIONS: [(&str, &[HelpEntry]); 1] = [(
+    "help.available_commands",
+    &[
+        ("h", "", "help.show_help"),
+        ("q", "", "help.return_to_positions"),
+        ("a", "[type] [amount] [value]", "help.add_order"),
+        ("d", "[id]", "help.delete_order"),
+        ("ai", "", "help.add_income_event"),
+        ("di", "[id]", "help.delete_income_event"),
+        ("ca", "", "help.add_corporate_action"),
+        ("dc", "[id]", "help.delete_corporate_action"),
+        ("s", "[o/a/t]", "help.simulate"),
+        ("lv", "", "help.price_levels"),
+        ("mp", "[price]", "help.set_market_price"),
+        ("avg", "", "help.averaging"),
+        ("ch", "", "help.position_chart"),
+        ("t", "[tags]", "help.set_tags"),
+        ("n", "", "help.next_page"),
+        ("p", "", "help.previous_page"),
+    ],
+)];
+
 pub fn render_global_help_page() {
     clear_screen().expect("clear screen");
-    println!("{}\n", "Available commands:".bold());
-    println!("{} - {}", "h".bold().yellow(), "Show help page");
-    println!("{} - {}", "q".bold().yellow(), "Exit application");
-    println!("{} - {}", "a".bold().yellow(), "Add new position");
-    println!(
-        "{} {} - {}",
-        "e".bold().yellow(),
-        "[id]".bold(),
-        "Edit position"
-    );
-    println!(
-        "{} {} - {}",
-        "d".bold().yellow(),
-        "[id]".bold(),
-        "Delete position"
-    );
-    println!("{} - {}", "cs".bold().yellow(), "Change sorting");
-    println!("{} - {}", "n".bold().yellow(), "Show next page");
-    println!("{} - {}", "p".bold().yellow(), "Show previous page");
-    println!();
+    render_help_sections(&GLOBAL_HELP_SECTIONS);
+    render_aliases_help();
+    render_arguments_help();
+}
+
+fn render_help_sections(sections: &[(&str, &[HelpEntry])]) {
+    for (title, entries) in sections {
+        println!("{}\n", t(title).bold());
+        for (command, arguments, description) in entries.iter() {
+            if arguments.is_empty() {
+                println!(
+                    "{} - {}",
+                    command.bold().paint(Role::Accent),
+                    t(description)
+                );
+            } else {
+                println!(
+                    "{} {} - {}",
+                    command.bold().paint(Role::Accent),
+                    arguments.bold(),
+                    t(description)
+                );
+            }
+        }
+        println!();
+    }
+}
+
+// Aliases and macros are set in options
+fn render_aliases_help() {
+    let options = get_options();
+    if !options.aliases.is_empty() {
+        println!("{}\n", t("help.aliases").bold());
+        options.aliases.iter().for_each(|(name, command)| {
+            println!("{} - {}", name.bold().paint(Role::Accent), command);
+        });
+        println!();
+    }
+
+    if !options.macros.is_empty() {
+        println!("{}\n", t("help.macros").bold());
+        options.macros.iter().for_each(|(name, commands)| {
+            println!(
+                "{} - {}",
+                name.bold().paint(Role::Accent),
+                commands.join("; ")
+            );
+        });
+        println!();
+    }
+}
+
+fn render_arguments_help() {
+    println!("{}", t("help.arguments").italic().paint(Role::Muted));
 }
 
 pub fn render_edit_position_help_page() {
     clear_screen().expect("clear screen");
-    println!("{}\n", "Available commands:".bold());
-    println!("{} - {}", "h".bold().yellow(), "Show help page");
-    println!("{} - {}", "q".bold().yellow(), "Return to positions");
-    println!("{} - {}", "a".bold().yellow(), "Add new order");
+    render_help_sections(&POSITION_HELP_SECTIONS);
+    render_aliases_help();
+    render_arguments_help();
+}
+
+pub fn render_single_position(position: &Position) {
+    let options = get_options();
+    let columns = options.get_position_columns();
+
+    let mut table = Table::new();
+    table.add_row(get_positions_header(&columns, &options));
+    table.add_row(Row::new(
+        columns
+            .iter()
+            .map(|column| get_position_cell(position, *column, &options, Local::now()))
+            .collect(),
+    ));
+
+    print_table(&mut table);
+}
+
+pub fn render_position_info(position: &Position, page: i32) {
+    let options = get_options();
+    let position_columns = options.get_position_columns();
+
+    let mut position_table = Table::new();
+    position_table.add_row(get_positions_header(&position_columns, &options));
+    position_table.add_row(Row::new(
+        position_columns
+            .iter()
+            .map(|column| get_position_cell(position, *column, &options, Local::now()))
+            .collect(),
+    ));
+
+    let order_columns = get_order_columns(position, &options);
+    let mut orders_table = Table::new();
+    orders_table.add_row(get_orders_header(&order_columns));
+
+    let orders_per_page = options.orders_per_page;
+    let orders_to_draw = select_items_for_page(position.orders.clone(), page, orders_per_page);
+
+    orders_to_draw.iter().for_each(|order| {
+        orders_table.add_row(Row::new(
+            order_columns
+                .iter()
+                .map(|column| get_order_cell(position, order, *column, &options))
+                .collect(),
+        ));
+    });
+
+    clear_screen().expect("clear screen");
+
+    print!("{} ", tf!("label.position_title", position.id));
+    match position.action {
+        Action::Long => print!("{} ", t("label.long").bold().paint(Role::Positive)),
+        Action::Short => println!("{} ", t("label.short").bold().paint(Role::Negative)),
+    }
+    print!("{} ", position.name.bold());
+
     println!(
-        "{} {} - {}",
-        "d".bold().yellow(),
-        "[id]".bold(),
-        "Delete order"
+        "{}{}",
+        t("label.last_edited_at_prefix").bold().paint(Role::Muted),
+        options
+            .locale
+            .format_date_time(&position.edited_at)
+            .bold()
+            .paint(Role::Muted)
     );
+
+    if !position.tags.is_empty() {
+        println!(
+            "{}{}",
+            t("label.tags_prefix").bold().paint(Role::Muted),
+            position.tags.join(", ").paint(Role::Accent)
+        );
+    }
+
+    print_table(&mut position_table);
+
+    if !position.is_closed() {
+        render_price_levels_panel(position);
+    }
+
+    println!(); // Gap between tables
+
     println!(
-        "{} {} - {}",
-        "n".bold().yellow(),
-        "[id]".bold(),
-        "Show next page"
+        "{}",
+        tf!("label.position_orders", position.id.to_string().bold())
+    );
+    print_table(&mut orders_table);
+
+    draw_page_counter(
+        page,
+        get_pages_count(position.orders.len(), orders_per_page),
     );
-    println!("{} - {}", "n".bold().yellow(), "Show previous page");
-    println!();
+
+    if !position.income_events.is_empty() {
+        println!();
+        println!(
+            "{}",
+            tf!(
+                "label.position_income_events",
+                position.id.to_string().bold()
+            )
+        );
+        render_income_events_table(position);
+    }
+
+    if !position.corporate_actions.is_empty() {
+        println!();
+        println!(
+            "{}",
+            tf!(
+                "label.position_corporate_actions",
+                position.id.to_string().bold()
+            )
+        );
+        render_corporate_actions_table(&position.corporate_actions);
+    }
 }
 
-pub fn render_single_position(position: &Position) {
+pub fn render_corporate_actions_table(actions: &[CorporateAction]) {
+    let locale = get_options().locale;
     let mut table = Table::new();
     table.add_row(row![
-        "Id",
-        "Name",
-        "Amount",
-        "Avg value",
-        "Avg price",
-        "Income"
+        t("label.id"),
+        t("label.date"),
+        t("label.action"),
+        t("label.after_order")
     ]);
 
+    actions.iter().for_each(|action| {
+        table.add_row(Row::new(vec![
+            cell!(action.id),
+            cell!(locale.format_date_time(&action.date)),
+            cell!(action.kind.to_string()),
+            cell!(action.after_order_id),
+        ]));
+    });
+
+    print_table(&mut table);
+}
+
+fn render_income_events_table(position: &Position) {
+    let locale = get_options().locale;
+    let mut table = Table::new();
+    table.add_row(get_income_events_header());
+
+    position.income_events.iter().for_each(|event| {
+        table.add_row(Row::new(vec![
+            cell!(event.id),
+            cell!(locale.format_date_time(&event.date)),
+            cell!(event.kind.to_string()),
+            cell!(event.after_order_id),
+            get_styled_income_cell(event.cash, None, locale),
+            cell!(format_rounded(event.units, locale)),
+        ]));
+    });
+
+    print_table(&mut table);
+}
+
+pub fn render_single_income_event(event: &IncomeEvent) {
+    let locale = get_options().locale;
+    let mut table = Table::new();
+    table.add_row(get_income_events_header());
     table.add_row(Row::new(vec![
-        cell!(position.id),
-        cell!(position.name),
-        cell!(round(position.amount).unwrap()),
-        cell!(round(position.avg_value).unwrap()),
-        cell!(round(position.avg_price).unwrap()),
-        get_styled_income_cell(round(position.income).unwrap(), None),
+        cell!(event.id),
+        cell!(locale.format_date_time(&event.date)),
+        cell!(event.kind.to_string()),
+        cell!(event.after_order_id),
+        get_styled_income_cell(event.cash, None, locale),
+        cell!(format_rounded(event.units, locale)),
     ]));
 
-    table.printstd();
+    print_table(&mut table);
 }
 
-pub fn render_position_info(position: &Position, page: i32) {
-    let mut position_table = Table::new();
-    position_table.add_row(row![
-        "Id",
-        "Name",
-        "Amount",
-        "Avg value",
-        "Avg price",
-        "Income"
+pub fn render_single_order(position: &Position, order: &Order) {
+    let options = get_options();
+    let columns = get_order_columns(position, &options);
+
+    let mut table = Table::new();
+    table.add_row(get_orders_header(&columns));
+    table.add_row(Row::new(
+        columns
+            .iter()
+            .map(|column| get_order_cell(position, order, *column, &options))
+            .collect(),
+    ));
+
+    print_table(&mut table);
+}
+
+pub fn render_income_report(report: &IncomeReport) {
+    let locale = get_options().locale;
+    clear_screen().expect("clear screen");
+
+    if report.rows.is_empty() {
+        println!("{}", t("info.no_realized_income").italic());
+        return;
+    }
+
+    let mut table = Table::new();
+    let mut header = Row::new(vec![cell!(t("label.period"))]);
+    report
+        .groups
+        .iter()
+        .for_each(|group| header.add_cell(cell!(group)));
+    header.add_cell(cell!(t("label.total")));
+    header.add_cell(cell!(t("label.cumulative")));
+    table.add_row(header);
+
+    report.rows.iter().for_each(|row| {
+        let mut table_row = Row::new(vec![cell!(row.period)]);
+        row.income_by_group
+            .iter()
+            .for_each(|income| table_row.add_cell(get_styled_income_cell(*income, None, locale)));
+        table_row.add_cell(get_styled_income_cell(row.total, None, locale));
+        table_row.add_cell(get_styled_income_cell(row.cumulative, None, locale));
+        table.add_row(table_row);
+    });
+
+    let mut total_row = Row::new(vec![cell!(t("label.total"))]);
+    report
+        .calculate_group_totals()
+        .iter()
+        .for_each(|income| total_row.add_cell(get_styled_income_cell(*income, None, locale)));
+    total_row.add_cell(get_styled_income_cell(
+        report.calculate_total(),
+        None,
+        locale,
+    ));
+    total_row.add_cell(cell!("-"));
+    table.add_row(total_row);
+
+    print_table(&mut table);
+}
+
+pub fn render_tax_report(report: &TaxReport) {
+    let locale = get_options().locale;
+    clear_screen().expect("clear screen");
+    match report.year {
+        Some(year) => println!("{}", tf!("label.disposals_in", year).bold()),
+        None => println!("{}", t("label.all_disposals").bold()),
+    }
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.position"),
+        t("label.name"),
+        t("label.amount"),
+        t("label.acquired"),
+        t("label.disposed"),
+        t("label.proceeds"),
+        t("label.cost_basis"),
+        t("label.gain"),
+        t("label.term")
     ]);
 
-    position_table.add_row(Row::new(vec![
-        cell!(position.id),
-        cell!(position.name),
-        cell!(round(position.amount).unwrap()),
-        cell!(round(position.avg_value).unwrap()),
-        cell!(round(position.avg_price).unwrap()),
-        get_styled_income_cell(round(position.income).unwrap(), None),
+    report.disposals.iter().for_each(|disposal| {
+        table.add_row(Row::new(vec![
+            cell!(disposal.position_id),
+            cell!(disposal.name),
+            cell!(format_rounded(disposal.amount, locale)),
+            cell!(locale.format_date(&disposal.acquired_at)),
+            cell!(locale.format_date(&disposal.disposed_at)),
+            cell!(format_rounded(disposal.proceeds, locale)),
+            cell!(format_rounded(disposal.cost_basis, locale)),
+            get_styled_income_cell(disposal.gain, None, locale),
+            cell!(if disposal.is_long_term {
+                t("label.long")
+            } else {
+                t("label.short")
+            }),
+        ]));
+    });
+    print_table(&mut table);
+
+    let summary = report.calculate_summary();
+    let mut summary_table = Table::new();
+    summary_table.add_row(row![
+        t("label.proceeds"),
+        t("label.cost_basis"),
+        t("label.short_term"),
+        t("label.long_term"),
+        t("label.total")
+    ]);
+    summary_table.add_row(Row::new(vec![
+        cell!(format_rounded(summary.proceeds, locale)),
+        cell!(format_rounded(summary.cost_basis, locale)),
+        get_styled_income_cell(summary.short_term_gain, None, locale),
+        get_styled_income_cell(summary.long_term_gain, None, locale),
+        get_styled_income_cell(summary.total_gain(), None, locale),
     ]));
+    print_table(&mut summary_table);
+}
 
-    let mut orders_table = Table::new();
+fn render_price_levels_panel(position: &Position) {
+    let locale = get_options().locale;
+    let target_percent = get_options().income_target_percent;
+    let format_price = |price: Option<f64>| match price {
+        Some(price) => format_rounded(price, locale),
+        None => String::from("-"),
+    };
 
-    orders_table.add_row(row!["Id", "Type", "Amount", "Value", "Price", "Income"]);
+    println!(
+        "{}{}   {}{}",
+        t("label.break_even_prefix").bold().paint(Role::Muted),
+        format_price(position.calculate_break_even_price()).bold(),
+        tf!("label.target_prefix", target_percent)
+            .bold()
+            .paint(Role::Muted),
+        format_price(position.calculate_price_for_income_percent(target_percent)).bold()
+    );
 
-    let orders_per_page = get_options().orders_per_page;
-    let orders_to_draw = select_items_for_page(position.orders.clone(), page, orders_per_page);
+    println!(
+        "{}{}   {}{}   {}{}{}",
+        t("label.stop_loss_prefix").bold().paint(Role::Muted),
+        format_price(position.stop_loss).bold(),
+        t("label.take_profit_prefix").bold().paint(Role::Muted),
+        format_price(position.take_profit).bold(),
+        t("label.market_price_prefix").bold().paint(Role::Muted),
+        format_price(position.market_price).bold(),
+        match position.market_price_updated_at {
+            Some(date) => format!(" ({})", locale.format_date_time(&date))
+                .paint(Role::Muted)
+                .to_string(),
+            None => String::new(),
+        }
+    );
 
-    orders_to_draw.iter().for_each(|order| {
-        let order_type = match order.action {
-            Action::Long => "Buy",
-            Action::Short => "Sell",
+    if let Some(level) = position.get_triggered_level() {
+        println!(
+            "{}",
+            tf!("info.level_triggered", level).bold().paint(Role::Alert)
+        );
+    }
+}
+
+/// Lists active positions of all portfolios, which market price crossed their levels.
+/// Positions are given with names of their portfolios.
+pub fn render_triggered_alerts(alerts: &[(String, Position)]) {
+    let locale = get_options().locale;
+    println!(
+        "{}\n",
+        t("label.triggered_alerts").bold().paint(Role::Negative)
+    );
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.portfolio"),
+        t("label.id"),
+        t("label.name"),
+        t("label.market_price"),
+        t("label.stop_loss"),
+        t("label.take_profit"),
+        t("label.alert")
+    ]);
+
+    alerts.iter().for_each(|(portfolio_name, position)| {
+        let format_price = |price: Option<f64>| match price {
+            Some(price) => cell!(format_rounded(price, locale)),
+            None => cell!("-"),
         };
 
-        let income_cell = if position.action == order.action {
-            cell!(String::from("-"))
+        table.add_row(Row::new(vec![
+            cell!(portfolio_name),
+            cell!(position.id),
+            cell!(position.name),
+            format_price(position.market_price),
+            format_price(position.stop_loss),
+            format_price(position.take_profit),
+            get_status_cell(position),
+        ]));
+    });
+
+    print_table(&mut table);
+}
+
+pub fn render_averaging(position: &Position, price: f64, amount: f64) {
+    let locale = get_options().locale;
+    let (averaged, order) = position.simulate_order(position.action, amount, price);
+    println!(
+        "{}",
+        tf!(
+            "info.averaging_result",
+            format_rounded(amount, locale).paint(Role::Accent),
+            format_rounded(order.value, locale).paint(Role::Accent),
+            format_rounded(averaged.avg_price, locale)
+        )
+        .bold()
+    );
+}
+
+pub fn render_allocation(rows: &[AllocationRow]) {
+    let locale = get_options().locale;
+    clear_screen().expect("clear screen");
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.target"),
+        t("label.value"),
+        t("label.weight"),
+        t("label.target_percent"),
+        t("label.drift"),
+        t("label.rebalance")
+    ]);
+
+    rows.iter().for_each(|row| {
+        let rebalance_cell = if !row.is_rebalanceable() {
+            paint_cell(cell!(t("label.no_positions")), Role::Muted)
+        } else if row.rebalance_value > 0f64 {
+            paint_cell(
+                cell!(tf!(
+                    "label.buy_value",
+                    format_rounded(row.rebalance_value, locale)
+                )),
+                Role::Positive,
+            )
+        } else if row.rebalance_value < 0f64 {
+            paint_cell(
+                cell!(tf!(
+                    "label.sell_value",
+                    format_rounded(-row.rebalance_value, locale)
+                )),
+                Role::Negative,
+            )
         } else {
-            get_styled_income_cell(round(order.income).unwrap(), None)
+            cell!("-")
         };
 
+        table.add_row(Row::new(vec![
+            cell!(if row.label == OTHER_ALLOCATION_LABEL {
+                t("label.other")
+            } else {
+                row.label.clone()
+            }),
+            cell!(format_rounded(row.value, locale)),
+            cell!(format!(
+                "{}%",
+                format_rounded(round::round(row.weight, 2), locale)
+            )),
+            cell!(format!(
+                "{}%",
+                format_rounded(round::round(row.target_weight, 2), locale)
+            )),
+            get_styled_income_cell(
+                round::round(row.calculate_drift(), 2),
+                Some(String::from("%")),
+                locale,
+            ),
+            rebalance_cell,
+        ]));
+    });
+    print_table(&mut table);
+
+    let orders: Vec<&RebalancingOrder> = rows.iter().flat_map(|row| row.orders.iter()).collect();
+    if orders.is_empty() {
+        return;
+    }
+
+    println!("\n{}", t("label.rebalancing_orders").bold());
+    let mut orders_table = Table::new();
+    orders_table.add_row(row![
+        t("label.position"),
+        t("label.name"),
+        t("label.type"),
+        t("label.amount"),
+        t("label.price"),
+        t("label.value")
+    ]);
+    orders.iter().for_each(|order| {
         orders_table.add_row(Row::new(vec![
-            cell!(order.id),
-            cell!(order_type),
-            cell!(round(order.amount).unwrap()),
-            cell!(round(order.value).unwrap()),
-            cell!(round(order.price).unwrap()),
-            income_cell,
+            cell!(order.position_id),
+            cell!(order.name),
+            match order.action {
+                Action::Long => paint_cell(cell!(t("label.buy")), Role::Positive),
+                Action::Short => paint_cell(cell!(t("label.sell")), Role::Negative),
+            },
+            cell!(format_rounded(order.amount, locale)),
+            cell!(format_rounded(order.price, locale)),
+            cell!(format_rounded(order.value(), locale)),
         ]));
     });
+    print_table(&mut orders_table);
+}
 
+pub fn render_dashboard(dashboard: &Dashboard) {
+    let locale = get_options().locale;
     clear_screen().expect("clear screen");
+    println!("{}", t("label.dashboard").bold());
 
-    print!("Position {} ", position.id);
-    match position.action {
-        Action::Long => print!("{} ", "Long".bold().green()),
-        Action::Short => println!("{} ", "Short".bold().red()),
+    let format_ranked = |position: &Option<RankedPosition>| match position {
+        Some(position) => format!(
+            "{} #{} ({}%)",
+            position.name,
+            position.id,
+            format_rounded(round::round(position.percent, 2), locale)
+        ),
+        None => String::from("-"),
+    };
+    let format_optional = |number: Option<f64>, postfix: &str| match number {
+        Some(number) => format!("{}{}", format_rounded(number, locale), postfix),
+        None => String::from("-"),
+    };
+
+    let mut table = Table::new();
+    table.add_row(row![
+        t("label.invested_capital"),
+        format_rounded(dashboard.invested_capital, locale)
+    ]);
+    table.add_row(Row::new(vec![
+        cell!(t("label.realized_income")),
+        get_styled_income_cell(dashboard.realized_income, None, locale),
+    ]));
+    table.add_row(Row::new(vec![
+        cell!(t("label.realized_income_percent")),
+        get_optional_percent_cell(dashboard.realized_income_percent, locale),
+    ]));
+    table.add_row(row![
+        t("label.open_closed_positions"),
+        format!(
+            "{} / {}",
+            dashboard.open_positions, dashboard.closed_positions
+        )
+    ]);
+    table.add_row(row![
+        t("label.best_position"),
+        format_ranked(&dashboard.best_position)
+    ]);
+    table.add_row(row![
+        t("label.worst_position"),
+        format_ranked(&dashboard.worst_position)
+    ]);
+    table.add_row(row![
+        t("label.win_rate"),
+        format_optional(dashboard.win_rate.map(|rate| round::round(rate, 2)), "%")
+    ]);
+    table.add_row(row![
+        t("label.avg_holding_time"),
+        format_optional(
+            dashboard.avg_holding_days.map(|days| round::round(days, 1)),
+            &t("label.days_postfix")
+        )
+    ]);
+    table.add_row(row![
+        t("label.largest_position"),
+        format_ranked(&dashboard.largest_position)
+    ]);
+    table.add_row(Row::new(vec![
+        cell!(t("label.unrealized_income")),
+        match dashboard.unrealized_income {
+            Some(income) => get_styled_income_cell(income, None, locale),
+            None => cell!("-"),
+        },
+    ]));
+    table.add_row(row![
+        t("label.equity"),
+        format_optional(dashboard.equity, "")
+    ]);
+    print_table(&mut table);
+
+    if dashboard.priced_positions < dashboard.open_positions {
+        println!(
+            "{}",
+            tf!(
+                "info.market_price_coverage",
+                dashboard.priced_positions,
+                dashboard.open_positions
+            )
+            .italic()
+            .paint(Role::Muted)
+        );
     }
-    print!("{} ", position.name.bold());
+}
 
+// Prices are converted to units after all splits, so they are comparable with current avg price
+pub fn render_position_chart(position: &Position) {
+    clear_screen().expect("clear screen");
+    println!("{}\n", tf!("label.prices_of", position.name).bold());
+
+    let mut orders = position.orders.clone();
+    orders.sort_by_key(|order| order.id);
+
+    let avg_prices = position
+        .get_avg_price_history()
+        .into_iter()
+        .zip(&orders)
+        .map(|((date, avg_price), order)| {
+            let factor = position.get_split_factor_after(order.id + 1);
+            (date.timestamp() as f64, avg_price / factor)
+        })
+        .collect();
+    let get_order_points = |action: Action| {
+        orders
+            .iter()
+            .filter(|order| order.action == action)
+            .map(|order| {
+                let factor = position.get_split_factor_after(order.id);
+                (order.date.timestamp() as f64, order.price / factor)
+            })
+            .collect()
+    };
+
+    let series = [
+        ChartSeries {
+            points: avg_prices,
+            marker: '-',
+            is_step_line: true,
+        },
+        ChartSeries {
+            points: get_order_points(Action::Long),
+            marker: 'B',
+            is_step_line: false,
+        },
+        ChartSeries {
+            points: get_order_points(Action::Short),
+            marker: 'S',
+            is_step_line: false,
+        },
+    ];
+    draw_line_chart(
+        &series,
+        get_terminal_width(),
+        CHART_HEIGHT,
+        format_chart_date,
+    )
+    .iter()
+    .for_each(|line| println!("{}", line));
     println!(
-        "{}{}",
-        "Last edited at ".bold().bright_black(),
-        position
-            .edited_at
-            .format("%d/%m/%Y %H:%M")
-            .to_string()
-            .bold()
-            .bright_black()
+        "\n{}",
+        t("label.position_chart_legend").italic().paint(Role::Muted)
     );
+}
 
-    position_table.printstd();
+pub fn render_portfolio_charts(positions: &[Position]) {
+    clear_screen().expect("clear screen");
+    let width = get_terminal_width();
 
-    println!(); // Gap between tables
+    let mut income: Vec<(DateTime<Local>, f64)> = positions
+        .iter()
+        .flat_map(|position| position.get_realized_income())
+        .collect();
+    income.sort_by_key(|(date, _)| *date);
+    let mut total = 0f64;
+    let income_points = income
+        .into_iter()
+        .map(|(date, value)| {
+            total += value;
+            (date.timestamp() as f64, total)
+        })
+        .collect();
 
-    println!("Position {} orders:", position.id.to_string().bold());
-    orders_table.printstd();
+    println!("{}\n", t("label.cumulative_realized_income").bold());
+    let series = [ChartSeries {
+        points: income_points,
+        marker: '*',
+        is_step_line: true,
+    }];
+    draw_line_chart(&series, width, CHART_HEIGHT, format_chart_date)
+        .iter()
+        .for_each(|line| println!("{}", line));
 
-    draw_page_counter(
-        page,
-        get_pages_count(position.orders.len(), orders_per_page),
-    );
+    // Positions with the same name are shown as one bar
+    let mut bars: Vec<(String, f64)> = vec![];
+    positions
+        .iter()
+        .filter(|position| !position.is_closed())
+        .for_each(
+            |position| match bars.iter_mut().find(|(name, _)| *name == position.name) {
+                Some((_, value)) => *value += position.avg_value,
+                None => bars.push((position.name.clone(), position.avg_value)),
+            },
+        );
+    bars.sort_by(|first, second| second.1.total_cmp(&first.1));
+
+    println!("\n{}\n", t("label.allocation_by_avg_value").bold());
+    draw_bar_chart(&bars, width)
+        .iter()
+        .for_each(|line| println!("{}", line));
 }
 
-pub fn render_single_order(position: &Position, order: &Order) {
-    let order_type = match order.action {
-        Action::Long => "Buy",
-        Action::Short => "Sell",
-    };
+fn format_chart_date(timestamp: f64) -> String {
+    match DateTime::from_timestamp(timestamp as i64, 0) {
+        Some(date) => get_options()
+            .locale
+            .format_date(&date.with_timezone(&Local)),
+        None => String::new(),
+    }
+}
 
-    let income_cell = if position.action == order.action {
-        cell!(String::from("-"))
-    } else {
-        get_styled_income_cell(round(order.income).unwrap(), None)
+pub fn render_simulation(position: &Position, simulated: &Position, order: &Order) {
+    let locale = get_options().locale;
+    clear_screen().expect("clear screen");
+    let simulation_key = match order.action {
+        Action::Long => "info.simulated_buy",
+        Action::Short => "info.simulated_sell",
     };
+    println!(
+        "{}",
+        tf!(
+            simulation_key,
+            format_rounded(order.amount, locale),
+            position.name,
+            format_rounded(order.price, locale)
+        )
+        .bold()
+    );
 
     let mut table = Table::new();
-    table.add_row(row!["Id", "Type", "Amount", "Value", "Price", "Income"]);
-
+    table.add_row(row!["", t("label.before"), t("label.after")]);
+    table.add_row(Row::new(vec![
+        cell!(t("label.amount")),
+        cell!(format_rounded(position.amount, locale)),
+        cell!(format_rounded(simulated.amount, locale)),
+    ]));
+    table.add_row(Row::new(vec![
+        cell!(t("label.avg_price")),
+        cell!(format_rounded(position.avg_price, locale)),
+        cell!(format_rounded(simulated.avg_price, locale)),
+    ]));
+    table.add_row(Row::new(vec![
+        cell!(t("label.avg_value")),
+        cell!(format_rounded(position.avg_value, locale)),
+        cell!(format_rounded(simulated.avg_value, locale)),
+    ]));
+    table.add_row(Row::new(vec![
+        cell!(t("label.income")),
+        get_styled_income_cell(position.income, None, locale),
+        get_styled_income_cell(simulated.income, None, locale),
+    ]));
     table.add_row(Row::new(vec![
-        cell!(order.id),
-        cell!(order_type),
-        cell!(round(order.amount).unwrap()),
-        cell!(round(order.value).unwrap()),
-        cell!(round(order.price).unwrap()),
-        income_cell,
+        cell!("%"),
+        get_styled_income_cell(
+            round::round(position.calculate_income_percent(), 2),
+            Some(String::from("%")),
+            locale,
+        ),
+        get_styled_income_cell(
+            round::round(simulated.calculate_income_percent(), 2),
+            Some(String::from("%")),
+            locale,
+        ),
     ]));
+    print_table(&mut table);
 
-    table.printstd();
+    if order.action != position.action {
+        let income = format_rounded(order.income, locale);
+        let income_text = match order.income.total_cmp(&0f64) {
+            Ordering::Greater => format!("+{}", income).paint(Role::Positive),
+            Ordering::Less => income.paint(Role::Negative),
+            Ordering::Equal => income.normal(),
+        };
+        println!("{}{}", t("label.order_income_prefix").bold(), income_text);
+    }
+    println!("{}", t("info.nothing_saved").italic().paint(Role::Muted));
 }
 
 fn calculate_total(positions: &Vec<Position>) -> (f64, f64) {
@@ -255,25 +1179,170 @@ fn calculate_total(positions: &Vec<Position>) -> (f64, f64) {
     (value, income)
 }
 
-fn get_styled_income_cell(income: f64, postfix: Option<String>) -> Cell {
+fn get_styled_income_cell(income: f64, postfix: Option<String>, locale: Locale) -> Cell {
+    let income_text = format_rounded(income, locale);
     let cell_value = match postfix {
-        Some(postfix) => format!("{}{}", income, postfix),
-        None => income.to_string(),
+        Some(postfix) => format!("{}{}", income_text, postfix),
+        None => income_text,
     };
 
-    match income.total_cmp(&0f64) {
-        Ordering::Equal => cell!(cell_value),
-        Ordering::Greater => {
-            cell!(format!("+{}", cell_value)).with_style(Attr::ForegroundColor(color::GREEN))
+    get_styled_number_cell(income, cell_value)
+}
+
+// Positive numbers get plus sign, color depends on sign of the number, not of its text
+fn get_styled_number_cell(number: f64, text: String) -> Cell {
+    match number.total_cmp(&0f64) {
+        Ordering::Equal => cell!(text),
+        Ordering::Greater => paint_cell(cell!(format!("+{}", text)), Role::Positive),
+        Ordering::Less => paint_cell(cell!(text), Role::Negative),
+    }
+}
+
+// Returns are unknown for some positions, e.g. ones opened today
+fn get_optional_percent_cell(percent: Option<f64>, locale: Locale) -> Cell {
+    match percent {
+        Some(percent) => {
+            get_styled_income_cell(round::round(percent, 2), Some(String::from("%")), locale)
         }
-        Ordering::Less => cell!(cell_value).with_style(Attr::ForegroundColor(color::RED)),
+        None => cell!("-"),
     }
 }
 
+// Numbers are rounded as by default precision of tables
+fn format_rounded(number: f64, locale: Locale) -> String {
+    locale.localize_number(&format_number(number, Precision::Auto, false))
+}
+
+// Borders are skipped in plain mode, so output is readable in logs
+fn print_table(table: &mut Table) {
+    if is_plain_output() {
+        table.set_format(*format::consts::FORMAT_CLEAN);
+    }
+
+    if is_styled_output() {
+        table.printstd();
+    } else if let Err(error) = table.print(&mut std::io::stdout()) {
+        print_error(error.to_string());
+    }
+}
+
+// Cells are left as is in monochrome theme
+fn paint_cell(mut cell: Cell, role: Role) -> Cell {
+    let (foreground, background) = cell_colors(role);
+    if let Some(color) = foreground {
+        cell = cell.with_style(Attr::ForegroundColor(color));
+    }
+    if let Some(color) = background {
+        cell = cell.with_style(Attr::BackgroundColor(color));
+    }
+
+    cell
+}
+
+fn get_cash_cell(cash: f64, text: String) -> Cell {
+    if cash < 0f64 {
+        paint_cell(cell!(text), Role::Negative)
+    } else {
+        cell!(text)
+    }
+}
+
+fn get_positions_header(columns: &[PositionColumn], options: &Options) -> Row {
+    Row::new(
+        columns
+            .iter()
+            .map(|column| cell!(get_position_column_title(*column, options)))
+            .collect(),
+    )
+}
+
+fn get_position_cell(
+    position: &Position,
+    column: PositionColumn,
+    options: &Options,
+    now: DateTime<Local>,
+) -> Cell {
+    match get_position_column_value(position, column, options, now) {
+        ColumnValue::Text(text) => cell!(text),
+        ColumnValue::Signed(number, text) => get_styled_number_cell(number, text),
+        ColumnValue::Status => get_status_cell(position),
+    }
+}
+
+// Label goes to the first column and cells to their columns, other columns are empty
+fn get_summary_row(
+    columns: &[PositionColumn],
+    label: &str,
+    mut cells: Vec<(PositionColumn, Cell)>,
+) -> Row {
+    Row::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let cell_index = cells
+                    .iter()
+                    .position(|(cell_column, _)| cell_column == column);
+                match (index, cell_index) {
+                    (0, _) => cell!(label),
+                    (_, Some(cell_index)) => cells.remove(cell_index).1,
+                    (_, None) => cell!("-"),
+                }
+            })
+            .collect(),
+    )
+}
+
+fn get_orders_header(columns: &[OrderColumn]) -> Row {
+    Row::new(
+        columns
+            .iter()
+            .map(|column| cell!(get_order_column_title(*column)))
+            .collect(),
+    )
+}
+
+fn get_order_cell(
+    position: &Position,
+    order: &Order,
+    column: OrderColumn,
+    options: &Options,
+) -> Cell {
+    match get_order_column_value(position, order, column, options) {
+        ColumnValue::Text(text) => cell!(text),
+        ColumnValue::Signed(number, text) => get_styled_number_cell(number, text),
+        ColumnValue::Status => cell!("-"),
+    }
+}
+
+fn get_income_events_header() -> Row {
+    row![
+        t("label.id"),
+        t("label.date"),
+        t("label.type"),
+        t("label.after_order"),
+        t("label.cash"),
+        t("label.units")
+    ]
+}
+
+// Triggered level is added to the status, so it's highlighted without hiding it
 fn get_status_cell(position: &Position) -> Cell {
-    if position.amount == 0f64 {
-        cell!("Closed").with_style(Attr::ForegroundColor(color::BRIGHT_BLACK))
+    let status = if position.is_closed() {
+        t("label.closed")
     } else {
-        cell!("Active")
+        t("label.active")
+    };
+
+    match position.get_triggered_level() {
+        Some(level) => {
+            let role = match level {
+                PriceLevel::StopLoss => Role::Alert,
+                PriceLevel::TakeProfit => Role::Success,
+            };
+            paint_cell(cell!(format!("{} ({})", status, level)), role).with_style(Attr::Bold)
+        }
+        None if position.is_closed() => paint_cell(cell!(status), Role::Muted),
+        None => cell!(status),
     }
 }
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use super::parser::{parse_command, ParsedCommand};
+use crate::tf;
+
+pub enum ResolvedCommand {
+    Command(ParsedCommand),
+    // Lines of commands, which are run one by one
+    Macro(String, Vec<String>),
+}
+
+/// Replaces alias with its command, putting preset arguments before entered ones.
+/// Aliases are resolved once, so alias of another alias is left as is.
+pub fn resolve_command(
+    command: ParsedCommand,
+    aliases: &BTreeMap<String, String>,
+    macros: &BTreeMap<String, Vec<String>>,
+) -> Result<ResolvedCommand, String> {
+    if let Some(lines) = macros.get(&command.name) {
+        if !command.args.is_empty() {
+            return Err(tf!("error.macro_arguments", command.name));
+        }
+
+        return Ok(ResolvedCommand::Macro(command.name, lines.clone()));
+    }
+
+    let preset = match aliases.get(&command.name) {
+        Some(preset) => preset,
+        None => return Ok(ResolvedCommand::Command(command)),
+    };
+
+    let mut resolved = match parse_command(preset) {
+        Ok(Some(resolved)) => resolved,
+        Ok(None) => return Err(tf!("error.empty_alias", command.name)),
+        Err(error) => return Err(tf!("error.parse_alias", command.name, error)),
+    };
+    resolved.args.extend(command.args);
+
+    Ok(ResolvedCommand::Command(resolved))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{resolve_command, ResolvedCommand};
+    use crate::commands::utils::parser::parse_command;
+
+    fn resolve(
+        input: &str,
+        macros: &BTreeMap<String, Vec<String>>,
+    ) -> Result<ResolvedCommand, String> {
+        let aliases = BTreeMap::from([
+            (String::from("add"), String::from("a")),
+            (String::from("long"), String::from("a --type long")),
+            (String::from("new"), String::from("add")),
+            (String::from("broken"), String::from("a \"x")),
+        ]);
+        resolve_command(parse_command(input).unwrap().unwrap(), &aliases, macros)
+    }
+
+    fn resolve_line(input: &str) -> String {
+        match resolve(input, &BTreeMap::new()) {
+            Ok(ResolvedCommand::Command(command)) => {
+                let [name, action, amount] = command.args.resolve(["name", "type", "amount"]);
+                format!(
+                    "{} {:?} {:?} {:?}",
+                    command.name,
+                    name.map(|value| value.as_str()),
+                    action.map(|value| value.as_str()),
+                    amount.map(|value| value.as_str())
+                )
+            }
+            Ok(ResolvedCommand::Macro(name, _)) => format!("macro {}", name),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn test_resolve_aliases() {
+        assert_eq!(resolve_line("add BTC"), "a Some(\"BTC\") None None");
+        assert_eq!(
+            resolve_line("long BTC 0.5"),
+            "a Some(\"BTC\") Some(\"long\") Some(\"0.5\")"
+        );
+        assert_eq!(resolve_line("new"), "add None None None");
+        assert_eq!(resolve_line("e 1"), "e Some(\"1\") None None");
+        assert!(resolve_line("broken").starts_with("Failed to parse alias 'broken'"));
+    }
+
+    #[test]
+    fn test_resolve_macros() {
+        let macros = BTreeMap::from([(
+            String::from("weekly"),
+            vec![String::from("r month"), String::from("alloc")],
+        )]);
+
+        match resolve("weekly", &macros) {
+            Ok(ResolvedCommand::Macro(name, lines)) => {
+                assert_eq!(name, "weekly");
+                assert_eq!(lines, vec!["r month", "alloc"]);
+            }
+            _ => panic!("macro is not resolved"),
+        }
+
+        assert!(resolve("weekly 1", &macros).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::models::{Action, Portfolio, Position};
+
+pub const OTHER_ALLOCATION_LABEL: &str = "Other";
+
+// Rebalancing values smaller than this are left from float rounding
+const VALUE_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RebalancingOrder {
+    pub position_id: i32,
+    pub name: String,
+    pub action: Action,
+    pub amount: f64,
+    pub price: f64,
+}
+
+impl RebalancingOrder {
+    pub fn value(&self) -> f64 {
+        self.amount * self.price
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationRow {
+    pub label: String,
+    pub value: f64,
+    // Weights are in percents of total value
+    pub weight: f64,
+    pub target_weight: f64,
+    // Value to buy if positive or to sell if negative to reach target weight
+    pub rebalance_value: f64,
+    pub orders: Vec<RebalancingOrder>,
+}
+
+impl AllocationRow {
+    pub fn calculate_drift(&self) -> f64 {
+        self.weight - self.target_weight
+    }
+
+    // Target can't be reached with orders if there are no positions to buy more of
+    pub fn is_rebalanceable(&self) -> bool {
+        self.rebalance_value.abs() < VALUE_EPSILON || !self.orders.is_empty()
+    }
+}
+
+/// Compares current weights of active long positions by market value with portfolio target
+/// weights. Each position is counted in the first matching target, positions without target
+/// are gathered in "Other" row, which gets the weight left from targets.
+/// Rebalancing value is split between positions of a row proportionally to their values.
+pub fn build_allocation(portfolio: &Portfolio) -> Vec<AllocationRow> {
+    let positions: Vec<&Position> = portfolio
+        .positions
+        .iter()
+        .filter(|position| !position.is_closed() && position.action == Action::Long)
+        .collect();
+    let total_value: f64 = positions
+        .iter()
+        .map(|position| position.calculate_market_value())
+        .sum();
+
+    let mut groups: Vec<(String, f64, Vec<&Position>)> = portfolio
+        .target_weights
+        .iter()
+        .map(|weight| (weight.key.to_string(), weight.percent, vec![]))
+        .collect();
+    let targets_sum: f64 = portfolio
+        .target_weights
+        .iter()
+        .map(|weight| weight.percent)
+        .sum();
+    groups.push((
+        String::from(OTHER_ALLOCATION_LABEL),
+        100f64 - targets_sum,
+        vec![],
+    ));
+
+    for position in positions {
+        let index = portfolio
+            .target_weights
+            .iter()
+            .position(|weight| weight.key.matches(position))
+            .unwrap_or(groups.len() - 1);
+        groups[index].2.push(position);
+    }
+
+    groups
+        .into_iter()
+        .filter(|(label, target_weight, positions)| {
+            label != OTHER_ALLOCATION_LABEL || *target_weight > 0f64 || !positions.is_empty()
+        })
+        .map(|(label, target_weight, positions)| {
+            // Folded from positive zero, as sum of empty rows would be negative zero
+            let value = positions.iter().fold(0f64, |sum, position| {
+                sum + position.calculate_market_value()
+            });
+            let rebalance_value = target_weight / 100f64 * total_value - value;
+
+            let orders = if rebalance_value.abs() < VALUE_EPSILON || value <= 0f64 {
+                vec![]
+            } else {
+                positions
+                    .iter()
+                    .map(|position| {
+                        let price = position.get_current_price();
+                        let position_value =
+                            rebalance_value * position.calculate_market_value() / value;
+                        RebalancingOrder {
+                            position_id: position.id,
+                            name: position.name.clone(),
+                            action: if position_value > 0f64 {
+                                Action::Long
+                            } else {
+                                Action::Short
+                            },
+                            amount: position_value.abs() / price,
+                            price,
+                        }
+                    })
+                    .collect()
+            };
+
+            AllocationRow {
+                label,
+                value,
+                weight: if total_value > 0f64 {
+                    value / total_value * 100f64
+                } else {
+                    0f64
+                },
+                target_weight,
+                rebalance_value,
+                orders,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::{build_allocation, OTHER_ALLOCATION_LABEL};
+    use crate::models::{Action, Order, Portfolio, Position, TargetWeight};
+
+    fn mock_position(id: i32, name: &str, amount: f64, price: f64, tags: &str) -> Position {
+        let mut position = Position::new(
+            id,
+            String::from(name),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount,
+                value: amount * price,
+                price,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+        position.set_tags(tags);
+        position
+    }
+
+    #[test]
+    fn test_build_allocation() {
+        let mut btc = mock_position(0, "BTC", 1f64, 100f64, "");
+        btc.set_market_price(200f64);
+
+        let mut portfolio = Portfolio::new(0, String::from("MOCK"));
+        portfolio.positions = vec![
+            btc,
+            mock_position(1, "ETH", 10f64, 10f64, ""),
+            mock_position(2, "USDT", 60f64, 1f64, "stable"),
+            mock_position(3, "USDC", 40f64, 1f64, "stable"),
+        ];
+        portfolio.target_weights = TargetWeight::parse_list("btc=40 eth=30 tag:stable=30").unwrap();
+
+        // Total value is 200 + 100 + 100 = 400
+        let rows = build_allocation(&portfolio);
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(rows[0].label, "btc");
+        assert_eq!(rows[0].weight, 50f64);
+        assert_eq!(rows[0].calculate_drift(), 10f64);
+        assert_eq!(rows[0].rebalance_value, -40f64);
+        assert_eq!(rows[0].orders[0].action, Action::Short);
+        assert_eq!(rows[0].orders[0].amount, 0.2f64);
+        assert_eq!(rows[0].orders[0].price, 200f64);
+
+        assert_eq!(rows[1].rebalance_value, 20f64);
+        assert_eq!(rows[1].orders[0].action, Action::Long);
+        assert_eq!(rows[1].orders[0].amount, 2f64);
+
+        // Tag value is split between positions proportionally
+        assert_eq!(rows[2].label, "tag:stable");
+        assert_eq!(rows[2].orders.len(), 2);
+        assert_eq!(rows[2].orders[0].amount, 12f64);
+        assert_eq!(rows[2].orders[1].amount, 8f64);
+
+        // Position without target goes to other, which target is the rest of weights
+        portfolio.target_weights = TargetWeight::parse_list("btc=50 eth=30").unwrap();
+        let rows = build_allocation(&portfolio);
+        assert_eq!(rows[2].label, OTHER_ALLOCATION_LABEL);
+        assert_eq!(rows[2].target_weight, 20f64);
+        assert_eq!(rows[2].value, 100f64);
+        assert_eq!(rows[2].rebalance_value, -20f64);
+    }
+}
@@ -1,21 +1,117 @@
-use std::str::FromStr;
+use std::fmt;
 
-use crate::utils::console::ask_for_input;
+use crate::models::validation::validate_positive;
+use crate::storage::load_storage;
+use crate::tf;
+use crate::utils::console::{
+    ask_confirmation, ask_for_choice, ask_for_input, ask_for_valid_input, print_error,
+    ConfirmationStatus,
+};
+use crate::utils::locale::{parse_localized, LocalizedInput};
 
 pub fn parse_arg_or_get_from_input<T>(arg: Option<&String>, question: &str) -> Result<T, String>
 where
-    T: FromStr,
+    T: LocalizedInput,
 {
-    if arg.is_some() {
-        let arg = arg.unwrap();
-        match arg.trim().parse::<T>() {
-            Ok(value) => Ok(value),
-            Err(_) => return Err(format!("Failed to parse answer '{}'", arg.trim())),
+    match arg {
+        Some(arg) => match parse_localized::<T>(arg.trim()) {
+            Some(value) => Ok(value),
+            None => Err(tf!("error.parse_answer", arg.trim())),
+        },
+        None => ask_for_input::<T>(question),
+    }
+}
+
+// Same as parse_arg_or_get_from_input, but completes the answer with one of the choices
+pub fn parse_arg_or_choose<T>(
+    arg: Option<&String>,
+    question: &str,
+    choices: &[&str],
+) -> Result<T, String>
+where
+    T: LocalizedInput,
+{
+    match arg {
+        Some(_) => parse_arg_or_get_from_input(arg, question),
+        None => ask_for_choice(question, choices),
+    }
+}
+
+// Argument, which doesn't pass validation, is rejected. Answer to the question is asked again.
+pub fn parse_valid_arg_or_get_from_input<T, E, F>(
+    arg: Option<&String>,
+    question: &str,
+    validate: F,
+) -> Result<T, String>
+where
+    T: LocalizedInput,
+    E: fmt::Display,
+    F: Fn(&T) -> Result<(), E>,
+{
+    match arg {
+        Some(_) => {
+            let value = parse_arg_or_get_from_input(arg, question)?;
+            match validate(&value) {
+                Ok(_) => Ok(value),
+                Err(error) => Err(tf!("error.invalid_value_with_message", error)),
+            }
         }
-    } else {
-        match ask_for_input::<T>(question) {
-            Ok(value) => Ok(value),
-            Err(error) => return Err(error),
+        None => ask_for_valid_input(question, validate),
+    }
+}
+
+// Empty answer means that price is not set. Invalid price is asked again.
+pub fn ask_for_optional_price(question: &str) -> Result<Option<f64>, String> {
+    loop {
+        let answer = ask_for_input::<String>(question)?;
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return Ok(None);
         }
+
+        match parse_localized::<f64>(answer) {
+            Some(price) => match validate_positive("price", price) {
+                Ok(_) => return Ok(Some(price)),
+                Err(error) => print_error(tf!("error.invalid_value_retry", error)),
+            },
+            None => print_error(tf!("error.parse_answer_retry", answer)),
+        }
+    }
+}
+
+// Returns cash balance of the current portfolio, if spending the value would make it negative.
+// Portfolios without cash entries and with margin allowed are not checked.
+pub fn find_cash_shortage(value: f64) -> Result<Option<f64>, String> {
+    let storage = load_storage()?;
+    let portfolio = match storage.current_portfolio() {
+        Some(portfolio) => portfolio,
+        None => return Ok(None),
+    };
+
+    if !portfolio.is_cash_tracked() || portfolio.allow_margin {
+        return Ok(None);
+    }
+
+    let cash_balance = portfolio.calculate_cash_balance();
+    if cash_balance - value >= 0f64 {
+        return Ok(None);
+    }
+
+    Ok(Some(cash_balance))
+}
+
+pub fn get_cash_shortage_message(cash_balance: f64, value: f64) -> String {
+    tf!("confirm.cash_shortage", cash_balance, value)
+}
+
+// Asks to confirm buying or withdrawal, which would make cash balance of the current portfolio
+// negative
+pub fn confirm_cash_spending(value: f64) -> Result<ConfirmationStatus, String> {
+    match find_cash_shortage(value)? {
+        Some(cash_balance) => ask_confirmation(
+            get_cash_shortage_message(cash_balance, value).as_str(),
+            ConfirmationStatus::Rejected,
+        ),
+        None => Ok(ConfirmationStatus::Confirmed),
     }
 }
@@ -0,0 +1,272 @@
+use crate::models::{Action, Portfolio, Position};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedPosition {
+    pub id: i32,
+    pub name: String,
+    // Income percent or share of portfolio in percents
+    pub percent: f64,
+}
+
+impl RankedPosition {
+    fn new(position: &Position, percent: f64) -> RankedPosition {
+        RankedPosition {
+            id: position.id,
+            name: position.name.clone(),
+            percent,
+        }
+    }
+}
+
+/// Key numbers of portfolios, shown by the dashboard command
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dashboard {
+    // Cost of amounts held in open positions
+    pub invested_capital: f64,
+    pub realized_income: f64,
+    // Realized income to funds spent on opening orders of all positions
+    pub realized_income_percent: Option<f64>,
+    pub open_positions: usize,
+    pub closed_positions: usize,
+    pub best_position: Option<RankedPosition>,
+    pub worst_position: Option<RankedPosition>,
+    // Percent of closed positions with positive income
+    pub win_rate: Option<f64>,
+    pub avg_holding_days: Option<f64>,
+    // Open long position with the largest market value
+    pub largest_position: Option<RankedPosition>,
+    pub priced_positions: usize,
+    // Known only if market price of at least one open position is set
+    pub unrealized_income: Option<f64>,
+    pub equity: Option<f64>,
+}
+
+/// Positions of all given portfolios are counted together. Positions are ranked by income
+/// percent only if they have realized something, so just opened ones don't take part.
+pub fn build_dashboard(portfolios: &[Portfolio]) -> Dashboard {
+    let positions: Vec<&Position> = portfolios
+        .iter()
+        .flat_map(|portfolio| portfolio.positions.iter())
+        .collect();
+    let (closed, open): (Vec<&Position>, Vec<&Position>) =
+        positions.iter().partition(|position| position.is_closed());
+
+    let realized_income = positions
+        .iter()
+        .fold(0f64, |sum, position| sum + position.income);
+    let invested_funds: f64 = positions
+        .iter()
+        .map(|position| position.calculate_invested_funds())
+        .sum();
+
+    let mut ranked: Vec<RankedPosition> = positions
+        .iter()
+        .filter(|position| position.is_closed() || position.income != 0f64)
+        .map(|position| RankedPosition::new(position, position.calculate_income_percent()))
+        .collect();
+    ranked.sort_by(|a, b| b.percent.total_cmp(&a.percent));
+
+    let winners = closed
+        .iter()
+        .filter(|position| position.income > 0f64)
+        .count();
+    let holding_days: Vec<f64> = closed
+        .iter()
+        .filter_map(|position| {
+            let first = position.orders.iter().map(|order| order.date).min()?;
+            let last = position.orders.iter().map(|order| order.date).max()?;
+            Some((last - first).num_seconds() as f64 / 86400f64)
+        })
+        .collect();
+
+    let long_positions: Vec<&&Position> = open
+        .iter()
+        .filter(|position| position.action == Action::Long)
+        .collect();
+    let long_value: f64 = long_positions
+        .iter()
+        .map(|position| position.calculate_market_value())
+        .sum();
+    let largest_position = long_positions
+        .iter()
+        .max_by(|a, b| {
+            a.calculate_market_value()
+                .total_cmp(&b.calculate_market_value())
+        })
+        .filter(|_| long_value > 0f64)
+        .map(|position| {
+            RankedPosition::new(
+                position,
+                position.calculate_market_value() / long_value * 100f64,
+            )
+        });
+
+    let unrealized: Vec<f64> = open
+        .iter()
+        .filter_map(|position| {
+            let price = position.market_price?;
+            Some(match position.action {
+                Action::Long => (price - position.avg_price) * position.amount,
+                Action::Short => (position.avg_price - price) * position.amount,
+            })
+        })
+        .collect();
+    let unrealized_income = if unrealized.is_empty() {
+        None
+    } else {
+        Some(unrealized.iter().sum::<f64>())
+    };
+
+    Dashboard {
+        invested_capital: open
+            .iter()
+            .fold(0f64, |sum, position| sum + position.avg_value),
+        realized_income,
+        realized_income_percent: if invested_funds > 0f64 {
+            Some(realized_income / invested_funds * 100f64)
+        } else {
+            None
+        },
+        open_positions: open.len(),
+        closed_positions: closed.len(),
+        best_position: ranked.first().cloned(),
+        worst_position: ranked.last().cloned(),
+        win_rate: if closed.is_empty() {
+            None
+        } else {
+            Some(winners as f64 / closed.len() as f64 * 100f64)
+        },
+        avg_holding_days: if holding_days.is_empty() {
+            None
+        } else {
+            Some(holding_days.iter().sum::<f64>() / holding_days.len() as f64)
+        },
+        largest_position,
+        priced_positions: unrealized.len(),
+        unrealized_income,
+        equity: unrealized_income
+            .map(|income| portfolios.iter().map(calculate_cost_equity).sum::<f64>() + income),
+    }
+}
+
+// Without cash entries, equity is the cost of open positions
+fn calculate_cost_equity(portfolio: &Portfolio) -> f64 {
+    if portfolio.is_cash_tracked() {
+        return portfolio.calculate_equity();
+    }
+
+    portfolio
+        .positions
+        .iter()
+        .map(|position| match position.action {
+            Action::Long => position.avg_value,
+            Action::Short => -position.avg_value,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Local};
+
+    use super::build_dashboard;
+    use crate::models::{Action, Order, Portfolio, Position};
+
+    fn mock_order(
+        id: i32,
+        action: Action,
+        amount: f64,
+        price: f64,
+        date: DateTime<Local>,
+    ) -> Order {
+        Order {
+            id,
+            action,
+            amount,
+            value: amount * price,
+            price,
+            income: 0f64,
+            date,
+        }
+    }
+
+    #[test]
+    fn test_build_dashboard() {
+        let now = Local::now();
+
+        // Closed with +50% in 10 days
+        let btc = Position::new(
+            0,
+            String::from("BTC"),
+            vec![
+                mock_order(0, Action::Long, 1f64, 100f64, now - Duration::days(30)),
+                mock_order(1, Action::Short, 1f64, 150f64, now - Duration::days(20)),
+            ],
+        );
+        // Closed with -20% in 30 days
+        let eth = Position::new(
+            1,
+            String::from("ETH"),
+            vec![
+                mock_order(0, Action::Long, 10f64, 10f64, now - Duration::days(40)),
+                mock_order(1, Action::Short, 10f64, 8f64, now - Duration::days(10)),
+            ],
+        );
+        let mut sol = Position::new(
+            2,
+            String::from("SOL"),
+            vec![mock_order(
+                0,
+                Action::Long,
+                3f64,
+                100f64,
+                now - Duration::days(5),
+            )],
+        );
+        sol.set_market_price(120f64);
+        let ada = Position::new(
+            3,
+            String::from("ADA"),
+            vec![mock_order(
+                0,
+                Action::Long,
+                100f64,
+                1f64,
+                now - Duration::days(5),
+            )],
+        );
+
+        let mut portfolio = Portfolio::new(0, String::from("MOCK"));
+        portfolio.positions = vec![btc, eth, sol, ada];
+        let dashboard = build_dashboard(&[portfolio]);
+
+        assert_eq!(dashboard.invested_capital, 400f64);
+        assert_eq!(dashboard.realized_income, 30f64);
+        assert_eq!(dashboard.realized_income_percent, Some(5f64));
+        assert_eq!(dashboard.open_positions, 2);
+        assert_eq!(dashboard.closed_positions, 2);
+        assert_eq!(dashboard.best_position.unwrap().name, "BTC");
+        assert_eq!(dashboard.worst_position.unwrap().percent, -20f64);
+        assert_eq!(dashboard.win_rate, Some(50f64));
+        assert_eq!(dashboard.avg_holding_days, Some(20f64));
+
+        // SOL is valued by market price, ADA by avg price
+        let largest = dashboard.largest_position.unwrap();
+        assert_eq!(largest.name, "SOL");
+        assert_eq!(largest.percent, 360f64 / 460f64 * 100f64);
+
+        assert_eq!(dashboard.priced_positions, 1);
+        assert_eq!(dashboard.unrealized_income, Some(60f64));
+        assert_eq!(dashboard.equity, Some(460f64));
+    }
+
+    #[test]
+    fn test_build_empty_dashboard() {
+        let dashboard = build_dashboard(&[Portfolio::new(0, String::from("MOCK"))]);
+        assert_eq!(dashboard.realized_income_percent, None);
+        assert_eq!(dashboard.best_position, None);
+        assert_eq!(dashboard.win_rate, None);
+        assert_eq!(dashboard.largest_position, None);
+        assert_eq!(dashboard.equity, None);
+    }
+}
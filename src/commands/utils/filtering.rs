@@ -0,0 +1,372 @@
+use chrono::NaiveDate;
+
+use super::parser::is_no_break_space;
+use crate::i18n::t;
+use crate::models::Position;
+use crate::tf;
+use crate::utils::locale::{Locale, LocalizedInput};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Id,
+    Name,
+    Tag,
+    Status,
+    Amount,
+    AvgValue,
+    AvgPrice,
+    Income,
+    IncomePercent,
+    Edited,
+}
+
+impl FilterField {
+    fn from_string(string: &str) -> Result<FilterField, String> {
+        match string.to_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "name" => Ok(Self::Name),
+            "tag" => Ok(Self::Tag),
+            "status" => Ok(Self::Status),
+            "amount" => Ok(Self::Amount),
+            "value" => Ok(Self::AvgValue),
+            "price" => Ok(Self::AvgPrice),
+            "income" => Ok(Self::Income),
+            "percent" => Ok(Self::IncomePercent),
+            "edited" => Ok(Self::Edited),
+            _ => Err(tf!("error.unknown_filter_field", string)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Contains,
+    NotContains,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+// Longer operators go first, so "<=" is not parsed as "<" followed by "=value"
+const OPERATORS: [(&str, FilterOperator); 8] = [
+    ("!~", FilterOperator::NotContains),
+    ("!=", FilterOperator::NotEqual),
+    ("<=", FilterOperator::LessOrEqual),
+    (">=", FilterOperator::GreaterOrEqual),
+    ("~", FilterOperator::Contains),
+    ("=", FilterOperator::Equal),
+    ("<", FilterOperator::Less),
+    (">", FilterOperator::Greater),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterValue {
+    Text(String),
+    Number(f64),
+    Date(NaiveDate),
+    Closed(bool),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterCondition {
+    pub field: FilterField,
+    pub operator: FilterOperator,
+    value: FilterValue,
+}
+
+impl FilterCondition {
+    pub fn parse(token: &str, locale: Locale) -> Result<FilterCondition, String> {
+        let (operator_index, operator_str, operator) = match OPERATORS
+            .iter()
+            .filter_map(|(operator_str, operator)| {
+                token
+                    .find(operator_str)
+                    .map(|index| (index, *operator_str, *operator))
+            })
+            .min_by_key(|(index, _, _)| *index)
+        {
+            Some(found) => found,
+            None => return Err(tf!("error.filter_no_operator", token)),
+        };
+
+        let field = FilterField::from_string(&token[..operator_index])?;
+        let value_str = &token[operator_index + operator_str.len()..];
+        if value_str.is_empty() {
+            return Err(tf!("error.filter_no_value", token));
+        }
+
+        let is_text_operator = matches!(
+            operator,
+            FilterOperator::Contains | FilterOperator::NotContains
+        );
+        let is_equality_operator =
+            matches!(operator, FilterOperator::Equal | FilterOperator::NotEqual);
+
+        let value = match field {
+            FilterField::Name | FilterField::Tag => {
+                if !is_text_operator && !is_equality_operator {
+                    return Err(match field {
+                        FilterField::Tag => tf!("error.operator_with_tags", operator_str),
+                        _ => tf!("error.operator_with_name", operator_str),
+                    });
+                }
+                FilterValue::Text(value_str.to_lowercase())
+            }
+            FilterField::Status => {
+                if !is_equality_operator {
+                    return Err(tf!("error.operator_with_status", operator_str));
+                }
+                match value_str.to_lowercase().as_str() {
+                    "active" | "a" => FilterValue::Closed(false),
+                    "closed" | "c" => FilterValue::Closed(true),
+                    _ => return Err(tf!("error.unknown_position_status", value_str)),
+                }
+            }
+            FilterField::Edited => {
+                if is_text_operator {
+                    return Err(tf!("error.operator_with_dates", operator_str));
+                }
+                match NaiveDate::parse_from_str(value_str, "%Y-%m-%d") {
+                    Ok(date) => FilterValue::Date(date),
+                    Err(_) => return Err(tf!("error.parse_filter_date", value_str)),
+                }
+            }
+            _ => {
+                if is_text_operator {
+                    return Err(tf!("error.operator_with_numbers", operator_str));
+                }
+                match f64::parse_input(value_str, locale) {
+                    Some(number) => FilterValue::Number(number),
+                    None => return Err(tf!("error.parse_number", value_str)),
+                }
+            }
+        };
+
+        Ok(FilterCondition {
+            field,
+            operator,
+            value,
+        })
+    }
+
+    pub fn matches(&self, position: &Position) -> bool {
+        match &self.value {
+            // Tag condition matches if any of position tags matches, negative operators
+            // require none of them to match
+            FilterValue::Text(text) if self.field == FilterField::Tag => {
+                let any_tag_matches = position.tags.iter().any(|tag| match self.operator {
+                    FilterOperator::Contains | FilterOperator::NotContains => tag.contains(text),
+                    _ => tag == text,
+                });
+                match self.operator {
+                    FilterOperator::Contains | FilterOperator::Equal => any_tag_matches,
+                    FilterOperator::NotContains | FilterOperator::NotEqual => !any_tag_matches,
+                    _ => false,
+                }
+            }
+            FilterValue::Text(text) => {
+                let name = position.name.to_lowercase();
+                match self.operator {
+                    FilterOperator::Contains => name.contains(text),
+                    FilterOperator::NotContains => !name.contains(text),
+                    FilterOperator::Equal => &name == text,
+                    FilterOperator::NotEqual => &name != text,
+                    _ => false,
+                }
+            }
+            FilterValue::Closed(closed) => match self.operator {
+                FilterOperator::Equal => position.is_closed() == *closed,
+                FilterOperator::NotEqual => position.is_closed() != *closed,
+                _ => false,
+            },
+            FilterValue::Date(date) => {
+                compare(self.operator, position.edited_at.date_naive(), *date)
+            }
+            FilterValue::Number(number) => {
+                let field_value = match self.field {
+                    FilterField::Id => position.id as f64,
+                    FilterField::Amount => position.amount,
+                    FilterField::AvgValue => position.avg_value,
+                    FilterField::AvgPrice => position.avg_price,
+                    FilterField::Income => position.income,
+                    FilterField::IncomePercent => position.calculate_income_percent(),
+                    _ => return false,
+                };
+                compare(self.operator, field_value, *number)
+            }
+        }
+    }
+}
+
+fn split_tokens(expression: &str) -> impl Iterator<Item = &str> {
+    expression
+        .split(|char: char| char.is_whitespace() && !is_no_break_space(char))
+        .filter(|token| !token.is_empty())
+}
+
+fn compare<T: PartialOrd>(operator: FilterOperator, left: T, right: T) -> bool {
+    match operator {
+        FilterOperator::Equal => left == right,
+        FilterOperator::NotEqual => left != right,
+        FilterOperator::Less => left < right,
+        FilterOperator::LessOrEqual => left <= right,
+        FilterOperator::Greater => left > right,
+        FilterOperator::GreaterOrEqual => left >= right,
+        FilterOperator::Contains | FilterOperator::NotContains => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionsFilter {
+    pub expression: String,
+    pub conditions: Vec<FilterCondition>,
+}
+
+impl PositionsFilter {
+    /// Parses expression like `name~BTC status=active income<0 edited>2026-01-01`.
+    /// All conditions must match for position to pass the filter. Numbers are entered with
+    /// separators of the locale.
+    pub fn parse(expression: &str, locale: Locale) -> Result<PositionsFilter, String> {
+        let mut conditions = vec![];
+        for token in split_tokens(expression) {
+            conditions.push(FilterCondition::parse(token, locale)?);
+        }
+
+        if conditions.is_empty() {
+            return Err(t("error.empty_filter"));
+        }
+
+        Ok(PositionsFilter {
+            expression: split_tokens(expression).collect::<Vec<&str>>().join(" "),
+            conditions,
+        })
+    }
+
+    pub fn matches(&self, position: &Position) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(position))
+    }
+
+    pub fn apply(&self, positions: &[Position]) -> Vec<Position> {
+        positions
+            .iter()
+            .filter(|position| self.matches(position))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::{FilterField, FilterOperator, PositionsFilter};
+    use crate::models::{Action, Order, Position};
+    use crate::utils::locale::Locale;
+
+    fn mock_position(id: i32, name: &str, sell_value: Option<f64>) -> Position {
+        let mut position = Position::new(
+            id,
+            String::from(name),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 1f64,
+                value: 100f64,
+                price: 100f64,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+
+        if let Some(value) = sell_value {
+            position.add_order(Order::new(&position, Action::Short, 1f64, value));
+        }
+
+        position
+    }
+
+    #[test]
+    fn test_filter_parse() {
+        let filter =
+            PositionsFilter::parse("name~BTC  status=active income<=0", Locale::EnGb).unwrap();
+        assert_eq!(filter.expression, "name~BTC status=active income<=0");
+        assert_eq!(filter.conditions.len(), 3);
+        assert_eq!(filter.conditions[2].field, FilterField::Income);
+        assert_eq!(filter.conditions[2].operator, FilterOperator::LessOrEqual);
+
+        assert!(PositionsFilter::parse("", Locale::EnGb).is_err());
+        assert!(PositionsFilter::parse("name", Locale::EnGb).is_err());
+        assert!(PositionsFilter::parse("color=red", Locale::EnGb).is_err());
+        assert!(PositionsFilter::parse("income~10", Locale::EnGb).is_err());
+        assert!(PositionsFilter::parse("edited>01.01.2026", Locale::EnGb).is_err());
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let positions = vec![
+            mock_position(0, "BTC", None),
+            mock_position(1, "btc-usd", Some(50f64)),
+            mock_position(2, "ETH", Some(150f64)),
+        ];
+
+        let ids = |expression: &str| -> Vec<i32> {
+            PositionsFilter::parse(expression, Locale::EnGb)
+                .unwrap()
+                .apply(&positions)
+                .iter()
+                .map(|position| position.id)
+                .collect()
+        };
+
+        assert_eq!(ids("name~BTC"), vec![0, 1]);
+        assert_eq!(ids("name=eth"), vec![2]);
+        assert_eq!(ids("status=active"), vec![0]);
+        assert_eq!(ids("status=closed income<0"), vec![1]);
+        assert_eq!(ids("percent>=50"), vec![2]);
+        assert_eq!(ids("name!~btc id>0"), vec![2]);
+        assert_eq!(ids("edited>2000-01-01"), vec![0, 1, 2]);
+
+        let mut positions = positions;
+        positions[0].set_tags("Crypto, long-term");
+        positions[2].set_tags("crypto");
+        let ids = |expression: &str| -> Vec<i32> {
+            PositionsFilter::parse(expression, Locale::EnGb)
+                .unwrap()
+                .apply(&positions)
+                .iter()
+                .map(|position| position.id)
+                .collect()
+        };
+        assert_eq!(ids("tag=crypto"), vec![0, 2]);
+        assert_eq!(ids("tag~long"), vec![0]);
+        assert_eq!(ids("tag!=crypto"), vec![1]);
+    }
+
+    #[test]
+    fn test_filter_localized_numbers() {
+        let positions = vec![
+            mock_position(0, "BTC", None),
+            mock_position(1, "ETH", Some(150f64)),
+        ];
+        let ids = |expression: &str, locale: Locale| -> Vec<i32> {
+            PositionsFilter::parse(expression, locale)
+                .unwrap()
+                .apply(&positions)
+                .iter()
+                .map(|position| position.id)
+                .collect()
+        };
+
+        assert_eq!(ids("amount>0,5", Locale::DeDe), vec![0]);
+        assert!(ids("value>=1.000", Locale::DeDe).is_empty());
+        assert_eq!(ids("income>49,5", Locale::DeDe), vec![1]);
+        assert_eq!(ids("price<1\u{202f}000,5", Locale::FrFr), vec![0, 1]);
+        assert_eq!(ids("amount>0.5", Locale::EnGb), vec![0]);
+        assert!(PositionsFilter::parse("amount>0,5", Locale::EnGb).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, Local, Weekday};
+use serde_json::{json, Map, Value};
+
+use crate::models::Position;
+use crate::tf;
+use crate::utils::format::escape_csv;
+
+pub const UNTAGGED_GROUP: &str = "untagged";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportPeriod {
+    // Weeks start from the given day
+    Week(Weekday),
+    Month,
+    Quarter,
+    Year,
+}
+
+impl ReportPeriod {
+    pub fn from_string(string: &str, first_weekday: Weekday) -> Result<ReportPeriod, String> {
+        match string.trim().to_lowercase().as_str() {
+            "week" | "w" => Ok(Self::Week(first_weekday)),
+            "month" | "m" => Ok(Self::Month),
+            "quarter" | "q" => Ok(Self::Quarter),
+            "year" | "y" => Ok(Self::Year),
+            _ => Err(tf!("error.unknown_report_period", string.trim())),
+        }
+    }
+
+    // Keys are sorted alphabetically in chronological order, e.g. "2026-03", "2026-Q1", "2026".
+    // Week is named by the date of its first day.
+    pub fn get_period_key(&self, date: DateTime<Local>) -> String {
+        match self {
+            Self::Week(first_weekday) => {
+                let days_since_start = date.weekday().days_since(*first_weekday);
+                let start = date.date_naive() - Duration::days(days_since_start as i64);
+                start.format("%Y-%m-%d").to_string()
+            }
+            Self::Month => format!("{}-{:02}", date.year(), date.month()),
+            Self::Quarter => format!("{}-Q{}", date.year(), (date.month() - 1) / 3 + 1),
+            Self::Year => date.year().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportGrouping {
+    Position,
+    Tag,
+}
+
+impl ReportGrouping {
+    pub fn from_string(string: &str) -> Result<ReportGrouping, String> {
+        match string.trim().to_lowercase().as_str() {
+            "position" | "p" => Ok(Self::Position),
+            "tag" | "t" => Ok(Self::Tag),
+            _ => Err(tf!("error.unknown_report_grouping", string.trim())),
+        }
+    }
+
+    fn get_group_names(&self, position: &Position) -> Vec<String> {
+        match self {
+            Self::Position => vec![format!("{} #{}", position.name, position.id)],
+            Self::Tag if position.tags.is_empty() => vec![String::from(UNTAGGED_GROUP)],
+            Self::Tag => position.tags.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncomeReportRow {
+    pub period: String,
+    pub income_by_group: Vec<f64>,
+    pub total: f64,
+    pub cumulative: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncomeReport {
+    pub groups: Vec<String>,
+    pub rows: Vec<IncomeReportRow>,
+}
+
+impl IncomeReport {
+    /// Buckets realized income of closing orders and income events by calendar period.
+    /// Position with several tags adds its income to each of them, so tag columns may sum up
+    /// to more than the period total.
+    pub fn build(
+        positions: &[Position],
+        period: ReportPeriod,
+        grouping: ReportGrouping,
+    ) -> IncomeReport {
+        let mut groups: Vec<String> = vec![];
+        let mut buckets: BTreeMap<String, (Vec<f64>, f64)> = BTreeMap::new();
+
+        for position in positions {
+            let realized_income = position.get_realized_income();
+            if realized_income.is_empty() {
+                continue;
+            }
+
+            let group_indexes: Vec<usize> = grouping
+                .get_group_names(position)
+                .into_iter()
+                .map(
+                    |name| match groups.iter().position(|group| *group == name) {
+                        Some(index) => index,
+                        None => {
+                            groups.push(name);
+                            groups.len() - 1
+                        }
+                    },
+                )
+                .collect();
+
+            for (date, income) in realized_income {
+                let (income_by_group, total) =
+                    buckets.entry(period.get_period_key(date)).or_default();
+                for index in &group_indexes {
+                    if income_by_group.len() <= *index {
+                        income_by_group.resize(index + 1, 0f64);
+                    }
+                    income_by_group[*index] += income;
+                }
+                *total += income;
+            }
+        }
+
+        let mut cumulative = 0f64;
+        let rows = buckets
+            .into_iter()
+            .map(|(period, (mut income_by_group, total))| {
+                income_by_group.resize(groups.len(), 0f64);
+                cumulative += total;
+                IncomeReportRow {
+                    period,
+                    income_by_group,
+                    total,
+                    cumulative,
+                }
+            })
+            .collect();
+
+        IncomeReport { groups, rows }
+    }
+
+    pub fn calculate_group_totals(&self) -> Vec<f64> {
+        (0..self.groups.len())
+            .map(|index| self.rows.iter().map(|row| row.income_by_group[index]).sum())
+            .collect()
+    }
+
+    pub fn calculate_total(&self) -> f64 {
+        self.rows.iter().map(|row| row.total).sum()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut header = vec![String::from("Period")];
+        header.extend(self.groups.iter().map(|group| escape_csv(group)));
+        header.push(String::from("Total"));
+        header.push(String::from("Cumulative"));
+
+        let mut lines = vec![header.join(",")];
+        for row in &self.rows {
+            let mut line = vec![row.period.clone()];
+            line.extend(row.income_by_group.iter().map(|income| income.to_string()));
+            line.push(row.total.to_string());
+            line.push(row.cumulative.to_string());
+            lines.push(line.join(","));
+        }
+
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        let rows: Vec<Value> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let income: Map<String, Value> = self
+                    .groups
+                    .iter()
+                    .cloned()
+                    .zip(row.income_by_group.iter().map(|income| json!(income)))
+                    .collect();
+                json!({
+                    "period": row.period,
+                    "income": income,
+                    "total": row.total,
+                    "cumulative": row.cumulative,
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&json!({ "rows": rows, "total": self.calculate_total() }))
+            .expect("serialize income report")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone, Weekday};
+
+    use super::{IncomeReport, ReportGrouping, ReportPeriod};
+    use crate::models::{Action, Order, Position};
+
+    fn mock_position(id: i32, tags: &str, sells: &[(u32, f64)]) -> Position {
+        let mut position = Position::new(
+            id,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 10f64,
+                value: 1000f64,
+                price: 100f64,
+                income: 0f64,
+                date: Local.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap(),
+            }],
+        );
+        position.set_tags(tags);
+
+        for (month, price) in sells {
+            let mut order = Order::new(&position, Action::Short, 1f64, *price);
+            order.date = Local.with_ymd_and_hms(2026, *month, 10, 12, 0, 0).unwrap();
+            position.add_order(order);
+        }
+
+        position
+    }
+
+    #[test]
+    fn test_income_report() {
+        let positions = vec![
+            mock_position(0, "crypto", &[(1, 150f64), (4, 90f64)]),
+            mock_position(1, "", &[(2, 120f64)]),
+            mock_position(2, "crypto", &[]),
+        ];
+
+        let report = IncomeReport::build(&positions, ReportPeriod::Quarter, ReportGrouping::Tag);
+        assert_eq!(report.groups, vec!["crypto", "untagged"]);
+        assert_eq!(report.rows.len(), 2);
+        assert_eq!(report.rows[0].period, "2026-Q1");
+        assert_eq!(report.rows[0].income_by_group, vec![50f64, 20f64]);
+        assert_eq!(report.rows[0].cumulative, 70f64);
+        assert_eq!(report.rows[1].period, "2026-Q2");
+        assert_eq!(report.rows[1].total, -10f64);
+        assert_eq!(report.rows[1].cumulative, 60f64);
+        assert_eq!(report.calculate_group_totals(), vec![40f64, 20f64]);
+
+        assert_eq!(
+            report.to_csv(),
+            "Period,crypto,untagged,Total,Cumulative\n2026-Q1,50,20,70,70\n2026-Q2,-10,0,-10,60"
+        );
+
+        let report = IncomeReport::build(&positions, ReportPeriod::Month, ReportGrouping::Position);
+        assert_eq!(report.groups, vec!["MOCK #0", "MOCK #1"]);
+        let periods: Vec<&str> = report.rows.iter().map(|row| row.period.as_str()).collect();
+        assert_eq!(periods, vec!["2026-01", "2026-02", "2026-04"]);
+
+        // 10 January 2026 is Saturday
+        let date = Local.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap();
+        let week = |first_weekday| {
+            ReportPeriod::from_string("week", first_weekday)
+                .unwrap()
+                .get_period_key(date)
+        };
+        assert_eq!(week(Weekday::Mon), "2026-01-05");
+        assert_eq!(week(Weekday::Sun), "2026-01-04");
+        assert_eq!(week(Weekday::Sat), "2026-01-10");
+    }
+}
@@ -1,2 +1,9 @@
+pub mod aliases;
+pub mod allocation;
 pub mod commands;
+pub mod dashboard;
+pub mod filtering;
+pub mod income_report;
+pub mod parser;
 pub mod sorting;
+pub mod tax_report;
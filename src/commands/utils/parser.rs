@@ -0,0 +1,220 @@
+use crate::i18n::t;
+use crate::tf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    value: String,
+    // Quoted tokens are never treated as keys, so '"--x"' is a positional argument
+    is_quoted: bool,
+}
+
+/// Arguments of the command: positional ones in order they are entered
+/// and named ones entered as `--key value` or `--key=value`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandArgs {
+    pub positional: Vec<String>,
+    pub named: Vec<(String, String)>,
+}
+
+impl CommandArgs {
+    /// Returns argument by its key, or by position if it's not named
+    pub fn get(&self, index: usize, key: &str) -> Option<&String> {
+        self.get_named(key).or(self.positional.get(index))
+    }
+
+    /// Assigns arguments to the keys in order. Named arguments don't take positions,
+    /// e.g. 'BTC --type long 0.5' gives values of name, type and amount.
+    pub fn resolve<const N: usize>(&self, keys: [&str; N]) -> [Option<&String>; N] {
+        let mut positional = self.positional.iter();
+        keys.map(|key| match self.get_named(key) {
+            Some(value) => Some(value),
+            None => positional.next(),
+        })
+    }
+
+    // Arguments of the other command go after these ones and override their named values
+    pub fn extend(&mut self, other: CommandArgs) {
+        self.positional.extend(other.positional);
+        self.named.extend(other.named);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positional.is_empty() && self.named.is_empty()
+    }
+
+    pub fn get_named(&self, key: &str) -> Option<&String> {
+        self.named
+            .iter()
+            .rev()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    // Used by commands, which accept text with spaces, e.g. filter expression
+    pub fn join_from(&self, index: usize, key: &str) -> Option<String> {
+        if let Some(value) = self.get_named(key) {
+            return Some(value.clone());
+        }
+
+        match self.positional.get(index..) {
+            Some(values) if !values.is_empty() => Some(values.join(" ")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCommand {
+    pub name: String,
+    pub args: CommandArgs,
+}
+
+/// Splits input into command name and arguments. Returns None for empty input.
+pub fn parse_command(input: &str) -> Result<Option<ParsedCommand>, String> {
+    let mut tokens = tokenize(input)?.into_iter();
+    let name = match tokens.next() {
+        Some(token) => token.value,
+        None => return Ok(None),
+    };
+
+    let mut args = CommandArgs::default();
+    while let Some(token) = tokens.next() {
+        let key = match token.value.strip_prefix("--") {
+            Some(key) if !token.is_quoted && !key.is_empty() => key,
+            _ => {
+                args.positional.push(token.value);
+                continue;
+            }
+        };
+
+        if let Some((key, value)) = key.split_once('=') {
+            args.named.push((key.to_string(), value.to_string()));
+            continue;
+        }
+
+        match tokens.next() {
+            Some(value) => args.named.push((key.to_string(), value.value)),
+            None => return Err(tf!("error.missing_argument_value", key)),
+        }
+    }
+
+    Ok(Some(ParsedCommand { name, args }))
+}
+
+// Splits input by whitespace. Double or single quotes keep spaces inside the token,
+// backslash escapes the next character. No-break spaces separate thousands of numbers,
+// so they don't split tokens.
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut current: Option<Token> = None;
+    let mut quote: Option<char> = None;
+    let mut chars = input.trim().chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                let escaped = match chars.next() {
+                    Some(escaped) => escaped,
+                    None => return Err(t("error.nothing_to_escape")),
+                };
+                push_char(&mut current, escaped);
+            }
+            '"' | '\'' if quote == Some(char) => quote = None,
+            '"' | '\'' if quote.is_none() => {
+                quote = Some(char);
+                current
+                    .get_or_insert_with(|| Token {
+                        value: String::new(),
+                        is_quoted: true,
+                    })
+                    .is_quoted = true;
+            }
+            char if char.is_whitespace() && !is_no_break_space(char) && quote.is_none() => {
+                if let Some(token) = current.take() {
+                    tokens.push(token);
+                }
+            }
+            char => push_char(&mut current, char),
+        }
+    }
+
+    if let Some(quote) = quote {
+        return Err(tf!("error.unclosed_quote", quote));
+    }
+
+    if let Some(token) = current {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+// Thousands separator of some locales, which doesn't split numbers into tokens
+pub fn is_no_break_space(char: char) -> bool {
+    matches!(char, '\u{a0}' | '\u{202f}')
+}
+
+fn push_char(token: &mut Option<Token>, char: char) {
+    token
+        .get_or_insert_with(|| Token {
+            value: String::new(),
+            is_quoted: false,
+        })
+        .value
+        .push(char);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_command, CommandArgs};
+
+    fn parse_args(input: &str) -> CommandArgs {
+        parse_command(input).unwrap().unwrap().args
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let command = parse_command("  a   BTC long  0.5 15000 ")
+            .unwrap()
+            .unwrap();
+        assert_eq!(command.name, "a");
+        assert_eq!(command.args.positional, vec!["BTC", "long", "0.5", "15000"]);
+
+        let args = parse_args(r#"a "Apple Inc" --value 300 --amount=2 long"#);
+        assert_eq!(args.positional, vec!["Apple Inc", "long"]);
+        assert_eq!(args.get(0, "name").unwrap(), "Apple Inc");
+        assert_eq!(args.get(4, "price"), None);
+        let [name, action, amount, value, price] =
+            args.resolve(["name", "type", "amount", "value", "price"]);
+        assert_eq!(name.unwrap(), "Apple Inc");
+        assert_eq!(action.unwrap(), "long");
+        assert_eq!(amount.unwrap(), "2");
+        assert_eq!(value.unwrap(), "300");
+        assert_eq!(price, None);
+
+        // Preset arguments of alias go first, entered ones override them
+        let mut args = parse_args("a --type long --value 1");
+        args.extend(parse_args("a BTC 0.5 --value 15000"));
+        let [name, action, amount, value] = args.resolve(["name", "type", "amount", "value"]);
+        assert_eq!(name.unwrap(), "BTC");
+        assert_eq!(action.unwrap(), "long");
+        assert_eq!(amount.unwrap(), "0.5");
+        assert_eq!(value.unwrap(), "15000");
+
+        let args = parse_args(r#"t 'it''s' a\ b "--x" \"q\""#);
+        assert_eq!(args.positional, vec!["its", "a b", "--x", "\"q\""]);
+        assert_eq!(args.join_from(1, "tags").unwrap(), "a b --x \"q\"");
+
+        // Number grouped by narrow no-break spaces is a single argument
+        let args = parse_args("a BTC long 1\u{202f}500,5 15000");
+        assert_eq!(
+            args.positional,
+            vec!["BTC", "long", "1\u{202f}500,5", "15000"]
+        );
+
+        assert_eq!(parse_command("   ").unwrap(), None);
+        assert!(parse_command("a \"BTC").is_err());
+        assert!(parse_command("a --name").is_err());
+        assert!(parse_command("a BTC\\").is_err());
+    }
+}
@@ -1,4 +1,11 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::Local;
+
+use crate::i18n::t;
 use crate::models::Position;
+use crate::tf;
 
 #[derive(Debug, Clone, Copy)]
 pub enum SortDirection {
@@ -6,11 +13,21 @@ pub enum SortDirection {
     Ascending,
 }
 
-impl ToString for SortDirection {
-    fn to_string(&self) -> String {
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Ascending => String::from("Asc"),
-            Self::Descending => String::from("Desc"),
+            Self::Ascending => write!(f, "{}", t("sorting.asc")),
+            Self::Descending => write!(f, "{}", t("sorting.desc")),
+        }
+    }
+}
+
+impl SortDirection {
+    pub fn from_string(string: &str) -> Result<SortDirection, String> {
+        match string.trim().to_lowercase().as_str() {
+            "asc" | "a" => Ok(Self::Ascending),
+            "desc" | "d" => Ok(Self::Descending),
+            _ => Err(tf!("error.parse_direction", string.trim())),
         }
     }
 }
@@ -22,52 +39,211 @@ pub enum SortBy {
     LastChange(SortDirection),
     AvgPrice(SortDirection),
     Income(SortDirection),
+    Name(SortDirection),
+    NaturalName(SortDirection),
+    Amount(SortDirection),
+    IncomePercent(SortDirection),
+    OrdersCount(SortDirection),
+    CreatedAt(SortDirection),
+    Status(SortDirection),
+    AnnualizedReturn(SortDirection),
+    Xirr(SortDirection),
 }
 
-impl ToString for SortBy {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Id(direction) => format!("Id ({})", direction.to_string()),
-            Self::AvgValue(direction) => format!("Avg value ({})", direction.to_string()),
-            Self::LastChange(direction) => format!("Last change ({})", direction.to_string()),
-            Self::AvgPrice(direction) => format!("Avg price ({})", direction.to_string()),
-            Self::Income(direction) => format!("Income ({})", direction.to_string()),
+pub type SortByConstructor = fn(SortDirection) -> SortBy;
+
+// Message keys of sorting methods in order they are shown in the sorting menu
+pub const SORTING_METHODS: [(&str, SortByConstructor); 14] = [
+    ("sorting.by_id", SortBy::Id),
+    ("sorting.by_avg_value", SortBy::AvgValue),
+    ("sorting.by_avg_price", SortBy::AvgPrice),
+    ("sorting.by_income", SortBy::Income),
+    ("sorting.by_last_change", SortBy::LastChange),
+    ("sorting.by_name", SortBy::Name),
+    ("sorting.by_natural_name", SortBy::NaturalName),
+    ("sorting.by_amount", SortBy::Amount),
+    ("sorting.by_income_percent", SortBy::IncomePercent),
+    ("sorting.by_orders_count", SortBy::OrdersCount),
+    ("sorting.by_created_at", SortBy::CreatedAt),
+    ("sorting.by_status", SortBy::Status),
+    ("sorting.by_annualized_return", SortBy::AnnualizedReturn),
+    ("sorting.by_xirr", SortBy::Xirr),
+];
+
+// Parses comma separated numbers of sorting methods from the menu, e.g. '12,4'
+pub fn parse_sorting_methods(
+    choice: &str,
+) -> Result<Vec<(&'static str, SortByConstructor)>, String> {
+    choice
+        .split(',')
+        .map(|number_str| match number_str.trim().parse::<usize>() {
+            Ok(number) if number >= 1 && number <= SORTING_METHODS.len() => {
+                Ok(SORTING_METHODS[number - 1])
+            }
+            _ => Err(tf!("error.parse_sorting_method", number_str.trim())),
+        })
+        .collect()
+}
+
+// Used for new portfolios and ones stored without sorting
+impl Default for SortBy {
+    fn default() -> Self {
+        SortBy::LastChange(SortDirection::Ascending)
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Id(_) => t("sorting.id"),
+            Self::AvgValue(_) => t("sorting.avg_value"),
+            Self::LastChange(_) => t("sorting.last_change"),
+            Self::AvgPrice(_) => t("sorting.avg_price"),
+            Self::Income(_) => t("sorting.income"),
+            Self::Name(_) => t("sorting.name"),
+            Self::NaturalName(_) => t("sorting.natural_name"),
+            Self::Amount(_) => t("sorting.amount"),
+            Self::IncomePercent(_) => t("sorting.income_percent"),
+            Self::OrdersCount(_) => t("sorting.orders_count"),
+            Self::CreatedAt(_) => t("sorting.created_at"),
+            Self::Status(_) => t("sorting.status"),
+            Self::AnnualizedReturn(_) => t("sorting.annualized_return"),
+            Self::Xirr(_) => t("sorting.xirr"),
+        };
+        write!(f, "{} ({})", name, self.direction())
+    }
+}
+
+impl SortBy {
+    pub fn direction(&self) -> SortDirection {
+        match *self {
+            Self::Id(direction)
+            | Self::AvgValue(direction)
+            | Self::LastChange(direction)
+            | Self::AvgPrice(direction)
+            | Self::Income(direction)
+            | Self::Name(direction)
+            | Self::NaturalName(direction)
+            | Self::Amount(direction)
+            | Self::IncomePercent(direction)
+            | Self::OrdersCount(direction)
+            | Self::CreatedAt(direction)
+            | Self::Status(direction)
+            | Self::AnnualizedReturn(direction)
+            | Self::Xirr(direction) => direction,
+        }
+    }
+
+    // Positions table is rendered in reversed order, so descending sorting orders positions
+    // from lowest to highest
+    pub fn compare(&self, first: &Position, second: &Position) -> Ordering {
+        let ordering = match self {
+            Self::Id(_) => first.id.cmp(&second.id),
+            Self::AvgValue(_) => first.avg_value.total_cmp(&second.avg_value),
+            Self::LastChange(_) => first.edited_at.cmp(&second.edited_at),
+            Self::AvgPrice(_) => first.avg_price.total_cmp(&second.avg_price),
+            Self::Income(_) => first.income.total_cmp(&second.income),
+            Self::Name(_) => first.name.to_lowercase().cmp(&second.name.to_lowercase()),
+            Self::NaturalName(_) => compare_natural(&first.name, &second.name),
+            Self::Amount(_) => first.amount.total_cmp(&second.amount),
+            Self::IncomePercent(_) => first
+                .calculate_income_percent()
+                .total_cmp(&second.calculate_income_percent()),
+            Self::OrdersCount(_) => first.orders.len().cmp(&second.orders.len()),
+            Self::CreatedAt(_) => first.created_at.cmp(&second.created_at),
+            Self::Status(_) => first.is_closed().cmp(&second.is_closed()),
+            // Positions without return, e.g. opened today, are treated as the lowest ones
+            Self::AnnualizedReturn(_) => {
+                let now = Local::now();
+                first
+                    .calculate_annualized_return(now)
+                    .unwrap_or(f64::NEG_INFINITY)
+                    .total_cmp(
+                        &second
+                            .calculate_annualized_return(now)
+                            .unwrap_or(f64::NEG_INFINITY),
+                    )
+            }
+            Self::Xirr(_) => {
+                let now = Local::now();
+                first
+                    .calculate_xirr(now)
+                    .unwrap_or(f64::NEG_INFINITY)
+                    .total_cmp(&second.calculate_xirr(now).unwrap_or(f64::NEG_INFINITY))
+            }
+        };
+
+        match self.direction() {
+            SortDirection::Descending => ordering,
+            SortDirection::Ascending => ordering.reverse(),
+        }
+    }
+}
+
+/// Compares strings case-insensitively, treating digit groups as numbers, so "BTC2" goes
+/// before "BTC10"
+pub fn compare_natural(first: &str, second: &str) -> Ordering {
+    let first_chunks = split_natural_chunks(&first.to_lowercase());
+    let second_chunks = split_natural_chunks(&second.to_lowercase());
+
+    for (first_chunk, second_chunk) in first_chunks.iter().zip(second_chunks.iter()) {
+        let first_is_number = first_chunk.starts_with(|c: char| c.is_ascii_digit());
+        let second_is_number = second_chunk.starts_with(|c: char| c.is_ascii_digit());
+
+        let ordering = if first_is_number && second_is_number {
+            let first_number = first_chunk.trim_start_matches('0');
+            let second_number = second_chunk.trim_start_matches('0');
+            first_number
+                .len()
+                .cmp(&second_number.len())
+                .then_with(|| first_number.cmp(second_number))
+        } else {
+            first_chunk.cmp(second_chunk)
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    first_chunks.len().cmp(&second_chunks.len())
+}
+
+fn split_natural_chunks(string: &str) -> Vec<String> {
+    let mut chunks: Vec<String> = vec![];
+    let mut previous_is_digit = None;
+
+    for c in string.chars() {
+        let is_digit = c.is_ascii_digit();
+        match chunks.last_mut() {
+            Some(chunk) if previous_is_digit == Some(is_digit) => chunk.push(c),
+            _ => chunks.push(c.to_string()),
         }
+        previous_is_digit = Some(is_digit);
     }
+
+    chunks
 }
 
 pub struct PositionsSorter {
     pub sort_by: SortBy,
+    // Secondary sort keys, used when positions are equal by previous keys
+    pub then_sort_by: Vec<SortBy>,
     pub hide_closed: bool,
     pub move_closed_to_bottom: bool,
 }
 
 impl PositionsSorter {
-    pub fn sort(&self, positions: &Vec<Position>) -> Vec<Position> {
-        let mut positions = positions.clone();
-
-        match self.sort_by {
-            SortBy::Id(direction) => positions.sort_by(|first, second| match direction {
-                SortDirection::Descending => first.id.cmp(&second.id),
-                SortDirection::Ascending => second.id.cmp(&first.id),
-            }),
-            SortBy::AvgValue(direction) => positions.sort_by(|first, second| match direction {
-                SortDirection::Descending => first.avg_value.total_cmp(&second.avg_price),
-                SortDirection::Ascending => second.avg_value.total_cmp(&first.avg_value),
-            }),
-            SortBy::Income(direction) => positions.sort_by(|first, second| match direction {
-                SortDirection::Descending => first.income.total_cmp(&second.income),
-                SortDirection::Ascending => second.income.total_cmp(&first.income),
-            }),
-            SortBy::LastChange(direction) => positions.sort_by(|first, second| match direction {
-                SortDirection::Descending => first.edited_at.cmp(&second.edited_at),
-                SortDirection::Ascending => second.edited_at.cmp(&first.edited_at),
-            }),
-            SortBy::AvgPrice(direction) => positions.sort_by(|first, second| match direction {
-                SortDirection::Descending => first.avg_price.total_cmp(&second.avg_price),
-                SortDirection::Ascending => second.avg_price.total_cmp(&first.avg_price),
-            }),
-        }
+    pub fn sort(&self, positions: &[Position]) -> Vec<Position> {
+        let mut positions = positions.to_vec();
+
+        positions.sort_by(|first, second| {
+            self.then_sort_by
+                .iter()
+                .fold(self.sort_by.compare(first, second), |ordering, sort_by| {
+                    ordering.then_with(|| sort_by.compare(first, second))
+                })
+        });
 
         if self.hide_closed {
             let active_positions = split_positions_by_status(&positions).0;
@@ -87,18 +263,104 @@ impl PositionsSorter {
 
         positions
     }
+
+    pub fn describe(&self) -> String {
+        let mut keys = vec![self.sort_by.to_string()];
+        self.then_sort_by
+            .iter()
+            .for_each(|sort_by| keys.push(sort_by.to_string()));
+        keys.join(&t("sorting.then_separator"))
+    }
 }
 
-fn split_positions_by_status(positions: &Vec<Position>) -> (Vec<Position>, Vec<Position>) {
+fn split_positions_by_status(positions: &[Position]) -> (Vec<Position>, Vec<Position>) {
     let mut active_positions = vec![];
     let mut closed_positions = vec![];
 
-    positions
-        .iter()
-        .for_each(|pos| match pos.avg_value == 0f64 {
-            false => active_positions.push(pos.clone()),
-            true => closed_positions.push(pos.clone()),
-        });
+    positions.iter().for_each(|pos| match pos.is_closed() {
+        false => active_positions.push(pos.clone()),
+        true => closed_positions.push(pos.clone()),
+    });
 
     (active_positions, closed_positions)
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use std::cmp::Ordering;
+
+    use super::{compare_natural, parse_sorting_methods, PositionsSorter, SortBy, SortDirection};
+    use crate::models::{Action, Order, Position};
+
+    fn mock_position(id: i32, name: &str, buy_value: f64, sell_value: Option<f64>) -> Position {
+        let mut position = Position::new(
+            id,
+            String::from(name),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 1f64,
+                value: buy_value,
+                price: buy_value,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+
+        if let Some(value) = sell_value {
+            position.add_order(Order::new(&position, Action::Short, 1f64, value));
+        }
+
+        position
+    }
+
+    #[test]
+    fn test_compare_natural() {
+        assert_eq!(compare_natural("BTC2", "BTC10"), Ordering::Less);
+        assert_eq!(compare_natural("btc10", "BTC2"), Ordering::Greater);
+        assert_eq!(compare_natural("ETH", "eth"), Ordering::Equal);
+        assert_eq!(compare_natural("a", "a1"), Ordering::Less);
+        assert_eq!(compare_natural("x007", "x7"), Ordering::Equal);
+        assert_eq!(compare_natural("10", "9a"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_sorter_multiple_keys() {
+        let positions = vec![
+            mock_position(0, "A", 100f64, Some(150f64)),
+            mock_position(1, "B", 100f64, None),
+            mock_position(2, "C", 100f64, Some(300f64)),
+            mock_position(3, "D", 200f64, None),
+        ];
+
+        let sorter = PositionsSorter {
+            sort_by: SortBy::Status(SortDirection::Ascending),
+            then_sort_by: vec![
+                SortBy::Income(SortDirection::Descending),
+                SortBy::AvgValue(SortDirection::Ascending),
+            ],
+            hide_closed: false,
+            move_closed_to_bottom: false,
+        };
+
+        // Table is rendered reversed, so the last position goes first
+        let mut ids: Vec<i32> = sorter.sort(&positions).iter().map(|pos| pos.id).collect();
+        ids.reverse();
+
+        assert_eq!(ids, vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn test_parse_sorting_methods() {
+        let methods = parse_sorting_methods(" 12, 4").unwrap();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].0, "sorting.by_status");
+        assert_eq!(methods[1].0, "sorting.by_income");
+
+        assert!(parse_sorting_methods("0").is_err());
+        assert!(parse_sorting_methods("15").is_err());
+        assert!(parse_sorting_methods("1,").is_err());
+    }
+}
@@ -0,0 +1,344 @@
+use chrono::{DateTime, Datelike, Local};
+
+use crate::models::position::AMOUNT_EPSILON;
+use crate::models::{Action, Position};
+use crate::utils::format::escape_csv;
+
+/// Part of closing order matched with a single acquisition lot
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disposal {
+    pub position_id: i32,
+    pub name: String,
+    pub amount: f64,
+    pub acquired_at: DateTime<Local>,
+    pub disposed_at: DateTime<Local>,
+    pub proceeds: f64,
+    pub cost_basis: f64,
+    pub gain: f64,
+    pub is_long_term: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaxSummary {
+    pub proceeds: f64,
+    pub cost_basis: f64,
+    pub short_term_gain: f64,
+    pub long_term_gain: f64,
+}
+
+impl TaxSummary {
+    pub fn total_gain(&self) -> f64 {
+        self.short_term_gain + self.long_term_gain
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxReport {
+    pub year: Option<i32>,
+    pub disposals: Vec<Disposal>,
+}
+
+struct Lot {
+    amount: f64,
+    acquired_at: DateTime<Local>,
+}
+
+// Reward units open a new lot, splits change amounts of all opened lots
+enum LotAdjustment {
+    Units(f64),
+    Split(f64),
+}
+
+impl TaxReport {
+    /// Lists realized disposals of positions, optionally only ones made in the given year.
+    /// Cost basis is the position's average price, so gains add up to position income.
+    /// Lots are matched first-in-first-out only to find acquisition dates, so closing order
+    /// is split into several disposals if it closes more than one lot.
+    pub fn build(positions: &[Position], year: Option<i32>, holding_days: i64) -> TaxReport {
+        let disposals = positions
+            .iter()
+            .flat_map(|position| find_disposals(position, holding_days))
+            .filter(|disposal| year.is_none_or(|year| disposal.disposed_at.year() == year))
+            .collect();
+
+        TaxReport { year, disposals }
+    }
+
+    pub fn calculate_summary(&self) -> TaxSummary {
+        let mut summary = TaxSummary::default();
+        for disposal in &self.disposals {
+            summary.proceeds += disposal.proceeds;
+            summary.cost_basis += disposal.cost_basis;
+            if disposal.is_long_term {
+                summary.long_term_gain += disposal.gain;
+            } else {
+                summary.short_term_gain += disposal.gain;
+            }
+        }
+
+        summary
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec![String::from(
+            "Position,Name,Amount,Acquired,Disposed,Proceeds,Cost basis,Gain,Term",
+        )];
+
+        for disposal in &self.disposals {
+            lines.push(
+                [
+                    disposal.position_id.to_string(),
+                    escape_csv(&disposal.name),
+                    disposal.amount.to_string(),
+                    disposal.acquired_at.format("%Y-%m-%d").to_string(),
+                    disposal.disposed_at.format("%Y-%m-%d").to_string(),
+                    disposal.proceeds.to_string(),
+                    disposal.cost_basis.to_string(),
+                    disposal.gain.to_string(),
+                    String::from(if disposal.is_long_term {
+                        "long"
+                    } else {
+                        "short"
+                    }),
+                ]
+                .join(","),
+            );
+        }
+
+        let summary = self.calculate_summary();
+        lines.push(String::new());
+        lines.push(format!("Total proceeds,{}", summary.proceeds));
+        lines.push(format!("Total cost basis,{}", summary.cost_basis));
+        lines.push(format!("Short-term gain,{}", summary.short_term_gain));
+        lines.push(format!("Long-term gain,{}", summary.long_term_gain));
+        lines.push(format!("Total gain,{}", summary.total_gain()));
+
+        lines.join("\n")
+    }
+}
+
+// Replays position history the same way position is recalculated, keeping opened lots
+fn find_disposals(position: &Position, holding_days: i64) -> Vec<Disposal> {
+    let mut adjustments: Vec<(i32, DateTime<Local>, LotAdjustment)> = position
+        .income_events
+        .iter()
+        .filter(|event| event.units != 0f64)
+        .map(|event| {
+            let adjustment = LotAdjustment::Units(event.units);
+            (event.after_order_id, event.date, adjustment)
+        })
+        .chain(
+            position
+                .corporate_actions
+                .iter()
+                .filter(|action| action.get_amount_factor() != 1f64)
+                .map(|action| {
+                    let adjustment = LotAdjustment::Split(action.get_amount_factor());
+                    (action.after_order_id, action.date, adjustment)
+                }),
+        )
+        .collect();
+    adjustments.sort_by_key(|(after_order_id, date, _)| (*after_order_id, *date));
+    let mut adjustments = adjustments.into_iter().peekable();
+
+    let mut lots: Vec<Lot> = vec![];
+    let mut disposals = vec![];
+    let mut orders = position.orders.clone();
+    orders.sort_by_key(|order| order.id);
+
+    for order in &orders {
+        if order.action == position.action {
+            lots.push(Lot {
+                amount: order.amount,
+                acquired_at: order.date,
+            });
+        } else {
+            // Long position is sold for order value, short one was sold at average price
+            let (proceeds, cost_basis) = match position.action {
+                Action::Long => (order.value, order.value - order.income),
+                Action::Short => (order.value + order.income, order.value),
+            };
+
+            let mut amount_left = order.amount;
+            while amount_left > AMOUNT_EPSILON {
+                let (amount, acquired_at) = match lots.first_mut() {
+                    Some(lot) => {
+                        let amount = lot.amount.min(amount_left);
+                        lot.amount -= amount;
+                        (amount, lot.acquired_at)
+                    }
+                    None => (amount_left, order.date),
+                };
+                lots.retain(|lot| lot.amount > AMOUNT_EPSILON);
+                amount_left -= amount;
+
+                let share = amount / order.amount;
+                disposals.push(Disposal {
+                    position_id: position.id,
+                    name: position.name.clone(),
+                    amount,
+                    acquired_at,
+                    disposed_at: order.date,
+                    proceeds: proceeds * share,
+                    cost_basis: cost_basis * share,
+                    gain: order.income * share,
+                    is_long_term: (order.date - acquired_at).num_days() > holding_days,
+                });
+            }
+        }
+
+        while let Some((_, date, adjustment)) =
+            adjustments.next_if(|(after_order_id, _, _)| *after_order_id <= order.id)
+        {
+            match adjustment {
+                LotAdjustment::Units(units) => lots.push(Lot {
+                    amount: units,
+                    acquired_at: date,
+                }),
+                LotAdjustment::Split(factor) => {
+                    lots.iter_mut().for_each(|lot| lot.amount *= factor)
+                }
+            }
+        }
+    }
+
+    disposals
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, TimeZone};
+
+    use super::TaxReport;
+    use crate::models::{Action, CorporateAction, CorporateActionKind, Order, Position};
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+    }
+
+    fn add_order(
+        position: &mut Position,
+        action: Action,
+        amount: f64,
+        value: f64,
+        at: DateTime<Local>,
+    ) {
+        let mut order = Order::new(position, action, amount, value);
+        order.date = at;
+        position.add_order(order);
+    }
+
+    fn mock_positions() -> Vec<Position> {
+        let first_order = Order {
+            id: 0,
+            action: Action::Long,
+            amount: 10f64,
+            value: 1000f64,
+            price: 100f64,
+            income: 0f64,
+            date: date(2024, 1, 10),
+        };
+
+        // Average price is 150, 10 units are held for 508 days and 5 units for 92 days
+        let mut averaged = Position::new(0, String::from("AAPL"), vec![first_order]);
+        add_order(
+            &mut averaged,
+            Action::Long,
+            10f64,
+            2000f64,
+            date(2025, 3, 1),
+        );
+        add_order(
+            &mut averaged,
+            Action::Short,
+            15f64,
+            4500f64,
+            date(2025, 6, 1),
+        );
+
+        // 10 units bought for 100 turn into 20 units for 50 after the split
+        let mut split = Position::new(
+            1,
+            String::from("TSLA"),
+            vec![Order {
+                date: date(2024, 1, 1),
+                ..first_order
+            }],
+        );
+        split.add_corporate_action(CorporateAction {
+            id: 0,
+            kind: CorporateActionKind::Split(2f64),
+            after_order_id: 0,
+            date: date(2024, 3, 1),
+        });
+        add_order(&mut split, Action::Short, 20f64, 1200f64, date(2024, 6, 1));
+
+        vec![averaged, split]
+    }
+
+    #[test]
+    fn test_tax_report_disposals() {
+        let report = TaxReport::build(&mock_positions(), None, 365);
+        assert_eq!(report.disposals.len(), 3);
+
+        let long_term = &report.disposals[0];
+        assert_eq!(long_term.amount, 10f64);
+        assert_eq!(long_term.acquired_at, date(2024, 1, 10));
+        assert_eq!(long_term.disposed_at, date(2025, 6, 1));
+        assert_eq!(long_term.proceeds, 3000f64);
+        assert_eq!(long_term.cost_basis, 1500f64);
+        assert_eq!(long_term.gain, 1500f64);
+        assert!(long_term.is_long_term);
+
+        let short_term = &report.disposals[1];
+        assert_eq!(short_term.amount, 5f64);
+        assert_eq!(short_term.acquired_at, date(2025, 3, 1));
+        assert_eq!(short_term.proceeds, 1500f64);
+        assert_eq!(short_term.cost_basis, 750f64);
+        assert_eq!(short_term.gain, 750f64);
+        assert!(!short_term.is_long_term);
+
+        let split = &report.disposals[2];
+        assert_eq!(split.amount, 20f64);
+        assert_eq!(split.acquired_at, date(2024, 1, 1));
+        assert_eq!(split.proceeds, 1200f64);
+        assert_eq!(split.cost_basis, 1000f64);
+        assert_eq!(split.gain, 200f64);
+        assert!(!split.is_long_term);
+
+        let summary = report.calculate_summary();
+        assert_eq!(summary.proceeds, 5700f64);
+        assert_eq!(summary.cost_basis, 3250f64);
+        assert_eq!(summary.short_term_gain, 950f64);
+        assert_eq!(summary.long_term_gain, 1500f64);
+        assert_eq!(summary.total_gain(), 2450f64);
+
+        // Shorter holding period makes all disposals long-term
+        let report = TaxReport::build(&mock_positions(), None, 30);
+        assert_eq!(report.calculate_summary().long_term_gain, 2450f64);
+    }
+
+    #[test]
+    fn test_tax_report_year_filter_and_csv() {
+        let report = TaxReport::build(&mock_positions(), Some(2024), 365);
+        assert_eq!(report.disposals.len(), 1);
+        assert_eq!(
+            report.to_csv(),
+            [
+                "Position,Name,Amount,Acquired,Disposed,Proceeds,Cost basis,Gain,Term",
+                "1,TSLA,20,2024-01-01,2024-06-01,1200,1000,200,short",
+                "",
+                "Total proceeds,1200",
+                "Total cost basis,1000",
+                "Short-term gain,200",
+                "Long-term gain,0",
+                "Total gain,200",
+            ]
+            .join("\n")
+        );
+
+        assert!(TaxReport::build(&mock_positions(), Some(2023), 365)
+            .disposals
+            .is_empty());
+    }
+}
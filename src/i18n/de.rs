@@ -0,0 +1,420 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("help.tooltip", "Geben Sie 'h' für Hilfe ein"),
+    ("help.available_commands", "Verfügbare Befehle:"),
+    ("help.show_help", "Hilfeseite anzeigen"),
+    ("help.exit", "Anwendung beenden"),
+    ("help.add_position", "Neue Position hinzufügen"),
+    ("help.edit_position", "Position bearbeiten"),
+    ("help.delete_position", "Position löschen"),
+    ("help.change_sorting", "Sortierung ändern"),
+    ("help.filter_positions", "Positionen filtern (Felder: id, name, status, amount, value, price, income, percent, edited)"),
+    ("help.next_page", "Nächste Seite anzeigen"),
+    ("help.previous_page", "Vorherige Seite anzeigen"),
+    ("help.portfolios", "Portfolios:"),
+    ("help.add_portfolio", "Neues Portfolio hinzufügen"),
+    ("help.switch_portfolio", "Portfolio wechseln, 'all' zeigt alle Konten"),
+    ("help.rename_portfolio", "Portfolio umbenennen"),
+    ("help.delete_portfolio", "Portfolio löschen"),
+    ("help.cash", "Bargeld:"),
+    ("help.deposit", "Bargeld einzahlen"),
+    ("help.withdraw", "Bargeld abheben"),
+    ("help.cash_ledger", "Kassenbuch anzeigen"),
+    ("help.set_market_prices", "Marktpreis der aktiven Positionen mit dem Namen festlegen"),
+    ("help.allocation", "Aufteilung:"),
+    ("help.target_weights", "Zielgewichte von Namen und Tags festlegen"),
+    ("help.show_allocation", "Aufteilung anzeigen und Positionen auf Zielgewichte umschichten"),
+    ("help.toggle_margin", "Negativen Kassenstand erlauben oder verbieten"),
+    ("help.reports", "Berichte:"),
+    ("help.income_report", "Realisierten Ertrag nach Woche, Monat, Quartal oder Jahr anzeigen"),
+    ("help.tax_report", "Realisierte Veräußerungen für die Steuererklärung anzeigen und als CSV exportieren"),
+    ("help.portfolio_charts", "Diagramme des kumulierten realisierten Ertrags und der Aufteilung zeichnen"),
+    ("help.dashboard", "Dashboard mit investiertem Kapital, Ertrag, Trefferquote und Eigenkapital anzeigen"),
+    ("help.return_to_positions", "Zurück zu den Positionen"),
+    ("help.add_order", "Neue Order hinzufügen"),
+    ("help.delete_order", "Order löschen"),
+    ("help.add_income_event", "Ertrag hinzufügen (Dividende, Zinsen oder Belohnung)"),
+    ("help.delete_income_event", "Ertrag löschen"),
+    ("help.add_corporate_action", "Kapitalmaßnahme hinzufügen (Split, Reverse Split oder Umbenennung)"),
+    ("help.delete_corporate_action", "Kapitalmaßnahme löschen"),
+    ("help.simulate", "Order, Schließen der Position zum Preis oder Erreichen des Ertragsziels simulieren"),
+    ("help.price_levels", "Stop-Loss und Take-Profit festlegen"),
+    ("help.set_market_price", "Marktpreis festlegen"),
+    ("help.averaging", "Menge berechnen, die den Durchschnittspreis auf den gewünschten verschiebt"),
+    ("help.position_chart", "Orderpreise und Durchschnittspreis im Zeitverlauf zeichnen"),
+    ("help.set_tags", "Kommagetrennte Tags festlegen, z. B. 'crypto,long-term'"),
+    ("help.aliases", "Aliase:"),
+    ("help.macros", "Makros:"),
+    ("help.arguments", "Argumente können nach Position oder Namen übergeben werden, z. B. 'a BTC long 0.5 15000' oder 'a BTC --value 15000'.\nWerte mit Leerzeichen in Anführungszeichen setzen, z. B. 'a \"Apple Inc\" long 2 300'. Fehlende Werte werden abgefragt."),
+
+    ("prompt.position_name", "Namen der Position eingeben"),
+    ("prompt.order_type", "Ordertyp eingeben (long/short)"),
+    ("prompt.position_amount", "Menge der Position eingeben"),
+    ("prompt.position_value", "Wert der Position eingeben"),
+    ("prompt.position_id", "ID der Position eingeben"),
+    ("prompt.order_id", "ID der Order eingeben"),
+    ("prompt.sorting_direction", "Richtung der Sortierung nach {} wählen (asc, desc): "),
+    ("prompt.filter_expression", "Filterausdruck eingeben, z. B. 'name~BTC status=active income<0 edited>2026-01-01'\nLeer lassen, um den Filter zurückzusetzen"),
+    ("prompt.portfolio_name", "Namen des Portfolios eingeben"),
+    ("prompt.portfolio_id_or_all", "ID des Portfolios oder 'all' für alle Konten eingeben"),
+    ("prompt.portfolio_id", "ID des Portfolios eingeben"),
+    ("prompt.new_portfolio_name", "Neuen Namen des Portfolios eingeben"),
+    ("prompt.deposit_amount", "Einzahlungsbetrag eingeben"),
+    ("prompt.withdrawal_amount", "Auszahlungsbetrag eingeben"),
+    ("prompt.report_period", "Berichtszeitraum eingeben (week/month/quarter/year)"),
+    ("prompt.report_grouping", "Ertrag gruppieren nach (position/tag)"),
+    ("prompt.report_format", "Ausgabeformat eingeben (table/csv/json)"),
+    ("prompt.report_path", "Dateipfad zum Speichern des Berichts eingeben oder leer lassen, um ihn anzuzeigen"),
+    ("prompt.tax_year", "Jahr der Veräußerungen eingeben oder leer lassen, um alle Jahre einzubeziehen"),
+    ("prompt.market_price", "Marktpreis eingeben"),
+    ("prompt.target_weights", "Zielgewichte in Prozent wie 'btc=40 eth=30 tag:stable=30' eingeben oder leer lassen, um sie zu entfernen"),
+    ("prompt.sorting_numbers", "\nNummer der gewünschten Sortierung wählen.\nMehrere Nummern mit Kommas trennen, um Gleichstände nach weiteren Methoden zu sortieren, z. B. '12,4':"),
+    ("prompt.order_type_buy_sell", "Ordertyp eingeben (buy/sell)"),
+    ("prompt.order_amount_or_close", "Menge der Position eingeben.\n0 eingeben, wenn die Position vollständig geschlossen wird."),
+    ("prompt.income_type", "Art des Ertrags eingeben (dividend/interest/reward)"),
+    ("prompt.income_cash", "Erhaltenen Barbetrag eingeben.\n0 eingeben, wenn der Ertrag in Einheiten des Vermögenswerts gezahlt wird."),
+    ("prompt.income_units", "Erhaltene Menge an Einheiten eingeben"),
+    ("prompt.income_event_id", "ID des Ertrags eingeben"),
+    ("prompt.corporate_action_type", "Art der Kapitalmaßnahme eingeben (split/reverse/rename)"),
+    ("prompt.reverse_split_ratio", "Eingeben, wie viele Einheiten zu einer zusammengelegt werden, z. B. 10 für einen Reverse Split 1:10"),
+    ("prompt.split_ratio", "Eingeben, in wie viele Einheiten jede Einheit geteilt wird, z. B. 10 für einen Split 10:1"),
+    ("prompt.new_position_name", "Neuen Namen der Position eingeben"),
+    ("prompt.corporate_action_id", "ID der Kapitalmaßnahme eingeben"),
+    ("prompt.tags", "Kommagetrennte Tags eingeben oder leer lassen, um sie zu entfernen"),
+    ("prompt.simulation", "Simulation eingeben: Order (o), Verkauf von allem zum Preis (a) oder Preis für Ertragsziel (t)"),
+    ("prompt.order_amount", "Ordermenge eingeben"),
+    ("prompt.order_price", "Orderpreis eingeben"),
+    ("prompt.close_price", "Preis zum Schließen der Position eingeben"),
+    ("prompt.target_income_percent", "Ziel-Ertrag in Prozent eingeben"),
+    ("prompt.new_order_price", "Preis der neuen Order eingeben"),
+    ("prompt.desired_avg_price", "Gewünschten Durchschnittspreis eingeben"),
+    ("prompt.stop_loss", "Stop-Loss-Preis eingeben oder leer lassen, um ihn zu entfernen"),
+    ("prompt.take_profit", "Take-Profit-Preis eingeben oder leer lassen, um ihn zu entfernen"),
+    ("prompt.tax_report_path", "\nDateipfad für den CSV-Export des Berichts eingeben oder leer lassen, um zurückzukehren"),
+    ("prompt.cash_entry_id", "\nID des Eintrags zum Löschen eingeben oder leer lassen, um zurückzukehren"),
+
+    ("confirm.delete_position", "Position {} wirklich löschen? (y,N)"),
+    ("confirm.delete_portfolio", "Portfolio '{}' mit {} Positionen wirklich löschen? (y,N)"),
+    ("confirm.delete_cash_entry", "Kassenbuchung {} wirklich löschen? (y,N)"),
+    ("confirm.delete_income_event", "Ertrag {} wirklich löschen? (y,N)"),
+    ("confirm.delete_corporate_action", "Kapitalmaßnahme {} wirklich löschen? (y,N)"),
+    ("confirm.delete_order", "Order {} wirklich löschen? (y,N)"),
+    ("confirm.cash_shortage", "Kassenstand ist {}, eine Ausgabe von {} macht ihn negativ. Trotzdem fortfahren? (y,N)\nMit dem Befehl 'margin' wird ein negativer Kassenstand erlaubt"),
+    ("confirm.place_rebalancing_orders", "\nUmschichtungsorders platzieren? (y,N)"),
+    ("confirm.reinitialize_storage", "Vielleicht hat sich die Version der App geändert. Sie können den Speicher neu initialisieren (alle Daten gehen verloren) oder \ndie Daten in der Datei selbst korrigieren\ny - Speicher neu initialisieren\nn - Ohne Änderungen beenden\n(Standard: n)"),
+
+    ("info.current_sorting", "Aktuelle Sortierung: {}"),
+    ("info.available_sorting_methods", "Verfügbare Sortierungen: "),
+    ("info.current_filter", "Aktueller Filter: {}"),
+    ("info.report_saved", "Bericht ist in '{}' gespeichert"),
+    ("info.current_target_weights", "Aktuelle Zielgewichte: {}"),
+    ("info.move_closed_to_bottom", "Geschlossene Positionen nach unten verschieben ({})"),
+    ("info.enabled", "aktiviert"),
+    ("info.disabled", "deaktiviert"),
+    ("info.exit", "Beenden"),
+    ("info.press_enter", "Eingabetaste drücken, um fortzufahren..."),
+    ("info.warning_prefix", "WARNUNG: "),
+    ("info.market_price_coverage", "Marktpreis ist für {} von {} offenen Positionen gesetzt, mit dem Befehl 'mp' setzen"),
+    ("info.not_enough_data", "Nicht genug Daten für ein Diagramm"),
+    ("info.page", "Seite "),
+    ("info.error_prefix", "FEHLER: "),
+    ("info.order_description", "{} {} für {}"),
+    ("info.margin_allowed", "Marge ist erlaubt"),
+    ("info.margin_not_allowed", "Marge ist nicht erlaubt"),
+    ("info.no_realized_income", "Noch kein realisierter Ertrag"),
+    ("info.nothing_saved", "Nichts wird gespeichert"),
+    ("info.level_triggered", "{} ist ausgelöst"),
+    ("info.averaging_result", "{} mehr für {} eröffnen, neuer Durchschnittspreis ist {}"),
+    ("info.simulated_buy", "Simulierter Kauf {} {} zu {}"),
+    ("info.simulated_sell", "Simulierter Verkauf {} {} zu {}"),
+
+    ("error.invalid_position", "Ungültige Position: {}"),
+    ("error.already_at_last_page", "Bereits auf der letzten Seite"),
+    ("error.already_at_first_page", "Bereits auf der ersten Seite"),
+    ("error.position_not_found", "Position mit ID {} nicht gefunden"),
+    ("error.position_not_found_quoted", "Position mit ID '{}' nicht gefunden"),
+    ("error.no_sorting_method", "Keine Sortierung gewählt"),
+    ("error.empty_portfolio_name", "Name des Portfolios darf nicht leer sein"),
+    ("error.portfolio_not_found_by_name", "Portfolio '{}' nicht gefunden"),
+    ("error.portfolio_not_found", "Portfolio mit ID {} nicht gefunden"),
+    ("error.delete_only_portfolio", "Das einzige Portfolio kann nicht gelöscht werden"),
+    ("error.invalid_value", "Ungültiger Wert: {}"),
+    ("error.cash_entry_not_found", "Kassenbuchung '{}' nicht gefunden"),
+    ("error.unknown_report_format", "Unbekanntes Berichtsformat '{}'"),
+    ("error.write_report", "Bericht konnte nicht in '{}' geschrieben werden: {}"),
+    ("error.parse_year", "Jahr '{}' konnte nicht gelesen werden"),
+    ("error.no_active_positions_named", "Keine aktiven Positionen mit dem Namen '{}'"),
+    ("error.target_weights_not_set", "Zielgewichte sind nicht festgelegt, mit dem Befehl 'tw' festlegen"),
+    ("error.no_portfolio_selected", "Kein Portfolio ausgewählt, mit dem Befehl 'ps' zu einem Portfolio wechseln"),
+    ("error.invalid_order", "Ungültige Order: {}"),
+    ("error.over_close", "Ungültige Order: {}. Option 'over_close_behavior' auf 'flip' setzen, um mit dem Rest der Order eine Gegenposition zu eröffnen"),
+    ("error.order_not_found", "Order mit ID {} in Position {} nicht gefunden"),
+    ("error.income_units_short", "Ertrag in Einheiten kann nicht zu einer Short-Position hinzugefügt werden"),
+    ("error.income_event_not_found", "Ertrag mit ID {} in Position {} nicht gefunden"),
+    ("error.invalid_split_ratio", "Split-Verhältnis muss eine positive Zahl sein"),
+    ("error.empty_position_name", "Name der Position darf nicht leer sein"),
+    ("error.corporate_action_not_found", "Kapitalmaßnahme mit ID {} in Position {} nicht gefunden"),
+    ("error.position_closed", "Position ist bereits geschlossen"),
+    ("error.unreachable_income", "Ertrag von {}% ist mit einem positiven Preis nicht erreichbar"),
+    ("error.unknown_simulation", "Unbekannte Simulation '{}', erwartet Order (o), alles (a) oder Ziel (t)"),
+    ("error.unreachable_avg_price", "Durchschnittspreis {} ist nicht erreichbar, er muss zwischen dem aktuellen Durchschnittspreis {} und dem Orderpreis {} liegen"),
+    ("error.draw_position", "Daten der Position konnten nicht gezeichnet werden"),
+    ("error.nested_macro", "Makro kann kein anderes Makro '{}' ausführen"),
+    ("error.macro_stopped", "Makro '{}' wurde bei '{}' angehalten: {}"),
+    ("error.unknown_command", "Unbekannter Befehl '{}'"),
+    ("error.parse_answer", "Antwort '{}' konnte nicht gelesen werden"),
+    ("error.invalid_value_with_message", "Ungültiger Wert: {}"),
+    ("error.read_input", "Eingabe konnte nicht von der Konsole gelesen werden"),
+    ("error.input_cancelled", "Eingabe abgebrochen"),
+    ("error.parse_answer_retry", "Antwort '{}' konnte nicht gelesen werden, bitte erneut versuchen"),
+    ("error.invalid_value_retry", "Ungültiger Wert: {}, bitte erneut versuchen"),
+    ("error.unsupported_confirmation", "Nicht unterstützte Bestätigung {}"),
+    ("error.macro_arguments", "Makro '{}' akzeptiert keine Argumente"),
+    ("error.empty_alias", "Alias '{}' hat einen leeren Befehl"),
+    ("error.parse_alias", "Alias '{}' konnte nicht gelesen werden: {}"),
+    ("error.remove_order_over_close", "Order {} kann nicht gelöscht werden, da spätere Orders mehr schließen würden, als die Position hält: {}"),
+    ("error.remove_income_event_over_close", "Ertrag {} kann nicht gelöscht werden, da spätere Orders mehr schließen würden, als die Position hält: {}"),
+    ("error.remove_corporate_action_over_close", "Kapitalmaßnahme {} kann nicht gelöscht werden, da spätere Orders mehr schließen würden, als die Position hält: {}"),
+    ("error.rebalancing_order_failed", "Order für {} wurde nicht platziert: {}"),
+    ("error.not_finite", "{} sollte eine endliche Zahl sein"),
+    ("error.not_positive", "{} sollte positiv sein, erhalten {}"),
+    ("error.empty_name", "Name darf nicht leer sein"),
+    ("error.exceeds_amount", "Menge {} übersteigt die Positionsmenge {}"),
+    ("error.invalid_income_type", "'{}' ist keine gültige Ertragsart (dividend/interest/reward)"),
+    ("error.invalid_position_type", "'{}' ist kein gültiger Positionstyp (long/short)"),
+    ("error.target_weight_format", "Zielgewichtung '{}' sollte wie 'btc=40' aussehen"),
+    ("error.target_weight_twice", "Zielgewichtung für '{}' ist doppelt gesetzt"),
+    ("error.parse_weight", "Gewichtung '{}' konnte nicht gelesen werden"),
+    ("error.invalid_weight", "Ungültige {} von '{}'"),
+    ("error.target_weights_over_100", "Zielgewichtungen ergeben zusammen {}%, das ist über 100%"),
+    ("error.remove_first_order", "Die erste Order kann nicht gelöscht werden"),
+    ("error.add_history", "Befehl konnte nicht zum Verlauf hinzugefügt werden: {}"),
+    ("error.save_history", "Befehlsverlauf konnte nicht gespeichert werden: {}"),
+    ("error.read_input_with_reason", "Eingabe konnte nicht von der Konsole gelesen werden: {}"),
+    ("error.missing_argument_value", "Fehlender Wert des Arguments '--{}'"),
+    ("error.nothing_to_escape", "Am Ende des Befehls gibt es nichts zu maskieren"),
+    ("error.unclosed_quote", "Nicht geschlossenes Anführungszeichen {} im Befehl"),
+    ("error.draw_ui", "Oberfläche konnte nicht gezeichnet werden: {}"),
+    ("error.read_terminal_event", "Terminal-Ereignis konnte nicht gelesen werden: {}"),
+    ("error.empty_position_orders", "Orders der Position {} konnten nicht gelesen werden, vielleicht ist sie leer"),
+    ("error.invalid_stored_split_ratio", "Kapitalmaßnahme {} hat ungültiges Split-Verhältnis {}"),
+    ("error.write_initial_storage", "Anfängliche Speicherdatei konnte nicht geschrieben werden"),
+    ("error.read_storage", "Speicherdatei konnte nicht gelesen werden"),
+    ("error.deserialize_storage", "Speicherdaten konnten nicht deserialisiert werden"),
+    ("error.serialize_storage", "Positionen konnten nicht in JSON serialisiert werden"),
+    ("error.write_storage", "Positionen konnten nicht in der Speicherdatei gespeichert werden"),
+    ("error.write_initial_options", "Anfängliche Optionsdatei konnte nicht geschrieben werden"),
+    ("error.read_options", "Optionsdatei konnte nicht gelesen werden"),
+    ("error.deserialize_options", "Optionen konnten nicht deserialisiert werden"),
+    ("error.parse_direction", "Richtung '{}' konnte nicht gelesen werden"),
+    ("error.parse_sorting_method", "Sortiermethode '{}' konnte nicht gelesen werden"),
+    ("error.unknown_filter_field", "Unbekanntes Filterfeld '{}'"),
+    ("error.filter_no_operator", "Filterbedingung '{}' hat keinen Operator"),
+    ("error.filter_no_value", "Filterbedingung '{}' hat keinen Wert"),
+    ("error.operator_with_tags", "Operator '{}' kann nicht mit Tags verwendet werden"),
+    ("error.operator_with_name", "Operator '{}' kann nicht mit dem Namen verwendet werden"),
+    ("error.operator_with_status", "Operator '{}' kann nicht mit dem Status verwendet werden"),
+    ("error.unknown_position_status", "Unbekannter Positionsstatus '{}'"),
+    ("error.operator_with_dates", "Operator '{}' kann nicht mit Datumsangaben verwendet werden"),
+    ("error.parse_filter_date", "Datum '{}' konnte nicht gelesen werden, erwartetes Format ist JJJJ-MM-TT"),
+    ("error.operator_with_numbers", "Operator '{}' kann nicht mit Zahlen verwendet werden"),
+    ("error.parse_number", "Zahl '{}' konnte nicht gelesen werden"),
+    ("error.empty_filter", "Filterausdruck ist leer"),
+    ("error.unknown_report_period", "Unbekannter Berichtszeitraum '{}'"),
+    ("error.unknown_report_grouping", "Unbekannte Berichtsgruppierung '{}'"),
+    ("error.invalid_corporate_action_type", "'{}' ist keine gültige Kapitalmaßnahme (split/reverse/rename)"),
+    ("error.parse_field", "{} '{}' konnte nicht gelesen werden"),
+    ("error.sorting_directions_count", "{} Richtungen erwartet, {} erhalten"),
+
+    ("warning.invalid_saved_filter", "Gespeicherter Filter '{}' wird ignoriert: {}. Mit dem Befehl 'f' ändern"),
+    ("warning.invalid_order", "Position {} ({}), Order {}: {}"),
+    ("warning.invalid_orders", "Die Speicherdatei enthält ungültige Orders, bearbeiten oder löschen Sie sie: {}"),
+
+    ("label.dashboard", "Dashboard:"),
+    ("label.invested_capital", "Investiertes Kapital"),
+    ("label.realized_income", "Realisierter Ertrag"),
+    ("label.realized_income_percent", "Realisierter Ertrag %"),
+    ("label.open_closed_positions", "Offene / geschlossene Positionen"),
+    ("label.best_position", "Beste Position"),
+    ("label.worst_position", "Schlechteste Position"),
+    ("label.win_rate", "Gewinnquote"),
+    ("label.avg_holding_time", "Durchschnittliche Haltedauer"),
+    ("label.days_postfix", " Tage"),
+    ("label.largest_position", "Größte Position"),
+    ("label.unrealized_income", "Unrealisierter Ertrag"),
+    ("label.equity", "Eigenkapital"),
+    ("label.dividend", "Dividende"),
+    ("label.interest", "Zinsen"),
+    ("label.reward", "Belohnung"),
+    ("label.stop_loss", "Stop-Loss"),
+    ("label.take_profit", "Take-Profit"),
+    ("label.split", "Split {}:1"),
+    ("label.reverse_split", "Reverse Split 1:{}"),
+    ("label.rename", "Umbenennung {} -> {}"),
+    ("label.buy", "Kauf"),
+    ("label.sell", "Verkauf"),
+    ("label.portfolio_prefix", "Portfolio: "),
+    ("label.filter_prefix", "Filter: "),
+    ("label.last_edited_at_prefix", "Zuletzt bearbeitet am "),
+    ("label.tags_prefix", "Tags: "),
+    ("label.total", "Gesamt"),
+    ("label.margin", "Marge"),
+    ("label.cash", "Bargeld"),
+    ("label.twr", "TWR"),
+    ("label.all_accounts", "Alle Konten"),
+    ("label.id", "Id"),
+    ("label.name", "Name"),
+    ("label.positions", "Positionen"),
+    ("label.active", "Aktiv"),
+    ("label.closed", "Geschlossen"),
+    ("label.status", "Status"),
+    ("label.avg_value", "Ø Wert"),
+    ("label.avg_price", "Ø Preis"),
+    ("label.income", "Ertrag"),
+    ("label.payouts", "Auszahlungen"),
+    ("label.yearly_percent", "Jährlich %"),
+    ("label.xirr_percent", "XIRR %"),
+    ("label.break_even", "Break-even"),
+    ("label.target_price", "Ziel +{}%"),
+    ("label.date", "Datum"),
+    ("label.type", "Typ"),
+    ("label.amount", "Menge"),
+    ("label.value", "Wert"),
+    ("label.price", "Preis"),
+    ("label.adjusted_amount", "Ber. Menge"),
+    ("label.adjusted_price", "Ber. Preis"),
+    ("label.cash_ledger_title", "Bargeldbuch von Portfolio {}:"),
+    ("label.deposit", "Einzahlung"),
+    ("label.withdrawal", "Auszahlung"),
+    ("label.deposited", "Eingezahlt"),
+    ("label.position_title", "Position {}"),
+    ("label.long", "Long"),
+    ("label.short", "Short"),
+    ("label.position_orders", "Orders von Position {}:"),
+    ("label.position_income_events", "Ertragsereignisse von Position {}:"),
+    ("label.position_corporate_actions", "Kapitalmaßnahmen von Position {}:"),
+    ("label.action", "Maßnahme"),
+    ("label.after_order", "Nach Order"),
+    ("label.units", "Einheiten"),
+    ("label.period", "Zeitraum"),
+    ("label.cumulative", "Kumuliert"),
+    ("label.disposals_in", "Veräußerungen in {}"),
+    ("label.all_disposals", "Alle Veräußerungen"),
+    ("label.position", "Position"),
+    ("label.acquired", "Erworben"),
+    ("label.disposed", "Veräußert"),
+    ("label.proceeds", "Erlös"),
+    ("label.cost_basis", "Anschaffungskosten"),
+    ("label.gain", "Gewinn"),
+    ("label.term", "Frist"),
+    ("label.short_term", "Kurzfristig"),
+    ("label.long_term", "Langfristig"),
+    ("label.break_even_prefix", "Break-even: "),
+    ("label.target_prefix", "Ziel +{}%: "),
+    ("label.stop_loss_prefix", "Stop-Loss: "),
+    ("label.take_profit_prefix", "Take-Profit: "),
+    ("label.market_price_prefix", "Marktpreis: "),
+    ("label.triggered_alerts", "Ausgelöste Preisalarme:"),
+    ("label.portfolio", "Portfolio"),
+    ("label.market_price", "Marktpreis"),
+    ("label.alert", "Alarm"),
+    ("label.target", "Ziel"),
+    ("label.weight", "Gewicht"),
+    ("label.target_percent", "Ziel %"),
+    ("label.drift", "Abweichung"),
+    ("label.rebalance", "Umschichtung"),
+    ("label.no_positions", "Keine Positionen"),
+    ("label.other", "Sonstige"),
+    ("label.buy_value", "Kauf {}"),
+    ("label.sell_value", "Verkauf {}"),
+    ("label.rebalancing_orders", "Umschichtungsorders:"),
+    ("label.prices_of", "Preise von {}"),
+    ("label.position_chart_legend", "B - Kauforder, S - Verkaufsorder, - - Ø Preis"),
+    ("label.cumulative_realized_income", "Kumulierter realisierter Ertrag:"),
+    ("label.allocation_by_avg_value", "Aufteilung nach Ø Wert:"),
+    ("label.before", "Vorher"),
+    ("label.after", "Nachher"),
+    ("label.order_income_prefix", "Ertrag der Order: "),
+
+    ("field.amount", "Menge"),
+    ("field.value", "Wert"),
+    ("field.price", "Preis"),
+    ("field.avg_price", "Durchschnittspreis"),
+    ("field.weight", "Gewichtung"),
+    ("field.cash", "Bargeld"),
+    ("field.units", "Einheiten"),
+
+    ("sorting.asc", "Aufst."),
+    ("sorting.desc", "Abst."),
+    ("sorting.then_separator", ", dann "),
+    ("sorting.by_id", "Nach ID"),
+    ("sorting.by_avg_value", "Nach durchschnittlichem Wert"),
+    ("sorting.by_avg_price", "Nach Durchschnittspreis"),
+    ("sorting.by_income", "Nach Ertrag"),
+    ("sorting.by_last_change", "Nach letzter Änderung"),
+    ("sorting.by_name", "Nach Name (alphabetisch)"),
+    ("sorting.by_natural_name", "Nach Name (natürlich, 'BTC2' vor 'BTC10')"),
+    ("sorting.by_amount", "Nach Menge"),
+    ("sorting.by_income_percent", "Nach Ertrag in Prozent"),
+    ("sorting.by_orders_count", "Nach Anzahl der Orders"),
+    ("sorting.by_created_at", "Nach Erstellungsdatum"),
+    ("sorting.by_status", "Nach Status (aktiv/geschlossen)"),
+    ("sorting.by_annualized_return", "Nach annualisierter Rendite"),
+    ("sorting.by_xirr", "Nach XIRR"),
+    ("sorting.id", "ID"),
+    ("sorting.avg_value", "Durchschnittlicher Wert"),
+    ("sorting.last_change", "Letzte Änderung"),
+    ("sorting.avg_price", "Durchschnittspreis"),
+    ("sorting.income", "Ertrag"),
+    ("sorting.name", "Name"),
+    ("sorting.natural_name", "Name natürlich"),
+    ("sorting.amount", "Menge"),
+    ("sorting.income_percent", "Ertrag %"),
+    ("sorting.orders_count", "Anzahl der Orders"),
+    ("sorting.created_at", "Erstellungsdatum"),
+    ("sorting.status", "Status"),
+    ("sorting.annualized_return", "Annualisierte Rendite"),
+    ("sorting.xirr", "XIRR"),
+
+    ("tui.global_keys", "↑/↓ auswählen  Enter bearbeiten  a hinzufügen  d löschen  s sortieren  c geschlossene nach unten  ? Hilfe  q beenden"),
+    ("tui.position_keys", "↑/↓ Order auswählen  a Order hinzufügen  d Order löschen  ? Hilfe  Esc zurück"),
+    ("tui.form_keys", "Tab nächstes Feld  Enter absenden  Esc abbrechen"),
+    ("tui.confirm_keys", "y bestätigen  n abbrechen"),
+    ("tui.no_portfolio_selected", "Kein Portfolio ausgewählt"),
+    ("tui.sorting_prefix", "Sortierung: "),
+    ("tui.orders", "Orders"),
+    ("tui.order", "Order"),
+    ("tui.no_order_selected", "Keine Order ausgewählt"),
+    ("tui.order_title", "Order {} - {}"),
+    ("tui.confirm", "Bestätigen"),
+    ("tui.help", "Hilfe"),
+    ("tui.select_position", "Position auswählen"),
+    ("tui.edit_position_orders", "Orders der Position bearbeiten"),
+    ("tui.add_position", "Position hinzufügen"),
+    ("tui.delete_position", "Position löschen"),
+    ("tui.change_sorting", "Sortierung ändern"),
+    ("tui.move_closed_to_bottom", "geschlossene Positionen nach unten"),
+    ("tui.quit", "beenden"),
+    ("tui.select_order", "Order auswählen"),
+    ("tui.add_order", "Order hinzufügen"),
+    ("tui.delete_order", "Order löschen"),
+    ("tui.back_to_positions", "zurück zu Positionen"),
+    ("tui.repl_commands", "Weitere Befehle sind in der REPL verfügbar, starten mit --repl"),
+    ("tui.press_any_key", "Beliebige Taste zum Schließen drücken"),
+    ("tui.sorting_changed", "Sortierung geändert"),
+    ("tui.confirm_delete_position", "Position {} ({}) wirklich löschen?"),
+    ("tui.confirm_delete_order", "Order {} wirklich löschen?"),
+    ("tui.position_deleted", "Position {} gelöscht"),
+    ("tui.position_added", "Position hinzugefügt"),
+    ("tui.order_deleted", "Order {} gelöscht"),
+    ("tui.order_added", "Order hinzugefügt"),
+    ("tui.add_position_title", "Position hinzufügen"),
+    ("tui.add_order_title", "Order hinzufügen"),
+    ("tui.change_sorting_title", "Sortierung ändern"),
+    ("tui.position_type_hint", "long/short"),
+    ("tui.order_type_hint", "buy/sell"),
+    ("tui.order_amount_hint", "0, um die Position vollständig zu schließen"),
+    ("tui.methods", "Methoden"),
+    ("tui.methods_hint", "Nummern aus der Liste, z. B. 12,4"),
+    ("tui.directions", "Richtungen"),
+    ("tui.directions_hint", "asc/desc für jede Methode, z. B. desc,asc"),
+];
@@ -0,0 +1,420 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("help.tooltip", "Type 'h' for help"),
+    ("help.available_commands", "Available commands:"),
+    ("help.show_help", "Show help page"),
+    ("help.exit", "Exit application"),
+    ("help.add_position", "Add new position"),
+    ("help.edit_position", "Edit position"),
+    ("help.delete_position", "Delete position"),
+    ("help.change_sorting", "Change sorting"),
+    ("help.filter_positions", "Filter positions (fields: id, name, status, amount, value, price, income, percent, edited)"),
+    ("help.next_page", "Show next page"),
+    ("help.previous_page", "Show previous page"),
+    ("help.portfolios", "Portfolios:"),
+    ("help.add_portfolio", "Add new portfolio"),
+    ("help.switch_portfolio", "Switch portfolio, 'all' shows all accounts"),
+    ("help.rename_portfolio", "Rename portfolio"),
+    ("help.delete_portfolio", "Delete portfolio"),
+    ("help.cash", "Cash:"),
+    ("help.deposit", "Deposit cash"),
+    ("help.withdraw", "Withdraw cash"),
+    ("help.cash_ledger", "Show cash ledger"),
+    ("help.set_market_prices", "Set market price of active positions with the name"),
+    ("help.allocation", "Allocation:"),
+    ("help.target_weights", "Set target weights of names and tags"),
+    ("help.show_allocation", "Show allocation and rebalance positions to target weights"),
+    ("help.toggle_margin", "Allow or forbid negative cash balance"),
+    ("help.reports", "Reports:"),
+    ("help.income_report", "Show realized income by week, month, quarter or year"),
+    ("help.tax_report", "Show realized disposals for tax filing and export them to CSV"),
+    ("help.portfolio_charts", "Draw cumulative realized income and allocation charts"),
+    ("help.dashboard", "Show dashboard with invested capital, income, win rate and equity"),
+    ("help.return_to_positions", "Return to positions"),
+    ("help.add_order", "Add new order"),
+    ("help.delete_order", "Delete order"),
+    ("help.add_income_event", "Add income event (dividend, interest or reward)"),
+    ("help.delete_income_event", "Delete income event"),
+    ("help.add_corporate_action", "Add corporate action (split, reverse split or rename)"),
+    ("help.delete_corporate_action", "Delete corporate action"),
+    ("help.simulate", "Simulate order, closing position at price or reaching income target"),
+    ("help.price_levels", "Set stop loss and take profit levels"),
+    ("help.set_market_price", "Set market price"),
+    ("help.averaging", "Calculate amount to add, which moves avg price to the desired one"),
+    ("help.position_chart", "Draw order prices and avg price over time"),
+    ("help.set_tags", "Set comma separated tags, e.g. 'crypto,long-term'"),
+    ("help.aliases", "Aliases:"),
+    ("help.macros", "Macros:"),
+    ("help.arguments", "Arguments can be passed by position or by name, e.g. 'a BTC long 0.5 15000' or 'a BTC --value 15000'.\nUse quotes for values with spaces, e.g. 'a \"Apple Inc\" long 2 300'. Missing values are asked."),
+
+    ("prompt.position_name", "Enter position name"),
+    ("prompt.order_type", "Enter order type (long/short)"),
+    ("prompt.position_amount", "Enter position amount"),
+    ("prompt.position_value", "Enter position value"),
+    ("prompt.position_id", "Enter position id"),
+    ("prompt.order_id", "Enter order id"),
+    ("prompt.sorting_direction", "Choose direction for sorting {} (asc, desc): "),
+    ("prompt.filter_expression", "Enter filter expression, e.g. 'name~BTC status=active income<0 edited>2026-01-01'\nLeave empty to reset filter"),
+    ("prompt.portfolio_name", "Enter portfolio name"),
+    ("prompt.portfolio_id_or_all", "Enter portfolio id or 'all' to see all accounts"),
+    ("prompt.portfolio_id", "Enter portfolio id"),
+    ("prompt.new_portfolio_name", "Enter new portfolio name"),
+    ("prompt.deposit_amount", "Enter deposit amount"),
+    ("prompt.withdrawal_amount", "Enter withdrawal amount"),
+    ("prompt.report_period", "Enter report period (week/month/quarter/year)"),
+    ("prompt.report_grouping", "Group income by (position/tag)"),
+    ("prompt.report_format", "Enter output format (table/csv/json)"),
+    ("prompt.report_path", "Enter file path to save report or leave empty to print it"),
+    ("prompt.tax_year", "Enter year of disposals or leave empty to include all years"),
+    ("prompt.market_price", "Enter market price"),
+    ("prompt.target_weights", "Enter target weights in percents like 'btc=40 eth=30 tag:stable=30' or leave empty to remove them"),
+    ("prompt.sorting_numbers", "\nChoose the number of preferred sorting.\nSeparate several numbers with commas to sort ties by next methods, e.g. '12,4':"),
+    ("prompt.order_type_buy_sell", "Enter order type (buy/sell)"),
+    ("prompt.order_amount_or_close", "Enter position amount.\nEnter 0 if you're fully closing position."),
+    ("prompt.income_type", "Enter income type (dividend/interest/reward)"),
+    ("prompt.income_cash", "Enter received cash amount.\nEnter 0 if income is paid in units of the asset."),
+    ("prompt.income_units", "Enter received units amount"),
+    ("prompt.income_event_id", "Enter income event id"),
+    ("prompt.corporate_action_type", "Enter corporate action type (split/reverse/rename)"),
+    ("prompt.reverse_split_ratio", "Enter how many units are merged into one, e.g. 10 for 1:10 reverse split"),
+    ("prompt.split_ratio", "Enter how many units each unit is split into, e.g. 10 for 10:1 split"),
+    ("prompt.new_position_name", "Enter new position name"),
+    ("prompt.corporate_action_id", "Enter corporate action id"),
+    ("prompt.tags", "Enter comma separated tags or leave empty to remove them"),
+    ("prompt.simulation", "Enter what to simulate: order (o), selling all at price (a) or price for income target (t)"),
+    ("prompt.order_amount", "Enter order amount"),
+    ("prompt.order_price", "Enter order price"),
+    ("prompt.close_price", "Enter price to close position at"),
+    ("prompt.target_income_percent", "Enter target income percent"),
+    ("prompt.new_order_price", "Enter price of the new order"),
+    ("prompt.desired_avg_price", "Enter desired avg price"),
+    ("prompt.stop_loss", "Enter stop loss price or leave empty to remove it"),
+    ("prompt.take_profit", "Enter take profit price or leave empty to remove it"),
+    ("prompt.tax_report_path", "\nEnter file path to export report to CSV or leave empty to return"),
+    ("prompt.cash_entry_id", "\nEnter entry id to delete it or leave empty to return"),
+
+    ("confirm.delete_position", "Are you sure want to delete position {}? (y,N)"),
+    ("confirm.delete_portfolio", "Are you sure want to delete portfolio '{}' with {} positions? (y,N)"),
+    ("confirm.delete_cash_entry", "Are you sure want to delete cash entry {}? (y,N)"),
+    ("confirm.delete_income_event", "Are you sure want to delete income event {}? (y,N)"),
+    ("confirm.delete_corporate_action", "Are you sure want to delete corporate action {}? (y,N)"),
+    ("confirm.delete_order", "Are you sure want to delete order {}? (y,N)"),
+    ("confirm.cash_shortage", "Cash balance is {}, spending {} will make it negative. Continue anyway? (y,N)\nUse 'margin' command to allow negative balance"),
+    ("confirm.place_rebalancing_orders", "\nPlace rebalancing orders? (y,N)"),
+    ("confirm.reinitialize_storage", "Perhaps, the version of app has changed. You can reinitialize storage (all data will be lost) or \ntry to fix data in file yourself\ny - Reinitialize storage\nn - Exit without changes\n(Default: n)"),
+
+    ("info.current_sorting", "Current sorting method: {}"),
+    ("info.available_sorting_methods", "Available sorting methods: "),
+    ("info.current_filter", "Current filter: {}"),
+    ("info.report_saved", "Report is saved to '{}'"),
+    ("info.current_target_weights", "Current target weights: {}"),
+    ("info.move_closed_to_bottom", "Move closed positions to bottom ({})"),
+    ("info.enabled", "enabled"),
+    ("info.disabled", "disabled"),
+    ("info.exit", "Exit"),
+    ("info.press_enter", "Press enter to continue..."),
+    ("info.warning_prefix", "WARNING: "),
+    ("info.market_price_coverage", "Market price is set for {} of {} open positions, use 'mp' command to set it"),
+    ("info.not_enough_data", "Not enough data to draw chart"),
+    ("info.page", "Page "),
+    ("info.error_prefix", "ERROR: "),
+    ("info.order_description", "{} {} for {}"),
+    ("info.margin_allowed", "Margin is allowed"),
+    ("info.margin_not_allowed", "Margin is not allowed"),
+    ("info.no_realized_income", "No realized income yet"),
+    ("info.nothing_saved", "Nothing is saved"),
+    ("info.level_triggered", "{} is triggered"),
+    ("info.averaging_result", "Open {} more for {}, new avg price is {}"),
+    ("info.simulated_buy", "Simulated buy {} {} at {}"),
+    ("info.simulated_sell", "Simulated sell {} {} at {}"),
+
+    ("error.invalid_position", "Invalid position: {}"),
+    ("error.already_at_last_page", "Already at last page"),
+    ("error.already_at_first_page", "Already at first page"),
+    ("error.position_not_found", "Position with id {} not found"),
+    ("error.position_not_found_quoted", "Position with id '{}' not found"),
+    ("error.no_sorting_method", "No sorting method chosen"),
+    ("error.empty_portfolio_name", "Portfolio name cannot be empty"),
+    ("error.portfolio_not_found_by_name", "Portfolio '{}' not found"),
+    ("error.portfolio_not_found", "Portfolio with id {} not found"),
+    ("error.delete_only_portfolio", "Cannot delete the only portfolio"),
+    ("error.invalid_value", "Invalid {}"),
+    ("error.cash_entry_not_found", "Cash entry '{}' not found"),
+    ("error.unknown_report_format", "Unknown report format '{}'"),
+    ("error.write_report", "Failed to write report to '{}': {}"),
+    ("error.parse_year", "Failed to parse year '{}'"),
+    ("error.no_active_positions_named", "No active positions named '{}'"),
+    ("error.target_weights_not_set", "Target weights are not set, use 'tw' command to set them"),
+    ("error.no_portfolio_selected", "No portfolio selected, switch to a portfolio with 'ps' command"),
+    ("error.invalid_order", "Invalid order: {}"),
+    ("error.over_close", "Invalid order: {}. Set 'over_close_behavior' option to 'flip' to open opposite position with the rest of the order"),
+    ("error.order_not_found", "Cannot find order with id {} in position {}"),
+    ("error.income_units_short", "Income in units cannot be added to short position"),
+    ("error.income_event_not_found", "Cannot find income event with id {} in position {}"),
+    ("error.invalid_split_ratio", "Split ratio should be a positive number"),
+    ("error.empty_position_name", "Position name cannot be empty"),
+    ("error.corporate_action_not_found", "Cannot find corporate action with id {} in position {}"),
+    ("error.position_closed", "Position is already closed"),
+    ("error.unreachable_income", "Income of {}% cannot be reached with a positive price"),
+    ("error.unknown_simulation", "Unknown simulation '{}', expected order (o), all (a) or target (t)"),
+    ("error.unreachable_avg_price", "Avg price {} cannot be reached, it should be between current avg price {} and order price {}"),
+    ("error.draw_position", "Failed to draw position data"),
+    ("error.nested_macro", "Macro cannot run other macro '{}'"),
+    ("error.macro_stopped", "Macro '{}' stopped at '{}': {}"),
+    ("error.unknown_command", "Unknown command '{}'"),
+    ("error.parse_answer", "Failed to parse answer '{}'"),
+    ("error.invalid_value_with_message", "Invalid value: {}"),
+    ("error.read_input", "Failed to read input from console"),
+    ("error.input_cancelled", "Input cancelled"),
+    ("error.parse_answer_retry", "Failed to parse answer '{}', try again"),
+    ("error.invalid_value_retry", "Invalid value: {}, try again"),
+    ("error.unsupported_confirmation", "Unsupported confirmation answer {}"),
+    ("error.macro_arguments", "Macro '{}' doesn't accept arguments"),
+    ("error.empty_alias", "Alias '{}' has empty command"),
+    ("error.parse_alias", "Failed to parse alias '{}': {}"),
+    ("error.remove_order_over_close", "Cannot remove order {}, as later orders would close more than position holds: {}"),
+    ("error.remove_income_event_over_close", "Cannot remove income event {}, as later orders would close more than position holds: {}"),
+    ("error.remove_corporate_action_over_close", "Cannot remove corporate action {}, as later orders would close more than position holds: {}"),
+    ("error.rebalancing_order_failed", "Order of {} is not placed: {}"),
+    ("error.not_finite", "{} should be a finite number"),
+    ("error.not_positive", "{} should be positive, got {}"),
+    ("error.empty_name", "name cannot be empty"),
+    ("error.exceeds_amount", "amount {} exceeds position amount {}"),
+    ("error.invalid_income_type", "'{}' is not valid income type (dividend/interest/reward)"),
+    ("error.invalid_position_type", "'{}' is not valid position type (long/short)"),
+    ("error.target_weight_format", "Target weight '{}' should look like 'btc=40'"),
+    ("error.target_weight_twice", "Target weight for '{}' is set twice"),
+    ("error.parse_weight", "Failed to parse weight '{}'"),
+    ("error.invalid_weight", "Invalid {} of '{}'"),
+    ("error.target_weights_over_100", "Target weights add up to {}%, which is over 100%"),
+    ("error.remove_first_order", "Cannot remove first order"),
+    ("error.add_history", "Failed to add command to history: {}"),
+    ("error.save_history", "Failed to save command history: {}"),
+    ("error.read_input_with_reason", "Failed to read input from console: {}"),
+    ("error.missing_argument_value", "Missing value of argument '--{}'"),
+    ("error.nothing_to_escape", "Nothing to escape at the end of command"),
+    ("error.unclosed_quote", "Unclosed quote {} in command"),
+    ("error.draw_ui", "Failed to draw UI: {}"),
+    ("error.read_terminal_event", "Failed to read terminal event: {}"),
+    ("error.empty_position_orders", "Failed to parse position {} orders, perhaps it's empty"),
+    ("error.invalid_stored_split_ratio", "Corporate action {} has invalid split ratio {}"),
+    ("error.write_initial_storage", "Failed to write initial storage file"),
+    ("error.read_storage", "Failed to read storage file"),
+    ("error.deserialize_storage", "Failed to deserialize storage data"),
+    ("error.serialize_storage", "Failed to serialize positions to json"),
+    ("error.write_storage", "Failed to save positions to storage file"),
+    ("error.write_initial_options", "Failed to write initial options file"),
+    ("error.read_options", "Failed to read options file"),
+    ("error.deserialize_options", "Failed to deserialize options"),
+    ("error.parse_direction", "Failed to parse direction '{}'"),
+    ("error.parse_sorting_method", "Failed to parse sorting method '{}'"),
+    ("error.unknown_filter_field", "Unknown filter field '{}'"),
+    ("error.filter_no_operator", "Filter condition '{}' has no operator"),
+    ("error.filter_no_value", "Filter condition '{}' has no value"),
+    ("error.operator_with_tags", "Operator '{}' cannot be used with tags"),
+    ("error.operator_with_name", "Operator '{}' cannot be used with name"),
+    ("error.operator_with_status", "Operator '{}' cannot be used with status"),
+    ("error.unknown_position_status", "Unknown position status '{}'"),
+    ("error.operator_with_dates", "Operator '{}' cannot be used with dates"),
+    ("error.parse_filter_date", "Failed to parse date '{}', expected format is YYYY-MM-DD"),
+    ("error.operator_with_numbers", "Operator '{}' cannot be used with numbers"),
+    ("error.parse_number", "Failed to parse number '{}'"),
+    ("error.empty_filter", "Filter expression is empty"),
+    ("error.unknown_report_period", "Unknown report period '{}'"),
+    ("error.unknown_report_grouping", "Unknown report grouping '{}'"),
+    ("error.invalid_corporate_action_type", "'{}' is not valid corporate action type (split/reverse/rename)"),
+    ("error.parse_field", "Failed to parse {} '{}'"),
+    ("error.sorting_directions_count", "Expected {} directions, got {}"),
+
+    ("warning.invalid_saved_filter", "Saved filter '{}' is ignored: {}. Use 'f' command to change it"),
+    ("warning.invalid_order", "position {} ({}), order {}: {}"),
+    ("warning.invalid_orders", "Storage file contains invalid orders, edit or delete them: {}"),
+
+    ("label.dashboard", "Dashboard:"),
+    ("label.invested_capital", "Invested capital"),
+    ("label.realized_income", "Realized income"),
+    ("label.realized_income_percent", "Realized income %"),
+    ("label.open_closed_positions", "Open / closed positions"),
+    ("label.best_position", "Best position"),
+    ("label.worst_position", "Worst position"),
+    ("label.win_rate", "Win rate"),
+    ("label.avg_holding_time", "Avg holding time"),
+    ("label.days_postfix", " days"),
+    ("label.largest_position", "Largest position"),
+    ("label.unrealized_income", "Unrealized income"),
+    ("label.equity", "Equity"),
+    ("label.dividend", "Dividend"),
+    ("label.interest", "Interest"),
+    ("label.reward", "Reward"),
+    ("label.stop_loss", "Stop loss"),
+    ("label.take_profit", "Take profit"),
+    ("label.split", "Split {}:1"),
+    ("label.reverse_split", "Reverse split 1:{}"),
+    ("label.rename", "Rename {} -> {}"),
+    ("label.buy", "Buy"),
+    ("label.sell", "Sell"),
+    ("label.portfolio_prefix", "Portfolio: "),
+    ("label.filter_prefix", "Filter: "),
+    ("label.last_edited_at_prefix", "Last edited at "),
+    ("label.tags_prefix", "Tags: "),
+    ("label.total", "Total"),
+    ("label.margin", "Margin"),
+    ("label.cash", "Cash"),
+    ("label.twr", "TWR"),
+    ("label.all_accounts", "All accounts"),
+    ("label.id", "Id"),
+    ("label.name", "Name"),
+    ("label.positions", "Positions"),
+    ("label.active", "Active"),
+    ("label.closed", "Closed"),
+    ("label.status", "Status"),
+    ("label.avg_value", "Avg value"),
+    ("label.avg_price", "Avg price"),
+    ("label.income", "Income"),
+    ("label.payouts", "Payouts"),
+    ("label.yearly_percent", "Yearly %"),
+    ("label.xirr_percent", "XIRR %"),
+    ("label.break_even", "Break-even"),
+    ("label.target_price", "Target +{}%"),
+    ("label.date", "Date"),
+    ("label.type", "Type"),
+    ("label.amount", "Amount"),
+    ("label.value", "Value"),
+    ("label.price", "Price"),
+    ("label.adjusted_amount", "Adj. amount"),
+    ("label.adjusted_price", "Adj. price"),
+    ("label.cash_ledger_title", "Portfolio {} cash ledger:"),
+    ("label.deposit", "Deposit"),
+    ("label.withdrawal", "Withdrawal"),
+    ("label.deposited", "Deposited"),
+    ("label.position_title", "Position {}"),
+    ("label.long", "Long"),
+    ("label.short", "Short"),
+    ("label.position_orders", "Position {} orders:"),
+    ("label.position_income_events", "Position {} income events:"),
+    ("label.position_corporate_actions", "Position {} corporate actions:"),
+    ("label.action", "Action"),
+    ("label.after_order", "After order"),
+    ("label.units", "Units"),
+    ("label.period", "Period"),
+    ("label.cumulative", "Cumulative"),
+    ("label.disposals_in", "Disposals in {}"),
+    ("label.all_disposals", "All disposals"),
+    ("label.position", "Position"),
+    ("label.acquired", "Acquired"),
+    ("label.disposed", "Disposed"),
+    ("label.proceeds", "Proceeds"),
+    ("label.cost_basis", "Cost basis"),
+    ("label.gain", "Gain"),
+    ("label.term", "Term"),
+    ("label.short_term", "Short-term"),
+    ("label.long_term", "Long-term"),
+    ("label.break_even_prefix", "Break-even: "),
+    ("label.target_prefix", "Target +{}%: "),
+    ("label.stop_loss_prefix", "Stop loss: "),
+    ("label.take_profit_prefix", "Take profit: "),
+    ("label.market_price_prefix", "Market price: "),
+    ("label.triggered_alerts", "Triggered price alerts:"),
+    ("label.portfolio", "Portfolio"),
+    ("label.market_price", "Market price"),
+    ("label.alert", "Alert"),
+    ("label.target", "Target"),
+    ("label.weight", "Weight"),
+    ("label.target_percent", "Target %"),
+    ("label.drift", "Drift"),
+    ("label.rebalance", "Rebalance"),
+    ("label.no_positions", "No positions"),
+    ("label.other", "Other"),
+    ("label.buy_value", "Buy {}"),
+    ("label.sell_value", "Sell {}"),
+    ("label.rebalancing_orders", "Rebalancing orders:"),
+    ("label.prices_of", "Prices of {}"),
+    ("label.position_chart_legend", "B - buy order, S - sell order, - - avg price"),
+    ("label.cumulative_realized_income", "Cumulative realized income:"),
+    ("label.allocation_by_avg_value", "Allocation by avg value:"),
+    ("label.before", "Before"),
+    ("label.after", "After"),
+    ("label.order_income_prefix", "Order income: "),
+
+    ("field.amount", "amount"),
+    ("field.value", "value"),
+    ("field.price", "price"),
+    ("field.avg_price", "avg price"),
+    ("field.weight", "weight"),
+    ("field.cash", "cash"),
+    ("field.units", "units"),
+
+    ("sorting.asc", "Asc"),
+    ("sorting.desc", "Desc"),
+    ("sorting.then_separator", ", then "),
+    ("sorting.by_id", "By id"),
+    ("sorting.by_avg_value", "By avg value"),
+    ("sorting.by_avg_price", "By avg price"),
+    ("sorting.by_income", "By income"),
+    ("sorting.by_last_change", "By last change"),
+    ("sorting.by_name", "By name (alphabetical)"),
+    ("sorting.by_natural_name", "By name (natural, 'BTC2' before 'BTC10')"),
+    ("sorting.by_amount", "By amount"),
+    ("sorting.by_income_percent", "By income percent"),
+    ("sorting.by_orders_count", "By orders count"),
+    ("sorting.by_created_at", "By creation date"),
+    ("sorting.by_status", "By status (active/closed)"),
+    ("sorting.by_annualized_return", "By annualized return"),
+    ("sorting.by_xirr", "By XIRR"),
+    ("sorting.id", "Id"),
+    ("sorting.avg_value", "Avg value"),
+    ("sorting.last_change", "Last change"),
+    ("sorting.avg_price", "Avg price"),
+    ("sorting.income", "Income"),
+    ("sorting.name", "Name"),
+    ("sorting.natural_name", "Name natural"),
+    ("sorting.amount", "Amount"),
+    ("sorting.income_percent", "Income %"),
+    ("sorting.orders_count", "Orders count"),
+    ("sorting.created_at", "Creation date"),
+    ("sorting.status", "Status"),
+    ("sorting.annualized_return", "Annualized return"),
+    ("sorting.xirr", "XIRR"),
+
+    ("tui.global_keys", "↑/↓ select  Enter edit  a add  d delete  s sort  c closed to bottom  ? help  q quit"),
+    ("tui.position_keys", "↑/↓ select order  a add order  d delete order  ? help  Esc back"),
+    ("tui.form_keys", "Tab next field  Enter submit  Esc cancel"),
+    ("tui.confirm_keys", "y confirm  n cancel"),
+    ("tui.no_portfolio_selected", "No portfolio selected"),
+    ("tui.sorting_prefix", "Sorting: "),
+    ("tui.orders", "Orders"),
+    ("tui.order", "Order"),
+    ("tui.no_order_selected", "No order selected"),
+    ("tui.order_title", "Order {} - {}"),
+    ("tui.confirm", "Confirm"),
+    ("tui.help", "Help"),
+    ("tui.select_position", "select position"),
+    ("tui.edit_position_orders", "edit position orders"),
+    ("tui.add_position", "add position"),
+    ("tui.delete_position", "delete position"),
+    ("tui.change_sorting", "change sorting"),
+    ("tui.move_closed_to_bottom", "move closed positions to bottom"),
+    ("tui.quit", "quit"),
+    ("tui.select_order", "select order"),
+    ("tui.add_order", "add order"),
+    ("tui.delete_order", "delete order"),
+    ("tui.back_to_positions", "back to positions"),
+    ("tui.repl_commands", "Other commands are available in the REPL, run with --repl"),
+    ("tui.press_any_key", "Press any key to close"),
+    ("tui.sorting_changed", "Sorting changed"),
+    ("tui.confirm_delete_position", "Are you sure want to delete position {} ({})?"),
+    ("tui.confirm_delete_order", "Are you sure want to delete order {}?"),
+    ("tui.position_deleted", "Position {} deleted"),
+    ("tui.position_added", "Position added"),
+    ("tui.order_deleted", "Order {} deleted"),
+    ("tui.order_added", "Order added"),
+    ("tui.add_position_title", "Add position"),
+    ("tui.add_order_title", "Add order"),
+    ("tui.change_sorting_title", "Change sorting"),
+    ("tui.position_type_hint", "long/short"),
+    ("tui.order_type_hint", "buy/sell"),
+    ("tui.order_amount_hint", "0 to fully close position"),
+    ("tui.methods", "Methods"),
+    ("tui.methods_hint", "numbers from the list, e.g. 12,4"),
+    ("tui.directions", "Directions"),
+    ("tui.directions_hint", "asc/desc for each method, e.g. desc,asc"),
+];
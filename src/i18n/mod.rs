@@ -0,0 +1,185 @@
+mod de;
+mod en;
+
+use std::cell::Cell;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Language of messages, help pages and prompts
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    De,
+}
+
+pub const LANGUAGES: [Language; 2] = [Language::En, Language::De];
+
+impl Language {
+    // Pairs of message keys and texts, "{}" are replaced with arguments in order
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::En => en::MESSAGES,
+            Self::De => de::MESSAGES,
+        }
+    }
+
+    fn find(&self, key: &str) -> Option<&'static str> {
+        self.catalog()
+            .iter()
+            .find(|(message_key, _)| *message_key == key)
+            .map(|(_, message)| *message)
+    }
+}
+
+thread_local! {
+    // Set from options at startup
+    static LANGUAGE: Cell<Language> = const { Cell::new(Language::En) };
+}
+
+pub fn set_language(language: Language) {
+    LANGUAGE.with(|current| current.set(language));
+}
+
+/// Returns message of the current language. Missing messages fall back to English,
+/// unknown keys are returned as is.
+pub fn t(key: &str) -> String {
+    let language = LANGUAGE.with(|current| current.get());
+    language
+        .find(key)
+        .or_else(|| Language::En.find(key))
+        .unwrap_or(key)
+        .to_string()
+}
+
+/// Same as `t`, but replaces "{}" in the message with arguments in order
+pub fn format_message(key: &str, args: &[&dyn Display]) -> String {
+    let mut args = args.iter();
+    t(key)
+        .split("{}")
+        .enumerate()
+        .map(|(index, part)| match index {
+            0 => part.to_string(),
+            _ => match args.next() {
+                Some(arg) => format!("{}{}", arg, part),
+                None => format!("{{}}{}", part),
+            },
+        })
+        .collect()
+}
+
+#[macro_export]
+macro_rules! tf {
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format_message($key, &[$(&$arg as &dyn std::fmt::Display),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::{format_message, set_language, t, Language, LANGUAGES};
+
+    const KEY_PREFIXES: [&str; 10] = [
+        "help", "prompt", "confirm", "info", "error", "warning", "label", "field", "sorting", "tui",
+    ];
+
+    // Collects "prefix.name" literals of the sources, catalogs themselves are skipped
+    fn collect_used_keys(dir: &Path, keys: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                if !path.ends_with("i18n") {
+                    collect_used_keys(&path, keys);
+                }
+                continue;
+            }
+
+            let source = fs::read_to_string(&path).unwrap();
+            for literal in source.split('"').skip(1).step_by(2) {
+                let is_key = literal.split_once('.').is_some_and(|(prefix, name)| {
+                    KEY_PREFIXES.contains(&prefix)
+                        && !name.is_empty()
+                        && name.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+                });
+                if is_key && !keys.iter().any(|key| key == literal) {
+                    keys.push(literal.to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalogs_have_all_keys() {
+        for language in LANGUAGES {
+            let catalog = language.catalog();
+            for (index, (key, _)) in catalog.iter().enumerate() {
+                assert!(
+                    catalog[..index].iter().all(|(other, _)| other != key),
+                    "{:?} has duplicate key '{}'",
+                    language,
+                    key
+                );
+            }
+
+            for other in LANGUAGES {
+                for (key, message) in other.catalog() {
+                    let translated = language.find(key);
+                    assert!(translated.is_some(), "{:?} has no key '{}'", language, key);
+                    assert_eq!(
+                        translated.unwrap().matches("{}").count(),
+                        message.matches("{}").count(),
+                        "{:?} has different arguments of '{}'",
+                        language,
+                        key
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_used_keys_are_in_catalogs() {
+        let mut keys = vec![];
+        collect_used_keys(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut keys,
+        );
+        assert!(keys.len() > 100);
+
+        for language in LANGUAGES {
+            for key in &keys {
+                assert!(
+                    language.find(key).is_some(),
+                    "{:?} has no key '{}'",
+                    language,
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_message() {
+        set_language(Language::De);
+        assert_eq!(
+            t("error.already_at_last_page"),
+            "Bereits auf der letzten Seite"
+        );
+        assert_eq!(
+            format_message("error.position_not_found", &[&5]),
+            "Position mit ID 5 nicht gefunden"
+        );
+        assert_eq!(t("unknown.key"), "unknown.key");
+
+        set_language(Language::En);
+        assert_eq!(
+            crate::tf!("error.position_not_found", 5),
+            "Position with id 5 not found"
+        );
+    }
+}
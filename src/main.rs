@@ -1,13 +1,21 @@
 pub mod commands;
 pub mod constants;
+pub mod i18n;
 pub mod models;
 pub mod options;
 pub mod storage;
+pub mod tui;
 pub mod utils;
 
+use std::io::IsTerminal;
+
 use commands::CommandHandler;
+use i18n::t;
 use models::Position;
 use utils::console::{ask_confirmation, print_error, wait_for_enter, ConfirmationStatus};
+use utils::line_editor::read_line;
+
+const REPL_FLAG: &str = "--repl";
 
 fn exit_with_error(error: String) -> ! {
     print_error(error);
@@ -16,14 +24,13 @@ fn exit_with_error(error: String) -> ! {
 
 fn handle_load_initial_positions_error(error: String) -> Vec<Position> {
     print_error(error);
-    let confirmation_message =
-"Perhaps, the version of app has changed. You can reinitialize storage (all data will be lost) or 
-try to fix data in file yourself\ny - Reinitialize storage\nn - Exit without changes\n(Default: n)";
-    let confirmation_status =
-        match ask_confirmation(confirmation_message, ConfirmationStatus::Rejected) {
-            Ok(value) => value,
-            Err(error) => exit_with_error(error),
-        };
+    let confirmation_status = match ask_confirmation(
+        &t("confirm.reinitialize_storage"),
+        ConfirmationStatus::Rejected,
+    ) {
+        Ok(value) => value,
+        Err(error) => exit_with_error(error),
+    };
 
     if confirmation_status == ConfirmationStatus::Confirmed {
         if let Err(error) = storage::reinitialize_storage() {
@@ -43,6 +50,9 @@ fn main() {
     if let Err(error) = options::initialize_options() {
         exit_with_error(error);
     }
+    let initial_options = options::get_options();
+    i18n::set_language(initial_options.language);
+    utils::theme::configure_output(&initial_options);
 
     if let Err(error) = storage::initialize_storage() {
         exit_with_error(error);
@@ -54,13 +64,35 @@ fn main() {
     };
 
     let mut command_handler = CommandHandler::new(&initial_positions);
+    if let Err(error) = command_handler.show_triggered_alerts() {
+        exit_with_error(error);
+    }
+    if initial_options.show_dashboard_on_startup {
+        if let Err(error) = command_handler.show_dashboard() {
+            exit_with_error(error);
+        }
+    }
     let stdin = std::io::stdin();
 
+    // Full-screen UI needs a terminal, piped input is handled by the REPL
+    let use_repl = std::env::args().any(|arg| arg == REPL_FLAG)
+        || !stdin.is_terminal()
+        || !std::io::stdout().is_terminal();
+    if !use_repl {
+        if let Err(error) = tui::run(command_handler) {
+            exit_with_error(error);
+        }
+        return;
+    }
+
     command_handler.show_ui();
 
     loop {
-        let mut cmd = String::new();
-        stdin.read_line(&mut cmd).expect("read command from stdin");
+        let cmd = match read_line(command_handler.get_completions(), true) {
+            Ok(Some(value)) => value,
+            Ok(None) => std::process::exit(0),
+            Err(error) => exit_with_error(error),
+        };
 
         let command_result: Result<(), String> = command_handler.handle_command(cmd);
 
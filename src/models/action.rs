@@ -1,20 +1,29 @@
+use crate::tf;
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Action {
     Long,
     Short,
 }
 
+// Answers completed when position or order type is asked
+pub const POSITION_TYPES: [&str; 2] = ["long", "short"];
+pub const ORDER_TYPES: [&str; 2] = ["buy", "sell"];
+
 impl Action {
     pub fn from_string(string: String) -> Result<Action, String> {
         match string.to_lowercase().as_str() {
             "l" | "long" | "b" | "buy" => Ok(Action::Long),
             "s" | "short" | "sell" => Ok(Action::Short),
-            _ => {
-                return Err(format!(
-                    "'{}' is not valid position type (long/short)",
-                    string
-                ))
-            }
+            _ => Err(tf!("error.invalid_position_type", string)),
+        }
+    }
+
+    // Action of orders, which close position of this action
+    pub fn opposite(&self) -> Action {
+        match self {
+            Action::Long => Action::Short,
+            Action::Short => Action::Long,
         }
     }
 }
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use super::validation::validate_positive;
+use super::Position;
+use crate::tf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllocationKey {
+    Name(String),
+    Tag(String),
+}
+
+impl AllocationKey {
+    pub fn matches(&self, position: &Position) -> bool {
+        match self {
+            Self::Name(name) => position.name.to_lowercase() == *name,
+            Self::Tag(tag) => position.tags.contains(tag),
+        }
+    }
+}
+
+impl fmt::Display for AllocationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{}", name),
+            Self::Tag(tag) => write!(f, "tag:{}", tag),
+        }
+    }
+}
+
+/// Desired share of portfolio value for positions with the name or tag
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetWeight {
+    pub key: AllocationKey,
+    pub percent: f64,
+}
+
+impl TargetWeight {
+    /// Parses weights like `btc=40 eth=30 tag:stable=30`. Weights should not exceed 100% in sum.
+    pub fn parse_list(input: &str) -> Result<Vec<TargetWeight>, String> {
+        let mut weights: Vec<TargetWeight> = vec![];
+        for token in input.split_whitespace() {
+            let (key, percent) = match token.split_once('=') {
+                Some(parts) => parts,
+                None => return Err(tf!("error.target_weight_format", token)),
+            };
+
+            let key = match key.to_lowercase().strip_prefix("tag:") {
+                Some(tag) => AllocationKey::Tag(tag.to_string()),
+                None => AllocationKey::Name(key.to_lowercase()),
+            };
+            if weights.iter().any(|weight| weight.key == key) {
+                return Err(tf!("error.target_weight_twice", key));
+            }
+
+            let percent = match percent.trim_end_matches('%').parse::<f64>() {
+                Ok(percent) => percent,
+                Err(_) => return Err(tf!("error.parse_weight", percent)),
+            };
+            if let Err(error) = validate_positive("weight", percent) {
+                return Err(tf!("error.invalid_weight", error, key));
+            }
+
+            weights.push(TargetWeight { key, percent });
+        }
+
+        let total: f64 = weights.iter().map(|weight| weight.percent).sum();
+        if total > 100f64 {
+            return Err(tf!("error.target_weights_over_100", total));
+        }
+
+        Ok(weights)
+    }
+}
+
+impl fmt::Display for TargetWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AllocationKey, TargetWeight};
+
+    #[test]
+    fn test_target_weights_parse() {
+        let weights = TargetWeight::parse_list("BTC=40 eth=30% tag:Stable=30").unwrap();
+        assert_eq!(weights.len(), 3);
+        assert_eq!(weights[0].key, AllocationKey::Name(String::from("btc")));
+        assert_eq!(weights[1].percent, 30f64);
+        assert_eq!(weights[2].key, AllocationKey::Tag(String::from("stable")));
+        assert_eq!(weights[2].to_string(), "tag:stable=30");
+
+        assert!(TargetWeight::parse_list("btc").is_err());
+        assert!(TargetWeight::parse_list("btc=-5").is_err());
+        assert!(TargetWeight::parse_list("btc=60 eth=50").is_err());
+        assert!(TargetWeight::parse_list("btc=10 BTC=20").is_err());
+    }
+}
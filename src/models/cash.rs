@@ -0,0 +1,39 @@
+use chrono::{DateTime, Local};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CashEntryKind {
+    Deposit,
+    Withdrawal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CashEntry {
+    pub id: i32,
+    pub kind: CashEntryKind,
+    pub amount: f64,
+    pub date: DateTime<Local>,
+}
+
+impl CashEntry {
+    pub fn new(entries: &[CashEntry], kind: CashEntryKind, amount: f64) -> CashEntry {
+        let id = match entries.iter().map(|entry| entry.id).max() {
+            Some(last_id) => last_id + 1,
+            None => 0,
+        };
+
+        CashEntry {
+            id,
+            kind,
+            amount,
+            date: Local::now(),
+        }
+    }
+
+    // Amount with sign, in which entry changes cash balance
+    pub fn signed_amount(&self) -> f64 {
+        match self.kind {
+            CashEntryKind::Deposit => self.amount,
+            CashEntryKind::Withdrawal => -self.amount,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use chrono::{DateTime, Local};
+
+use crate::tf;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum CorporateActionKind {
+    // New units received for each held unit, e.g. 10 for 10:1 split
+    Split(f64),
+    // Held units replaced by one new unit, e.g. 10 for 1:10 reverse split
+    ReverseSplit(f64),
+    Rename { old_name: String, new_name: String },
+}
+
+impl fmt::Display for CorporateActionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Split(ratio) => write!(f, "{}", tf!("label.split", ratio)),
+            Self::ReverseSplit(ratio) => write!(f, "{}", tf!("label.reverse_split", ratio)),
+            Self::Rename { old_name, new_name } => {
+                write!(f, "{}", tf!("label.rename", old_name, new_name))
+            }
+        }
+    }
+}
+
+/// Stock split, reverse split or ticker change. Orders placed before the action are kept
+/// intact, amount and price of the position are adjusted from the action point.
+#[derive(Debug, Clone)]
+pub struct CorporateAction {
+    pub id: i32,
+    pub kind: CorporateActionKind,
+    // Action is applied right after the order with this id
+    pub after_order_id: i32,
+    pub date: DateTime<Local>,
+}
+
+impl CorporateAction {
+    // Multiplier for amounts held before the action, prices are divided by it
+    pub fn get_amount_factor(&self) -> f64 {
+        match self.kind {
+            CorporateActionKind::Split(ratio) => ratio,
+            CorporateActionKind::ReverseSplit(ratio) => 1f64 / ratio,
+            CorporateActionKind::Rename { .. } => 1f64,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use chrono::{DateTime, Local};
+
+use crate::i18n::t;
+use crate::tf;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum IncomeEventKind {
+    Dividend,
+    Interest,
+    Reward,
+}
+
+impl IncomeEventKind {
+    pub fn from_string(string: String) -> Result<IncomeEventKind, String> {
+        match string.to_lowercase().as_str() {
+            "d" | "dividend" => Ok(IncomeEventKind::Dividend),
+            "i" | "interest" | "coupon" => Ok(IncomeEventKind::Interest),
+            "r" | "reward" | "staking" => Ok(IncomeEventKind::Reward),
+            _ => Err(tf!("error.invalid_income_type", string)),
+        }
+    }
+}
+
+impl fmt::Display for IncomeEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dividend => write!(f, "{}", t("label.dividend")),
+            Self::Interest => write!(f, "{}", t("label.interest")),
+            Self::Reward => write!(f, "{}", t("label.reward")),
+        }
+    }
+}
+
+/// Income, which is not a trade: dividends, coupon interest or staking rewards.
+/// It's paid either in cash or in units of the asset, units are added to position at zero cost.
+#[derive(Debug, Clone, Copy)]
+pub struct IncomeEvent {
+    pub id: i32,
+    pub kind: IncomeEventKind,
+    // Event is applied right after the order with this id
+    pub after_order_id: i32,
+    pub cash: f64,
+    pub units: f64,
+    pub date: DateTime<Local>,
+}
@@ -1,7 +1,21 @@
 pub mod action;
+pub mod allocation;
+pub mod cash;
+pub mod corporate_action;
+pub mod income_event;
 pub mod order;
+pub mod portfolio;
 pub mod position;
+pub mod price_level;
+pub mod validation;
 
 pub use action::Action;
+pub use allocation::{AllocationKey, TargetWeight};
+pub use cash::{CashEntry, CashEntryKind};
+pub use corporate_action::{CorporateAction, CorporateActionKind};
+pub use income_event::{IncomeEvent, IncomeEventKind};
 pub use order::Order;
+pub use portfolio::Portfolio;
 pub use position::Position;
+pub use price_level::PriceLevel;
+pub use validation::ValidationError;
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Local};
+
 use super::{Action, Position};
 
 #[derive(Debug, Clone, Copy)]
@@ -8,15 +10,20 @@ pub struct Order {
     pub value: f64,
     pub price: f64,
     pub income: f64,
+    pub date: DateTime<Local>,
 }
 
 impl Order {
     pub fn new(pos: &Position, action: Action, amount: f64, value: f64) -> Order {
         let price = value / amount;
-        let income = (price - pos.avg_price) * amount;
+        let income = if action == pos.action {
+            0f64
+        } else {
+            Order::calculate_closing_income(pos.action, pos.avg_price, price, amount)
+        };
 
         let mut pos_orders_clone = pos.orders.to_vec();
-        pos_orders_clone.sort_by(|first, second| first.id.cmp(&second.id));
+        pos_orders_clone.sort_by_key(|order| order.id);
         let id = pos_orders_clone.last().unwrap().id + 1;
 
         Order {
@@ -26,12 +33,28 @@ impl Order {
             value,
             price,
             income,
+            date: Local::now(),
+        }
+    }
+
+    // Long position earns when price grows, short one earns when price falls
+    pub fn calculate_closing_income(
+        position_action: Action,
+        avg_price: f64,
+        price: f64,
+        amount: f64,
+    ) -> f64 {
+        match position_action {
+            Action::Long => (price - avg_price) * amount,
+            Action::Short => (avg_price - price) * amount,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::Local;
+
     use super::{Action, Order, Position};
 
     #[test]
@@ -43,6 +66,7 @@ mod tests {
             value: 100f64,
             price: 10f64,
             income: 0f64,
+            date: Local::now(),
         };
         let pos = Position::new(0, String::from("MOCK"), vec![manual_order]);
 
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Local};
+
+use super::{Action, CashEntry, Position, TargetWeight};
+use crate::commands::utils::sorting::SortBy;
+
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    pub id: i32,
+    pub name: String,
+    pub sort_positions_by: SortBy,
+    pub then_sort_positions_by: Vec<SortBy>,
+    pub move_closed_positions_to_bottom: bool,
+    pub positions_filter: Option<String>,
+    pub cash_entries: Vec<CashEntry>,
+    // Allows buying for more than cash balance without warnings
+    pub allow_margin: bool,
+    pub target_weights: Vec<TargetWeight>,
+    pub positions: Vec<Position>,
+}
+
+impl Portfolio {
+    pub fn new(id: i32, name: String) -> Portfolio {
+        Portfolio {
+            id,
+            name,
+            sort_positions_by: SortBy::default(),
+            then_sort_positions_by: vec![],
+            move_closed_positions_to_bottom: false,
+            positions_filter: None,
+            cash_entries: vec![],
+            allow_margin: false,
+            target_weights: vec![],
+            positions: vec![],
+        }
+    }
+
+    pub fn count_active_positions(&self) -> usize {
+        self.positions
+            .iter()
+            .filter(|position| !position.is_closed())
+            .count()
+    }
+
+    // Cash is tracked since the first deposit or withdrawal
+    pub fn is_cash_tracked(&self) -> bool {
+        !self.cash_entries.is_empty()
+    }
+
+    pub fn calculate_net_deposits(&self) -> f64 {
+        self.cash_entries
+            .iter()
+            .map(|entry| entry.signed_amount())
+            .sum()
+    }
+
+    pub fn calculate_cash_balance(&self) -> f64 {
+        let orders_cash_flow: f64 = self
+            .positions
+            .iter()
+            .map(|position| position.calculate_cash_flow())
+            .sum();
+
+        self.calculate_net_deposits() + orders_cash_flow
+    }
+
+    // Equity is valued by positions cost, as market prices are unknown. Short positions are
+    // debts, so their value is subtracted.
+    pub fn calculate_equity(&self) -> f64 {
+        let positions_value: f64 = self
+            .positions
+            .iter()
+            .map(|position| match position.action {
+                Action::Long => position.avg_value,
+                Action::Short => -position.avg_value,
+            })
+            .sum();
+
+        self.calculate_cash_balance() + positions_value
+    }
+
+    /// Time-weighted return in percents. Deposits and withdrawals split history into periods,
+    /// return of each period is realized income divided by equity at its start.
+    /// None if cash is not tracked, as equity is unknown then.
+    pub fn calculate_time_weighted_return(&self) -> Option<f64> {
+        if !self.is_cash_tracked() {
+            return None;
+        }
+
+        // Flows are (date, external cash, income)
+        let mut flows: Vec<(DateTime<Local>, f64, f64)> = self
+            .cash_entries
+            .iter()
+            .map(|entry| (entry.date, entry.signed_amount(), 0f64))
+            .collect();
+        for position in &self.positions {
+            position
+                .get_realized_income()
+                .into_iter()
+                .for_each(|(date, income)| flows.push((date, 0f64, income)));
+        }
+        flows.sort_by_key(|(date, _, _)| *date);
+
+        let mut growth = 1f64;
+        let mut period_start_equity = 0f64;
+        let mut period_income = 0f64;
+        for (_, external_cash, income) in flows {
+            if external_cash != 0f64 {
+                if period_start_equity > 0f64 {
+                    growth *= 1f64 + period_income / period_start_equity;
+                }
+                period_start_equity += period_income + external_cash;
+                period_income = 0f64;
+            }
+            period_income += income;
+        }
+        if period_start_equity > 0f64 {
+            growth *= 1f64 + period_income / period_start_equity;
+        }
+
+        Some((growth - 1f64) * 100f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local};
+
+    use super::Portfolio;
+    use crate::models::{Action, CashEntry, CashEntryKind, Order, Position};
+
+    #[test]
+    fn test_portfolio_cash_balance() {
+        let mut portfolio = Portfolio::new(0, String::from("MOCK"));
+        portfolio.cash_entries = vec![
+            CashEntry::new(&[], CashEntryKind::Deposit, 1000f64),
+            CashEntry::new(&[], CashEntryKind::Withdrawal, 100f64),
+        ];
+
+        let mut position = Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 10f64,
+                value: 500f64,
+                price: 50f64,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+        position.add_order(Order::new(&position, Action::Short, 5f64, 300f64));
+        portfolio.positions = vec![position];
+
+        // 1000 deposited - 100 withdrawn - 500 spent on buy + 300 received from sell
+        assert_eq!(portfolio.calculate_net_deposits(), 900f64);
+        assert_eq!(portfolio.calculate_cash_balance(), 700f64);
+        assert_eq!(portfolio.calculate_equity(), 950f64);
+    }
+
+    #[test]
+    fn test_portfolio_time_weighted_return() {
+        let start = Local::now() - Duration::days(30);
+        let mut portfolio = Portfolio::new(0, String::from("MOCK"));
+        assert_eq!(portfolio.calculate_time_weighted_return(), None);
+
+        let mut deposit = CashEntry::new(&[], CashEntryKind::Deposit, 1000f64);
+        deposit.date = start;
+        let mut second_deposit = CashEntry::new(&[deposit], CashEntryKind::Deposit, 1100f64);
+        second_deposit.date = start + Duration::days(20);
+        portfolio.cash_entries = vec![deposit, second_deposit];
+
+        let mut position = Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 10f64,
+                value: 1000f64,
+                price: 100f64,
+                income: 0f64,
+                date: start,
+            }],
+        );
+        // +100 income before second deposit, -220 after it
+        let mut order = Order::new(&position, Action::Short, 5f64, 600f64);
+        order.date = start + Duration::days(10);
+        position.add_order(order);
+        let mut order = Order::new(&position, Action::Short, 5f64, 280f64);
+        order.date = start + Duration::days(30);
+        position.add_order(order);
+        portfolio.positions = vec![position];
+
+        // Periods return +10% on 1000 and -10% on 2200, so (1.1 * 0.9 - 1) = -1%
+        let twr = portfolio.calculate_time_weighted_return().unwrap();
+        assert!((twr + 1f64).abs() < 1e-9);
+    }
+}
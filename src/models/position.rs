@@ -1,35 +1,118 @@
 use chrono::{DateTime, Local};
 
-use super::{Action, Order};
+use crate::i18n::t;
+use crate::tf;
+use crate::utils::math::{annualize_return, calculate_xirr, years_between};
+
+use super::validation::{validate_order_values, ValidationError};
+use super::{Action, CorporateAction, CorporateActionKind, IncomeEvent, Order, PriceLevel};
+
+enum Adjustment<'a> {
+    Income(&'a IncomeEvent),
+    Corporate(&'a CorporateAction),
+}
+
+impl Adjustment<'_> {
+    fn after_order_id(&self) -> i32 {
+        match self {
+            Self::Income(event) => event.after_order_id,
+            Self::Corporate(action) => action.after_order_id,
+        }
+    }
+
+    fn date(&self) -> DateTime<Local> {
+        match self {
+            Self::Income(event) => event.date,
+            Self::Corporate(action) => action.date,
+        }
+    }
+}
+
+// Amounts smaller than this are left from float rounding, e.g. of splits, and treated as zero
+pub const AMOUNT_EPSILON: f64 = 1e-9;
+
+// Running state of position while its history is replayed
+#[derive(Default)]
+struct Holding {
+    amount: f64,
+    cost: f64,
+    avg_price: f64,
+    events_income: f64,
+}
+
+impl Holding {
+    // Price of closed position is kept, so it's still visible in the table
+    fn update_avg_price(&mut self) {
+        if self.amount != 0f64 {
+            self.avg_price = self.cost / self.amount;
+        }
+    }
+
+    fn apply(&mut self, adjustment: &Adjustment) {
+        match adjustment {
+            Adjustment::Income(event) => {
+                self.events_income += event.cash;
+                self.amount += event.units;
+                self.update_avg_price();
+            }
+            Adjustment::Corporate(action) => {
+                let factor = action.get_amount_factor();
+                self.amount *= factor;
+                self.avg_price /= factor;
+                self.update_avg_price();
+            }
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Position {
     pub id: i32,
     pub edited_at: DateTime<Local>,
+    pub created_at: DateTime<Local>,
     pub action: Action,
     pub name: String,
     pub amount: f64,
     pub avg_value: f64,
     pub avg_price: f64,
+    // Total income, including income from events
     pub income: f64,
+    pub events_income: f64,
     pub orders: Vec<Order>,
+    pub income_events: Vec<IncomeEvent>,
+    pub corporate_actions: Vec<CorporateAction>,
+    pub tags: Vec<String>,
+    pub stop_loss: Option<f64>,
+    pub take_profit: Option<f64>,
+    // Entered manually, as there is no price source yet
+    pub market_price: Option<f64>,
+    pub market_price_updated_at: Option<DateTime<Local>>,
 }
 
 impl Position {
     pub fn new(id: i32, name: String, mut orders: Vec<Order>) -> Position {
-        orders.sort_by(|first, second| first.id.cmp(&second.id));
+        orders.sort_by_key(|order| order.id);
         let first_order = orders.first().unwrap();
 
         let mut pos = Position {
             id,
             edited_at: Local::now(),
+            created_at: Local::now(),
             name,
             action: first_order.action,
             amount: 0f64,
             avg_price: 0f64,
             income: 0f64,
+            events_income: 0f64,
             avg_value: 0f64,
             orders: vec![],
+            income_events: vec![],
+            corporate_actions: vec![],
+            tags: vec![],
+            stop_loss: None,
+            take_profit: None,
+            market_price: None,
+            market_price_updated_at: None,
         };
 
         orders
@@ -40,67 +123,282 @@ impl Position {
     }
 
     pub fn add_order(&mut self, order: Order) {
-        let mut same_action_prices_sum = 0f64;
-        let mut same_action_orders_count = 0f64;
+        self.orders.push(order);
+        self.recalculate();
+    }
 
-        let mut new_orders = self.orders.to_vec();
-        new_orders.push(order);
+    pub fn remove_order(&mut self, order_id: i32) -> Result<(), String> {
+        if order_id == 0 {
+            return Err(t("error.remove_first_order"));
+        }
 
-        let mut new_amount = 0f64;
+        let order_index = match self.orders.iter().position(|order| order.id == order_id) {
+            Some(order) => order,
+            None => return Err(tf!("error.order_not_found", order_id, self.id)),
+        };
 
-        new_orders.iter().for_each(|order| {
-            if self.action == order.action {
-                new_amount += order.amount;
+        let order = self.orders.remove(order_index);
+        if let Some(error) = self.recalculate() {
+            self.orders.push(order);
+            self.recalculate();
+            return Err(tf!("error.remove_order_over_close", order_id, error));
+        }
 
-                same_action_prices_sum += order.price;
-                same_action_orders_count += 1f64;
-            } else {
-                new_amount -= order.amount;
-            }
-        });
+        Ok(())
+    }
 
-        self.avg_price = same_action_prices_sum / same_action_orders_count;
+    pub fn add_income_event(&mut self, event: IncomeEvent) {
+        self.income_events.push(event);
+        self.recalculate();
+    }
 
-        let mut new_income = 0f64;
+    pub fn remove_income_event(&mut self, event_id: i32) -> Result<(), String> {
+        let event_index = match self
+            .income_events
+            .iter()
+            .position(|event| event.id == event_id)
+        {
+            Some(index) => index,
+            None => return Err(tf!("error.income_event_not_found", event_id, self.id)),
+        };
 
-        new_orders.iter().for_each(|order| {
-            if self.action != order.action {
-                new_income += order.income;
-            }
+        let event = self.income_events.remove(event_index);
+        if let Some(error) = self.recalculate() {
+            self.income_events.insert(event_index, event);
+            self.recalculate();
+            return Err(tf!("error.remove_income_event_over_close", event_id, error));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_next_income_event_id(&self) -> i32 {
+        match self.income_events.iter().map(|event| event.id).max() {
+            Some(last_id) => last_id + 1,
+            None => 0,
+        }
+    }
+
+    pub fn add_corporate_action(&mut self, action: CorporateAction) {
+        if let CorporateActionKind::Rename { new_name, .. } = &action.kind {
+            self.name = new_name.clone();
+        }
+
+        self.corporate_actions.push(action);
+        self.recalculate();
+    }
+
+    pub fn remove_corporate_action(&mut self, action_id: i32) -> Result<(), String> {
+        let action_index = match self
+            .corporate_actions
+            .iter()
+            .position(|action| action.id == action_id)
+        {
+            Some(index) => index,
+            None => return Err(tf!("error.corporate_action_not_found", action_id, self.id)),
+        };
+
+        let action = self.corporate_actions.remove(action_index);
+        if let Some(error) = self.recalculate() {
+            self.corporate_actions.insert(action_index, action);
+            self.recalculate();
+            return Err(tf!(
+                "error.remove_corporate_action_over_close",
+                action_id,
+                error
+            ));
+        }
+
+        // Name is restored only if the latest rename is removed
+        let is_latest_rename = self.corporate_actions.iter().all(|other| {
+            !matches!(other.kind, CorporateActionKind::Rename { .. }) || other.date < action.date
         });
+        if let CorporateActionKind::Rename { old_name, .. } = action.kind {
+            if is_latest_rename {
+                self.name = old_name;
+            }
+        }
 
-        self.amount = new_amount;
-        self.avg_value = new_amount * self.avg_price;
-        self.income = new_income;
-        self.orders = new_orders;
+        Ok(())
     }
 
-    pub fn remove_order(&mut self, order_id: i32) -> Result<(), String> {
-        if order_id == 0 {
-            return Err(String::from("Cannot remove first order"));
+    pub fn get_next_corporate_action_id(&self) -> i32 {
+        match self.corporate_actions.iter().map(|action| action.id).max() {
+            Some(last_id) => last_id + 1,
+            None => 0,
         }
+    }
 
-        let order_index = match self.orders.iter().position(|order| order.id == order_id) {
-            Some(order) => order,
-            None => {
-                return Err(format!(
-                    "Order with id {} not found in position {}",
-                    order_id, self.id
-                ))
+    // Multiplier, which converts units of the order to units after all following splits
+    pub fn get_split_factor_after(&self, order_id: i32) -> f64 {
+        self.corporate_actions
+            .iter()
+            .filter(|action| action.after_order_id >= order_id)
+            .map(|action| action.get_amount_factor())
+            .product()
+    }
+
+    // Replays orders, income events and corporate actions from the beginning, recalculating
+    // amount, average price and income of the position and income of each closing order.
+    // Returns the first closing order, which exceeds amount held at its time.
+    fn recalculate(&mut self) -> Option<ValidationError> {
+        self.orders.sort_by_key(|order| order.id);
+
+        let mut adjustments: Vec<Adjustment> = self
+            .income_events
+            .iter()
+            .map(Adjustment::Income)
+            .chain(self.corporate_actions.iter().map(Adjustment::Corporate))
+            .collect();
+        adjustments.sort_by_key(|adjustment| (adjustment.after_order_id(), adjustment.date()));
+
+        let mut holding = Holding::default();
+        let mut orders_income = 0f64;
+        let mut over_close = None;
+
+        let mut adjustments = adjustments.into_iter().peekable();
+        for order in self.orders.iter_mut() {
+            if order.action == self.action {
+                holding.amount += order.amount;
+                holding.cost += order.value;
+                order.income = 0f64;
+            } else {
+                if over_close.is_none() && order.amount > holding.amount + AMOUNT_EPSILON {
+                    over_close = Some(ValidationError::OverClose {
+                        requested: order.amount,
+                        available: holding.amount,
+                    });
+                }
+                order.income = Order::calculate_closing_income(
+                    self.action,
+                    holding.avg_price,
+                    order.price,
+                    order.amount,
+                );
+                orders_income += order.income;
+                holding.amount -= order.amount;
+                holding.cost = holding.amount * holding.avg_price;
             }
-        };
+            holding.update_avg_price();
 
-        let mut new_orders = self.orders.to_vec();
-        new_orders.remove(order_index);
+            while let Some(adjustment) =
+                adjustments.next_if(|adjustment| adjustment.after_order_id() <= order.id)
+            {
+                holding.apply(&adjustment);
+            }
+        }
+
+        // Adjustments anchored after the last order, e.g. if that order was removed
+        adjustments.for_each(|adjustment| holding.apply(&adjustment));
+
+        self.amount = holding.amount;
+        self.avg_price = holding.avg_price;
+        self.avg_value = holding.amount * holding.avg_price;
+        self.events_income = holding.events_income;
+        self.income = orders_income + holding.events_income;
 
-        self.orders = vec![];
-        new_orders
+        over_close
+    }
+
+    /// Average price after each order, including income events and corporate actions
+    /// anchored before the next order
+    pub fn get_avg_price_history(&self) -> Vec<(DateTime<Local>, f64)> {
+        let mut orders = self.orders.clone();
+        orders.sort_by_key(|order| order.id);
+
+        orders
             .iter()
-            .for_each(|order| self.add_order(order.to_owned()));
+            .enumerate()
+            .map(|(index, order)| {
+                let next_order_id = orders.get(index + 1).map(|next| next.id);
+                let is_before_next =
+                    |after_order_id: i32| next_order_id.is_none_or(|id| after_order_id < id);
+
+                let mut position = self.clone();
+                position.orders = orders[..=index].to_vec();
+                position
+                    .income_events
+                    .retain(|event| is_before_next(event.after_order_id));
+                position
+                    .corporate_actions
+                    .retain(|action| is_before_next(action.after_order_id));
+                position.recalculate();
+
+                (order.date, position.avg_price)
+            })
+            .collect()
+    }
+
+    /// Checks that order can be added to position. Orders closing more than position amount
+    /// are rejected with `ValidationError::OverClose`.
+    pub fn validate_order(
+        &self,
+        action: Action,
+        amount: f64,
+        value: f64,
+    ) -> Result<(), ValidationError> {
+        validate_order_values(amount, value)?;
+
+        if action != self.action && amount > self.amount + AMOUNT_EPSILON {
+            return Err(ValidationError::OverClose {
+                requested: amount,
+                available: self.amount,
+            });
+        }
 
         Ok(())
     }
 
+    /// Splits order, which closes more than position amount, into the order closing position
+    /// and amount and value left for a new position of opposite direction.
+    /// Returns None if order doesn't exceed position amount.
+    pub fn split_over_close(
+        &self,
+        action: Action,
+        amount: f64,
+        value: f64,
+    ) -> Option<(Order, f64, f64)> {
+        if action == self.action || amount <= self.amount + AMOUNT_EPSILON {
+            return None;
+        }
+
+        let closing_value = value * self.amount / amount;
+        let closing_order = Order::new(self, action, self.amount, closing_value);
+
+        Some((closing_order, amount - self.amount, value - closing_value))
+    }
+
+    // Buy orders take cash from account, sell orders and cash income events return it
+    pub fn calculate_cash_flow(&self) -> f64 {
+        let orders_cash_flow: f64 = self
+            .orders
+            .iter()
+            .map(|order| match order.action {
+                Action::Long => -order.value,
+                Action::Short => order.value,
+            })
+            .sum();
+
+        orders_cash_flow + self.events_income
+    }
+
+    /// Orders with invalid amount or value can appear only after manual edit of storage file,
+    /// so they are reported to be fixed or deleted
+    pub fn find_invalid_orders(&self) -> Vec<String> {
+        self.orders
+            .iter()
+            .filter_map(|order| {
+                validate_order_values(order.amount, order.value)
+                    .err()
+                    .map(|error| tf!("warning.invalid_order", self.id, self.name, order.id, error))
+            })
+            .collect()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.amount.abs() < AMOUNT_EPSILON
+    }
+
     pub fn calculate_income_percent(&self) -> f64 {
         let other_action_orders: Vec<&Order> = self
             .orders
@@ -108,27 +406,236 @@ impl Position {
             .filter(|order| order.action != self.action)
             .collect();
 
-        if other_action_orders.len() < 1 {
+        if other_action_orders.is_empty() && self.income_events.is_empty() {
             return 0f64;
         }
 
-        let mut invested_funds = 0f64;
+        self.income / self.calculate_invested_funds() * 100f64
+    }
+
+    // Funds spent on opening orders
+    pub fn calculate_invested_funds(&self) -> f64 {
         self.orders
             .iter()
             .filter(|order| order.action == self.action)
-            .for_each(|order| {
+            .map(|order| order.value)
+            .sum()
+    }
+
+    /// Price, at which closing the rest of position makes its income percent equal to the
+    /// given one. None if position is closed or price is not positive.
+    pub fn calculate_price_for_income_percent(&self, percent: f64) -> Option<f64> {
+        if self.is_closed() {
+            return None;
+        }
+
+        let income_needed = percent / 100f64 * self.calculate_invested_funds() - self.income;
+        let price = match self.action {
+            Action::Long => self.avg_price + income_needed / self.amount,
+            Action::Short => self.avg_price - income_needed / self.amount,
+        };
+
+        if price > 0f64 {
+            Some(price)
+        } else {
+            None
+        }
+    }
+
+    // Fees are not tracked, so break-even price only covers realized income
+    pub fn calculate_break_even_price(&self) -> Option<f64> {
+        self.calculate_price_for_income_percent(0f64)
+    }
+
+    /// Amount to open at the given price, which moves average price to the desired one.
+    /// None if desired price is not between current average price and order price.
+    pub fn calculate_amount_to_average(&self, price: f64, desired_avg_price: f64) -> Option<f64> {
+        if self.is_closed() {
+            return None;
+        }
+
+        let amount =
+            self.amount * (self.avg_price - desired_avg_price) / (desired_avg_price - price);
+        if amount.is_finite() && amount > 0f64 {
+            Some(amount)
+        } else {
+            None
+        }
+    }
+
+    // Average price is used until market price is entered
+    pub fn get_current_price(&self) -> f64 {
+        self.market_price.unwrap_or(self.avg_price)
+    }
+
+    pub fn calculate_market_value(&self) -> f64 {
+        self.amount * self.get_current_price()
+    }
+
+    pub fn set_market_price(&mut self, price: f64) {
+        self.market_price = Some(price);
+        self.market_price_updated_at = Some(Local::now());
+    }
+
+    /// Level crossed by market price of active position. Long position hits stop loss when
+    /// price falls, short one when price grows.
+    pub fn get_triggered_level(&self) -> Option<PriceLevel> {
+        let price = self.market_price?;
+        if self.is_closed() {
+            return None;
+        }
+
+        let (is_below_stop_loss, is_above_take_profit) = match self.action {
+            Action::Long => (
+                self.stop_loss.is_some_and(|level| price <= level),
+                self.take_profit.is_some_and(|level| price >= level),
+            ),
+            Action::Short => (
+                self.stop_loss.is_some_and(|level| price >= level),
+                self.take_profit.is_some_and(|level| price <= level),
+            ),
+        };
+
+        if is_below_stop_loss {
+            Some(PriceLevel::StopLoss)
+        } else if is_above_take_profit {
+            Some(PriceLevel::TakeProfit)
+        } else {
+            None
+        }
+    }
+
+    /// Copy of position with the order added, position itself is left unchanged
+    pub fn simulate_order(&self, action: Action, amount: f64, price: f64) -> (Position, Order) {
+        let mut simulated = self.clone();
+        let order = Order::new(&simulated, action, amount, amount * price);
+        simulated.add_order(order);
+
+        (simulated, order)
+    }
+
+    // Position is held from the first order until closing order or until now, if it's active
+    fn get_holding_years(&self, now: DateTime<Local>) -> f64 {
+        let opened_at = self.orders.iter().map(|order| order.date).min();
+        let closed_at = if self.is_closed() {
+            self.orders.iter().map(|order| order.date).max()
+        } else {
+            Some(now)
+        };
+
+        match (opened_at, closed_at) {
+            (Some(opened_at), Some(closed_at)) => years_between(opened_at, closed_at),
+            _ => 0f64,
+        }
+    }
+
+    /// Income percent converted to yearly return. None if position was held for less than a day.
+    pub fn calculate_annualized_return(&self, now: DateTime<Local>) -> Option<f64> {
+        let years = self.get_holding_years(now);
+        if years < 1f64 / 365f64 {
+            return None;
+        }
+
+        annualize_return(self.calculate_income_percent() / 100f64, years).map(|rate| rate * 100f64)
+    }
+
+    // Cash flows from investor's point of view: opening orders invest funds, closing orders and
+    // income events return them. Amount left open is counted as returned at average price now,
+    // as market prices are unknown.
+    pub fn get_dated_cash_flows(&self, now: DateTime<Local>) -> Vec<(DateTime<Local>, f64)> {
+        let mut cash_flows: Vec<(DateTime<Local>, f64)> = self
+            .orders
+            .iter()
+            .map(|order| {
                 if order.action == self.action {
-                    invested_funds += order.value;
+                    return (order.date, -order.value);
+                }
+
+                let cost = match self.action {
+                    Action::Long => order.value - order.income,
+                    Action::Short => order.value + order.income,
+                };
+                (order.date, cost + order.income)
+            })
+            .collect();
+
+        self.income_events
+            .iter()
+            .filter(|event| event.cash != 0f64)
+            .for_each(|event| cash_flows.push((event.date, event.cash)));
+
+        if !self.is_closed() {
+            cash_flows.push((now, self.avg_value));
+        }
+
+        cash_flows
+    }
+
+    // Income of closing orders and cash income events with dates, when it was received
+    pub fn get_realized_income(&self) -> Vec<(DateTime<Local>, f64)> {
+        let orders_income = self
+            .orders
+            .iter()
+            .filter(|order| order.action != self.action)
+            .map(|order| (order.date, order.income));
+        let events_income = self
+            .income_events
+            .iter()
+            .filter(|event| event.cash != 0f64)
+            .map(|event| (event.date, event.cash));
+
+        orders_income.chain(events_income).collect()
+    }
+
+    /// Parses comma separated tags, dropping empty and repeated ones
+    pub fn set_tags(&mut self, input: &str) {
+        self.tags = vec![];
+        input
+            .split(',')
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .for_each(|tag| {
+                if !self.tags.contains(&tag) {
+                    self.tags.push(tag);
                 }
             });
+    }
 
-        self.income / invested_funds * 100f64
+    /// Money-weighted yearly return in percents
+    pub fn calculate_xirr(&self, now: DateTime<Local>) -> Option<f64> {
+        if self.get_holding_years(now) < 1f64 / 365f64 {
+            return None;
+        }
+
+        calculate_xirr(&self.get_dated_cash_flows(now)).map(|rate| rate * 100f64)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Action, Order, Position};
+    use chrono::{Duration, Local};
+
+    use super::{
+        Action, CorporateAction, CorporateActionKind, IncomeEvent, Order, Position, PriceLevel,
+    };
+    use crate::models::IncomeEventKind;
+
+    // Long position of 10 units bought at 100
+    fn mock_position() -> Position {
+        Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 10f64,
+                value: 1000f64,
+                price: 100f64,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        )
+    }
 
     #[test]
     fn test_position_add_order() {
@@ -139,6 +646,7 @@ mod tests {
             value: 100f64,
             price: 10f64,
             income: 0f64,
+            date: Local::now(),
         };
 
         let mut position = Position::new(0, String::from("MOCK"), vec![first_order]);
@@ -166,6 +674,7 @@ mod tests {
             value: 100f64,
             price: 10f64,
             income: 0f64,
+            date: Local::now(),
         };
 
         let mut position = Position::new(0, String::from("MOCK"), vec![first_order]);
@@ -177,6 +686,7 @@ mod tests {
             value: 50f64,
             price: 5f64,
             income: 0f64,
+            date: Local::now(),
         });
 
         let tested_order_id = 2;
@@ -187,6 +697,7 @@ mod tests {
             value: 50f64,
             price: 5f64,
             income: 5f64,
+            date: Local::now(),
         });
 
         // Remove last order and check recalculation
@@ -214,6 +725,7 @@ mod tests {
                     value: 100f64,
                     price: 100f64,
                     income: 0f64,
+                    date: Local::now(),
                 },
                 Order {
                     id: 1,
@@ -222,6 +734,7 @@ mod tests {
                     value: 200f64,
                     price: 200f64,
                     income: 100f64,
+                    date: Local::now(),
                 },
             ],
         );
@@ -240,6 +753,7 @@ mod tests {
                     value: 200f64,
                     price: 200f64,
                     income: 0f64,
+                    date: Local::now(),
                 },
                 Order {
                     id: 1,
@@ -248,10 +762,332 @@ mod tests {
                     value: 100f64,
                     price: 100f64,
                     income: 100f64,
+                    date: Local::now(),
                 },
             ],
         );
 
         assert_eq!(position.calculate_income_percent(), -50f64);
     }
+
+    #[test]
+    fn test_position_income_events() {
+        let mut position = mock_position();
+
+        position.add_income_event(IncomeEvent {
+            id: 0,
+            kind: IncomeEventKind::Dividend,
+            after_order_id: 0,
+            cash: 50f64,
+            units: 0f64,
+            date: Local::now(),
+        });
+        position.add_income_event(IncomeEvent {
+            id: 1,
+            kind: IncomeEventKind::Reward,
+            after_order_id: 0,
+            cash: 0f64,
+            units: 10f64,
+            date: Local::now(),
+        });
+
+        // Reward units are added at zero cost, so avg price is halved
+        assert_eq!(position.amount, 20f64);
+        assert_eq!(position.avg_price, 50f64);
+        assert_eq!(position.avg_value, 1000f64);
+        assert_eq!(position.income, 50f64);
+        assert_eq!(position.events_income, 50f64);
+        assert_eq!(position.calculate_income_percent(), 5f64);
+
+        position.add_order(Order::new(&position, Action::Short, 20f64, 1200f64));
+
+        // (60 - 50) * 20 from sell order and 50 from dividend
+        assert_eq!(position.amount, 0f64);
+        assert_eq!(position.income, 250f64);
+        assert_eq!(position.calculate_income_percent(), 25f64);
+
+        // Sell order closes reward units too, so removing the reward is rejected
+        assert!(position.remove_income_event(1).is_err());
+        assert_eq!(position.income_events.len(), 2);
+        assert_eq!(position.amount, 0f64);
+        assert_eq!(position.income, 250f64);
+
+        position
+            .remove_income_event(0)
+            .expect("Remove dividend event");
+        assert_eq!(position.income, 200f64);
+    }
+
+    #[test]
+    fn test_position_corporate_actions() {
+        let mut position = mock_position();
+
+        position.add_corporate_action(CorporateAction {
+            id: 0,
+            kind: CorporateActionKind::Split(10f64),
+            after_order_id: 0,
+            date: Local::now(),
+        });
+
+        assert_eq!(position.amount, 100f64);
+        assert_eq!(position.avg_price, 10f64);
+        assert_eq!(position.avg_value, 1000f64);
+        assert_eq!(position.get_split_factor_after(0), 10f64);
+
+        // Sell half of the position in new units
+        position.add_order(Order::new(&position, Action::Short, 50f64, 750f64));
+        assert_eq!(position.amount, 50f64);
+        assert_eq!(position.income, 250f64);
+        assert_eq!(position.get_split_factor_after(1), 1f64);
+
+        position.add_corporate_action(CorporateAction {
+            id: 1,
+            kind: CorporateActionKind::ReverseSplit(5f64),
+            after_order_id: 1,
+            date: Local::now(),
+        });
+        position.add_corporate_action(CorporateAction {
+            id: 2,
+            kind: CorporateActionKind::Rename {
+                old_name: String::from("MOCK"),
+                new_name: String::from("NEW"),
+            },
+            after_order_id: 1,
+            date: Local::now(),
+        });
+
+        assert_eq!(position.name, "NEW");
+        assert_eq!(position.amount, 10f64);
+        assert_eq!(position.avg_price, 50f64);
+        assert_eq!(position.get_split_factor_after(0), 2f64);
+
+        // Original orders are kept intact
+        assert_eq!(position.orders[0].amount, 10f64);
+        assert_eq!(position.orders[0].price, 100f64);
+
+        // Split after the first order changes its average price, but not the following ones
+        let avg_prices: Vec<f64> = position
+            .get_avg_price_history()
+            .into_iter()
+            .map(|(_, avg_price)| avg_price)
+            .collect();
+        assert_eq!(avg_prices, vec![10f64, 50f64]);
+
+        // Sell order closes split units, so removing the split is rejected
+        assert!(position.remove_corporate_action(0).is_err());
+        assert_eq!(position.corporate_actions.len(), 3);
+        assert_eq!(position.amount, 10f64);
+
+        position.remove_corporate_action(2).expect("Remove rename");
+        assert_eq!(position.name, "MOCK");
+    }
+
+    #[test]
+    fn test_short_position() {
+        let mut position = Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Short,
+                amount: 10f64,
+                value: 1000f64,
+                price: 100f64,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+
+        // Partial cover below entry price is profitable for short position
+        position.add_order(Order::new(&position, Action::Long, 4f64, 320f64));
+        assert_eq!(position.orders[1].income, 80f64);
+        assert_eq!(position.amount, 6f64);
+        assert_eq!(position.avg_price, 100f64);
+        assert_eq!(position.avg_value, 600f64);
+        assert_eq!(position.income, 80f64);
+
+        // Adding to short position averages entry price
+        position.add_order(Order::new(&position, Action::Short, 4f64, 200f64));
+        assert_eq!(position.amount, 10f64);
+        assert_eq!(position.avg_price, 80f64);
+
+        // Covering above entry price is a loss
+        position.add_order(Order::new(&position, Action::Long, 10f64, 1000f64));
+        assert_eq!(position.amount, 0f64);
+        assert_eq!(position.income, -120f64);
+        assert_eq!(position.calculate_income_percent(), -10f64);
+    }
+
+    #[test]
+    fn test_position_float_leftover() {
+        let mut position = Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 0.3f64,
+                value: 30f64,
+                price: 100f64,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+        position.add_order(Order::new(&position, Action::Short, 0.1f64, 10f64));
+
+        // 0.3 - 0.1 is slightly less than 0.2, which is still allowed to close the rest
+        assert!(position.amount < 0.2f64);
+        assert!(position
+            .validate_order(Action::Short, 0.2f64, 20f64)
+            .is_ok());
+        assert!(position
+            .split_over_close(Action::Short, 0.2f64, 20f64)
+            .is_none());
+
+        position.add_order(Order::new(&position, Action::Short, 0.2f64, 20f64));
+        assert!(position.amount != 0f64);
+        assert!(position.is_closed());
+    }
+
+    #[test]
+    fn test_position_split_over_close() {
+        let position = mock_position();
+
+        assert!(position
+            .split_over_close(Action::Long, 20f64, 2000f64)
+            .is_none());
+        assert!(position
+            .split_over_close(Action::Short, 10f64, 1200f64)
+            .is_none());
+
+        // Selling 15 of 10 closes the position and leaves 5 for new short position
+        let (closing_order, rest_amount, rest_value) = position
+            .split_over_close(Action::Short, 15f64, 1800f64)
+            .expect("Split over close order");
+
+        assert_eq!(closing_order.id, 1);
+        assert_eq!(closing_order.action, Action::Short);
+        assert_eq!(closing_order.amount, 10f64);
+        assert_eq!(closing_order.value, 1200f64);
+        assert_eq!(closing_order.income, 200f64);
+        assert_eq!(rest_amount, 5f64);
+        assert_eq!(rest_value, 600f64);
+
+        let mut closed_position = position.clone();
+        closed_position.add_order(closing_order);
+        assert!(closed_position.is_closed());
+        assert_eq!(closed_position.income, 200f64);
+
+        let flipped_position = Position::new(
+            1,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Short,
+                amount: rest_amount,
+                value: rest_value,
+                price: rest_value / rest_amount,
+                income: 0f64,
+                date: Local::now(),
+            }],
+        );
+        assert_eq!(flipped_position.action, Action::Short);
+        assert_eq!(flipped_position.amount, 5f64);
+        assert_eq!(flipped_position.avg_price, 120f64);
+    }
+
+    #[test]
+    fn test_position_time_based_returns() {
+        let now = Local::now();
+        let year_ago = now - Duration::seconds((365.25 * 24f64 * 3600f64) as i64);
+        let mut position = Position::new(
+            0,
+            String::from("MOCK"),
+            vec![Order {
+                id: 0,
+                action: Action::Long,
+                amount: 10f64,
+                value: 1000f64,
+                price: 100f64,
+                income: 0f64,
+                date: year_ago,
+            }],
+        );
+        assert!((position.calculate_xirr(now).unwrap()).abs() < 1e-6);
+
+        let mut order = Order::new(&position, Action::Short, 10f64, 1100f64);
+        order.date = now;
+        position.add_order(order);
+
+        // 10% earned in exactly one year
+        assert!((position.calculate_annualized_return(now).unwrap() - 10f64).abs() < 1e-6);
+        assert!((position.calculate_xirr(now).unwrap() - 10f64).abs() < 1e-6);
+
+        // Position opened just now has no meaningful yearly return
+        let fresh = Position::new(1, String::from("MOCK"), vec![Order { date: now, ..order }]);
+        assert_eq!(fresh.calculate_annualized_return(now), None);
+    }
+
+    #[test]
+    fn test_position_simulation() {
+        let position = mock_position();
+
+        let (simulated, order) = position.simulate_order(Action::Short, 4f64, 150f64);
+        assert_eq!(order.income, 200f64);
+        assert_eq!(simulated.amount, 6f64);
+        assert_eq!(simulated.calculate_income_percent(), 20f64);
+        assert_eq!(position.amount, 10f64);
+        assert_eq!(position.orders.len(), 1);
+
+        // 30% of 1000 invested: 200 is earned already, 100 more on 6 units left
+        let price = simulated.calculate_price_for_income_percent(30f64).unwrap();
+        assert!((price - (100f64 + 100f64 / 6f64)).abs() < 1e-9);
+        let (closed, _) = simulated.simulate_order(Action::Short, 6f64, price);
+        assert!((closed.calculate_income_percent() - 30f64).abs() < 1e-9);
+
+        // Loss is never deep enough to need non-positive price
+        assert_eq!(position.calculate_price_for_income_percent(-100f64), None);
+    }
+
+    #[test]
+    fn test_position_break_even_and_averaging() {
+        let mut position = mock_position();
+        assert_eq!(position.calculate_break_even_price(), Some(100f64));
+
+        // 5 units sold with 100 loss, rest has to be sold 20 higher to cover it
+        position.add_order(Order::new(&position, Action::Short, 5f64, 400f64));
+        assert_eq!(position.calculate_break_even_price(), Some(120f64));
+
+        // Buying 5 more at 70 makes average (500 + 350) / 10 = 85
+        let amount = position.calculate_amount_to_average(70f64, 85f64).unwrap();
+        assert!((amount - 5f64).abs() < 1e-9);
+        let (averaged, _) = position.simulate_order(Action::Long, amount, 70f64);
+        assert!((averaged.avg_price - 85f64).abs() < 1e-9);
+
+        assert_eq!(position.calculate_amount_to_average(110f64, 85f64), None);
+        assert_eq!(position.calculate_amount_to_average(70f64, 60f64), None);
+    }
+
+    #[test]
+    fn test_position_triggered_levels() {
+        let mut position = mock_position();
+        position.stop_loss = Some(90f64);
+        position.take_profit = Some(120f64);
+        assert_eq!(position.get_triggered_level(), None);
+
+        position.set_market_price(100f64);
+        assert_eq!(position.get_triggered_level(), None);
+        position.set_market_price(85f64);
+        assert_eq!(position.get_triggered_level(), Some(PriceLevel::StopLoss));
+        position.set_market_price(120f64);
+        assert_eq!(position.get_triggered_level(), Some(PriceLevel::TakeProfit));
+
+        // Short position earns when price falls, so levels are mirrored
+        position.action = Action::Short;
+        position.stop_loss = Some(110f64);
+        position.take_profit = Some(80f64);
+        assert_eq!(position.get_triggered_level(), Some(PriceLevel::StopLoss));
+        position.set_market_price(75f64);
+        assert_eq!(position.get_triggered_level(), Some(PriceLevel::TakeProfit));
+    }
 }
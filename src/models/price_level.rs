@@ -0,0 +1,19 @@
+use std::fmt;
+
+use crate::i18n::t;
+
+/// Price level of position, which triggers an alert when market price crosses it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceLevel {
+    StopLoss,
+    TakeProfit,
+}
+
+impl fmt::Display for PriceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StopLoss => write!(f, "{}", t("label.stop_loss")),
+            Self::TakeProfit => write!(f, "{}", t("label.take_profit")),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::i18n::t;
+use crate::tf;
+
+/// Reason, why order or position data is rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    NotFinite { field: &'static str },
+    NotPositive { field: &'static str, value: f64 },
+    EmptyName,
+    OverClose { requested: f64, available: f64 },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFinite { field } => {
+                write!(f, "{}", tf!("error.not_finite", field_name(field)))
+            }
+            Self::NotPositive { field, value } => {
+                write!(f, "{}", tf!("error.not_positive", field_name(field), value))
+            }
+            Self::EmptyName => write!(f, "{}", t("error.empty_name")),
+            Self::OverClose {
+                requested,
+                available,
+            } => write!(f, "{}", tf!("error.exceeds_amount", requested, available)),
+        }
+    }
+}
+
+// Fields are named in messages of the current language
+fn field_name(field: &str) -> String {
+    match field {
+        "amount" => t("field.amount"),
+        "value" => t("field.value"),
+        "price" => t("field.price"),
+        "avg price" => t("field.avg_price"),
+        "weight" => t("field.weight"),
+        "cash" => t("field.cash"),
+        "units" => t("field.units"),
+        field => field.to_string(),
+    }
+}
+
+pub fn validate_positive(field: &'static str, value: f64) -> Result<(), ValidationError> {
+    if !value.is_finite() {
+        return Err(ValidationError::NotFinite { field });
+    }
+
+    if value <= 0f64 {
+        return Err(ValidationError::NotPositive { field, value });
+    }
+
+    Ok(())
+}
+
+pub fn validate_order_values(amount: f64, value: f64) -> Result<(), ValidationError> {
+    validate_positive("amount", amount)?;
+    validate_positive("value", value)
+}
+
+pub fn validate_name(name: &str) -> Result<(), ValidationError> {
+    if name.trim().is_empty() {
+        return Err(ValidationError::EmptyName);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_name, validate_order_values, ValidationError};
+
+    #[test]
+    fn test_validate_order_values() {
+        assert_eq!(validate_order_values(1f64, 100f64), Ok(()));
+        assert_eq!(
+            validate_order_values(0f64, 100f64),
+            Err(ValidationError::NotPositive {
+                field: "amount",
+                value: 0f64
+            })
+        );
+        assert_eq!(
+            validate_order_values(1f64, -5f64),
+            Err(ValidationError::NotPositive {
+                field: "value",
+                value: -5f64
+            })
+        );
+        assert_eq!(
+            validate_order_values(f64::NAN, 100f64),
+            Err(ValidationError::NotFinite { field: "amount" })
+        );
+        assert_eq!(
+            validate_order_values(1f64, f64::INFINITY),
+            Err(ValidationError::NotFinite { field: "value" })
+        );
+        assert_eq!(validate_name("  "), Err(ValidationError::EmptyName));
+    }
+}
@@ -1,10 +1,14 @@
 pub mod model;
 
 use serde_json::json;
+use std::collections::BTreeMap;
 use std::{fs, path::Path};
 
-use model::Options;
+use model::{Options, OverCloseBehavior, TablesOptions};
 
+use crate::i18n::{t, Language};
+use crate::utils::locale::Locale;
+use crate::utils::theme::Theme;
 use crate::{constants::OPTIONS_FILE_PATH, exit_with_error};
 
 pub fn initialize_options() -> Result<(), String> {
@@ -17,23 +21,35 @@ pub fn initialize_options() -> Result<(), String> {
         orders_per_page: 10,
         hide_closed_positions: false,
         storage_file_path: String::from("./storage.json"),
+        over_close_behavior: OverCloseBehavior::Reject,
+        optional_columns: vec![],
+        long_term_holding_days: 365,
+        income_target_percent: 10f64,
+        aliases: BTreeMap::new(),
+        macros: BTreeMap::new(),
+        tables: TablesOptions::default(),
+        locale: Locale::default(),
+        language: Language::default(),
+        theme: Theme::default(),
+        plain_output: false,
+        show_dashboard_on_startup: false,
     })
     .to_string();
 
     match fs::write(OPTIONS_FILE_PATH, default_options_contents) {
         Ok(_) => Ok(()),
-        Err(_) => Err(String::from("Failed to write initial storage file")),
+        Err(_) => Err(t("error.write_initial_options")),
     }
 }
 
 pub fn get_options() -> Options {
     let file_content = match std::fs::read_to_string(OPTIONS_FILE_PATH) {
         Ok(content) => content,
-        Err(_) => exit_with_error(String::from("Failed to read options file")),
+        Err(_) => exit_with_error(t("error.read_options")),
     };
 
     match serde_json::from_str::<Options>(&file_content) {
         Ok(options_value) => options_value,
-        Err(_) => exit_with_error(String::from("Failed to deserialize options")),
+        Err(_) => exit_with_error(t("error.deserialize_options")),
     }
 }
@@ -1,9 +1,240 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::Language;
+use crate::utils::locale::Locale;
+use crate::utils::theme::Theme;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Options {
     pub positions_per_page: i32,
     pub hide_closed_positions: bool,
     pub orders_per_page: i32,
     pub storage_file_path: String,
+
+    #[serde(default)]
+    pub over_close_behavior: OverCloseBehavior,
+
+    // Added after default columns of positions table, unless columns are set in `tables`
+    #[serde(default)]
+    pub optional_columns: Vec<PositionColumn>,
+
+    // Disposals of lots held longer than this are long-term in tax report
+    #[serde(default = "default::long_term_holding_days")]
+    pub long_term_holding_days: i64,
+
+    // Income percent, for which target price of open positions is calculated
+    #[serde(default = "default::income_target_percent")]
+    pub income_target_percent: f64,
+
+    // Names of commands with preset arguments, e.g. "buy": "a --type long"
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+
+    // Names of command sequences, e.g. "weekly": ["r month", "alloc"]
+    #[serde(default)]
+    pub macros: BTreeMap<String, Vec<String>>,
+
+    #[serde(default)]
+    pub tables: TablesOptions,
+
+    // Separators of numbers in input and output, format of dates and first day of week
+    #[serde(default)]
+    pub locale: Locale,
+
+    // Language of help pages, prompts and messages
+    #[serde(default)]
+    pub language: Language,
+
+    // Colors of text and table cells, "dark", "light" or "monochrome"
+    #[serde(default)]
+    pub theme: Theme,
+
+    // Skips clearing screen and table borders, e.g. for logging sessions
+    #[serde(default)]
+    pub plain_output: bool,
+
+    // Dashboard is shown before positions when app is started
+    #[serde(default)]
+    pub show_dashboard_on_startup: bool,
+}
+
+impl Options {
+    pub fn get_position_columns(&self) -> Vec<PositionColumn> {
+        match &self.tables.position_columns {
+            Some(columns) => columns.clone(),
+            None => DEFAULT_POSITION_COLUMNS
+                .iter()
+                .chain(self.optional_columns.iter())
+                .copied()
+                .collect(),
+        }
+    }
+
+    pub fn get_order_columns(&self) -> Vec<OrderColumn> {
+        match &self.tables.order_columns {
+            Some(columns) => columns.clone(),
+            None => DEFAULT_ORDER_COLUMNS.to_vec(),
+        }
+    }
+}
+
+mod default {
+    pub fn long_term_holding_days() -> i64 {
+        365
+    }
+
+    pub fn income_target_percent() -> f64 {
+        10f64
+    }
+}
+
+// What to do with order, which closes more than position amount
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverCloseBehavior {
+    #[default]
+    Reject,
+    // Close position and open a new one of opposite direction with the rest of the order
+    Flip,
+}
+
+/// Columns of positions and orders tables and formatting of their numbers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TablesOptions {
+    #[serde(default)]
+    pub position_columns: Option<Vec<PositionColumn>>,
+
+    #[serde(default)]
+    pub order_columns: Option<Vec<OrderColumn>>,
+
+    // Used for columns, which don't have their own rule
+    #[serde(default)]
+    pub default_precision: Precision,
+
+    // Rules by column name, e.g. "amount": {"decimals": 4}
+    #[serde(default)]
+    pub precision: BTreeMap<String, Precision>,
+
+    #[serde(default)]
+    pub thousands_separator: bool,
+
+    // Added before values, prices and incomes, e.g. "$"
+    #[serde(default)]
+    pub currency_symbol: String,
+}
+
+impl TablesOptions {
+    pub fn get_precision(&self, column: &str) -> Precision {
+        self.precision
+            .get(column)
+            .copied()
+            .unwrap_or(self.default_precision)
+    }
+}
+
+// How many digits of numbers are shown
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Precision {
+    // 0 decimals for numbers above 1000 and 4 significant decimals for others
+    #[default]
+    Auto,
+    Decimals(u32),
+    Significant(u32),
+}
+
+pub const DEFAULT_POSITION_COLUMNS: [PositionColumn; 9] = [
+    PositionColumn::Id,
+    PositionColumn::Name,
+    PositionColumn::Amount,
+    PositionColumn::AvgValue,
+    PositionColumn::AvgPrice,
+    PositionColumn::Income,
+    PositionColumn::Payouts,
+    PositionColumn::IncomePercent,
+    PositionColumn::Status,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionColumn {
+    Id,
+    Name,
+    Amount,
+    AvgValue,
+    AvgPrice,
+    Income,
+    Payouts,
+    IncomePercent,
+    Status,
+    AnnualizedReturn,
+    Xirr,
+    BreakEven,
+    TargetPrice,
+}
+
+impl PositionColumn {
+    // Same as in options, used as key of precision rules
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::Amount => "amount",
+            Self::AvgValue => "avg_value",
+            Self::AvgPrice => "avg_price",
+            Self::Income => "income",
+            Self::Payouts => "payouts",
+            Self::IncomePercent => "income_percent",
+            Self::Status => "status",
+            Self::AnnualizedReturn => "annualized_return",
+            Self::Xirr => "xirr",
+            Self::BreakEven => "break_even",
+            Self::TargetPrice => "target_price",
+        }
+    }
+}
+
+pub const DEFAULT_ORDER_COLUMNS: [OrderColumn; 9] = [
+    OrderColumn::Id,
+    OrderColumn::Date,
+    OrderColumn::Type,
+    OrderColumn::Amount,
+    OrderColumn::Value,
+    OrderColumn::Price,
+    OrderColumn::Income,
+    OrderColumn::AdjustedAmount,
+    OrderColumn::AdjustedPrice,
+];
+
+// Adjusted columns are shown only for positions with splits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderColumn {
+    Id,
+    Date,
+    Type,
+    Amount,
+    Value,
+    Price,
+    Income,
+    AdjustedAmount,
+    AdjustedPrice,
+}
+
+impl OrderColumn {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Date => "date",
+            Self::Type => "type",
+            Self::Amount => "amount",
+            Self::Value => "value",
+            Self::Price => "price",
+            Self::Income => "income",
+            Self::AdjustedAmount => "adjusted_amount",
+            Self::AdjustedPrice => "adjusted_price",
+        }
+    }
 }
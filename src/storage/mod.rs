@@ -1,23 +1,39 @@
 pub mod models;
+mod portfolios;
 mod positions;
 
 use models::storage::StorageModel;
 use models::{FromModel, ToModel};
+pub use portfolios::{load_portfolios, update_current_portfolio, NO_PORTFOLIO_SELECTED_ERROR};
 pub use positions::{load_positions, save_position, save_positions};
 
 use std::path::Path;
 
-use crate::commands::utils::sorting::SortBy;
-use crate::models::Position;
+use crate::i18n::t;
+use crate::models::Portfolio;
 use crate::options::get_options;
 
 pub struct Storage {
-    pub sort_positions_by: SortBy,
-    pub move_closed_positions_to_bottom: bool,
-    pub positions: Vec<Position>,
+    // None means that all accounts view is selected
+    pub current_portfolio_id: Option<i32>,
+    pub portfolios: Vec<Portfolio>,
 }
 
-const DEFAULT_STORAGE_FILE_CONTENT: &str = "{ \"positions\": [], \"sort_positions_by\": { \"LastChange\": \"A\" }, \"move_closed_positions_to_bottom\": false }";
+impl Storage {
+    pub fn current_portfolio(&self) -> Option<&Portfolio> {
+        let id = self.current_portfolio_id?;
+        self.portfolios.iter().find(|portfolio| portfolio.id == id)
+    }
+
+    pub fn current_portfolio_mut(&mut self) -> Option<&mut Portfolio> {
+        let id = self.current_portfolio_id?;
+        self.portfolios
+            .iter_mut()
+            .find(|portfolio| portfolio.id == id)
+    }
+}
+
+const DEFAULT_STORAGE_FILE_CONTENT: &str = "{ \"current_portfolio\": 0, \"portfolios\": [{ \"id\": 0, \"name\": \"Main\", \"positions\": [], \"sort_positions_by\": { \"LastChange\": \"A\" } }] }";
 
 pub fn initialize_storage() -> Result<(), String> {
     let storage_file_path = get_options().storage_file_path;
@@ -28,7 +44,7 @@ pub fn initialize_storage() -> Result<(), String> {
 
     match std::fs::write(storage_file_path, DEFAULT_STORAGE_FILE_CONTENT) {
         Ok(_) => Ok(()),
-        Err(_) => Err(String::from("Failed to write initial storage file")),
+        Err(_) => Err(t("error.write_initial_storage")),
     }
 }
 
@@ -37,7 +53,7 @@ pub fn reinitialize_storage() -> Result<(), String> {
 
     match std::fs::write(storage_file_path, DEFAULT_STORAGE_FILE_CONTENT) {
         Ok(_) => Ok(()),
-        Err(_) => Err(String::from("Failed to write initial storage file")),
+        Err(_) => Err(t("error.write_initial_storage")),
     }
 }
 
@@ -46,12 +62,12 @@ pub fn load_storage() -> Result<Storage, String> {
 
     let file_content = match std::fs::read_to_string(storage_file_path) {
         Ok(content) => content,
-        Err(_) => return Err(String::from("Failed to read storage file")),
+        Err(_) => return Err(t("error.read_storage")),
     };
 
     let storage_model = match serde_json::from_str::<StorageModel>(&file_content) {
         Ok(data) => data,
-        Err(_) => return Err(String::from("Failed to deserialize storage data")),
+        Err(_) => return Err(t("error.deserialize_storage")),
     };
 
     storage_model.to_model()
@@ -59,23 +75,20 @@ pub fn load_storage() -> Result<Storage, String> {
 
 pub fn update_storage<F>(func: F) -> Result<(), String>
 where
-    F: Fn(&mut Storage) -> (),
+    F: Fn(&mut Storage),
 {
-    let mut storage = match load_storage() {
-        Ok(storage) => storage,
-        Err(error) => return Err(error),
-    };
+    let mut storage = load_storage()?;
 
     func(&mut storage);
 
     let json_string = match serde_json::to_string(&StorageModel::from_model(storage)) {
         Ok(json) => json,
-        Err(_) => return Err(String::from("Failed to serialize positions to json")),
+        Err(_) => return Err(t("error.serialize_storage")),
     };
 
     let storage_file_path = get_options().storage_file_path;
     match std::fs::write(storage_file_path, json_string) {
         Ok(_) => Ok(()),
-        Err(_) => Err(String::from("Failed to save positions to storage file")),
+        Err(_) => Err(t("error.write_storage")),
     }
 }
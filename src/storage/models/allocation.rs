@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::allocation::{AllocationKey, TargetWeight};
+
+use super::{FromModel, ToModel};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AllocationKeyStorageModel {
+    N(String),
+    T(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetWeightStorageModel {
+    pub key: AllocationKeyStorageModel,
+    pub percent: f64,
+}
+
+impl ToModel<TargetWeight> for TargetWeightStorageModel {
+    fn to_model(&self) -> Result<TargetWeight, String> {
+        Ok(TargetWeight {
+            key: match &self.key {
+                AllocationKeyStorageModel::N(name) => AllocationKey::Name(name.clone()),
+                AllocationKeyStorageModel::T(tag) => AllocationKey::Tag(tag.clone()),
+            },
+            percent: self.percent,
+        })
+    }
+}
+
+impl FromModel<TargetWeight> for TargetWeightStorageModel {
+    fn from_model(model: TargetWeight) -> Self {
+        Self {
+            key: match model.key {
+                AllocationKey::Name(name) => AllocationKeyStorageModel::N(name),
+                AllocationKey::Tag(tag) => AllocationKeyStorageModel::T(tag),
+            },
+            percent: model.percent,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CashEntry, CashEntryKind};
+
+use super::{FromModel, ToModel};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CashEntryKindStorageModel {
+    D,
+    W,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashEntryStorageModel {
+    pub id: i32,
+    pub kind: CashEntryKindStorageModel,
+    pub amount: f64,
+    pub date: DateTime<Local>,
+}
+
+impl ToModel<CashEntry> for CashEntryStorageModel {
+    fn to_model(&self) -> Result<CashEntry, String> {
+        Ok(CashEntry {
+            id: self.id,
+            kind: match self.kind {
+                CashEntryKindStorageModel::D => CashEntryKind::Deposit,
+                CashEntryKindStorageModel::W => CashEntryKind::Withdrawal,
+            },
+            amount: self.amount,
+            date: self.date,
+        })
+    }
+}
+
+impl FromModel<CashEntry> for CashEntryStorageModel {
+    fn from_model(model: CashEntry) -> Self {
+        Self {
+            id: model.id,
+            kind: match model.kind {
+                CashEntryKind::Deposit => CashEntryKindStorageModel::D,
+                CashEntryKind::Withdrawal => CashEntryKindStorageModel::W,
+            },
+            amount: model.amount,
+            date: model.date,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CorporateAction, CorporateActionKind};
+use crate::tf;
+
+use super::{FromModel, ToModel};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CorporateActionKindStorageModel {
+    S(f64),
+    R(f64),
+    N { from: String, to: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorporateActionStorageModel {
+    pub id: i32,
+    pub kind: CorporateActionKindStorageModel,
+    pub after_order_id: i32,
+    pub date: DateTime<Local>,
+}
+
+impl ToModel<CorporateAction> for CorporateActionStorageModel {
+    fn to_model(&self) -> Result<CorporateAction, String> {
+        let kind = match &self.kind {
+            CorporateActionKindStorageModel::S(ratio) => CorporateActionKind::Split(*ratio),
+            CorporateActionKindStorageModel::R(ratio) => CorporateActionKind::ReverseSplit(*ratio),
+            CorporateActionKindStorageModel::N { from, to } => CorporateActionKind::Rename {
+                old_name: from.clone(),
+                new_name: to.clone(),
+            },
+        };
+
+        if let CorporateActionKind::Split(ratio) | CorporateActionKind::ReverseSplit(ratio) = kind {
+            if !ratio.is_finite() || ratio <= 0f64 {
+                return Err(tf!("error.invalid_stored_split_ratio", self.id, ratio));
+            }
+        }
+
+        Ok(CorporateAction {
+            id: self.id,
+            kind,
+            after_order_id: self.after_order_id,
+            date: self.date,
+        })
+    }
+}
+
+impl FromModel<CorporateAction> for CorporateActionStorageModel {
+    fn from_model(model: CorporateAction) -> Self {
+        Self {
+            id: model.id,
+            kind: match model.kind {
+                CorporateActionKind::Split(ratio) => CorporateActionKindStorageModel::S(ratio),
+                CorporateActionKind::ReverseSplit(ratio) => {
+                    CorporateActionKindStorageModel::R(ratio)
+                }
+                CorporateActionKind::Rename { old_name, new_name } => {
+                    CorporateActionKindStorageModel::N {
+                        from: old_name,
+                        to: new_name,
+                    }
+                }
+            },
+            after_order_id: model.after_order_id,
+            date: model.date,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{IncomeEvent, IncomeEventKind};
+
+use super::{FromModel, ToModel};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncomeEventKindStorageModel {
+    D,
+    I,
+    R,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeEventStorageModel {
+    pub id: i32,
+    pub kind: IncomeEventKindStorageModel,
+    pub after_order_id: i32,
+
+    #[serde(default)]
+    pub cash: f64,
+
+    #[serde(default)]
+    pub units: f64,
+
+    pub date: DateTime<Local>,
+}
+
+impl ToModel<IncomeEvent> for IncomeEventStorageModel {
+    fn to_model(&self) -> Result<IncomeEvent, String> {
+        Ok(IncomeEvent {
+            id: self.id,
+            kind: match self.kind {
+                IncomeEventKindStorageModel::D => IncomeEventKind::Dividend,
+                IncomeEventKindStorageModel::I => IncomeEventKind::Interest,
+                IncomeEventKindStorageModel::R => IncomeEventKind::Reward,
+            },
+            after_order_id: self.after_order_id,
+            cash: self.cash,
+            units: self.units,
+            date: self.date,
+        })
+    }
+}
+
+impl FromModel<IncomeEvent> for IncomeEventStorageModel {
+    fn from_model(model: IncomeEvent) -> Self {
+        Self {
+            id: model.id,
+            kind: match model.kind {
+                IncomeEventKind::Dividend => IncomeEventKindStorageModel::D,
+                IncomeEventKind::Interest => IncomeEventKindStorageModel::I,
+                IncomeEventKind::Reward => IncomeEventKindStorageModel::R,
+            },
+            after_order_id: model.after_order_id,
+            cash: model.cash,
+            units: model.units,
+            date: model.date,
+        }
+    }
+}
@@ -1,11 +1,21 @@
 pub mod action;
+pub mod allocation;
+pub mod cash;
+pub mod corporate_action;
+pub mod income_event;
 pub mod order;
+pub mod portfolio;
 pub mod position;
 pub mod sort_by;
 pub mod storage;
 
 pub use action::ActionStorageModel;
+pub use allocation::TargetWeightStorageModel;
+pub use cash::CashEntryStorageModel;
+pub use corporate_action::CorporateActionStorageModel;
+pub use income_event::IncomeEventStorageModel;
 pub use order::OrderStorageModel;
+pub use portfolio::PortfolioStorageModel;
 pub use position::PositionStorageModel;
 
 pub trait ToModel<T> {
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
 use crate::models::Order;
@@ -10,6 +11,10 @@ pub struct OrderStorageModel {
     pub action: ActionStorageModel,
     pub amount: f64,
     pub value: f64,
+
+    // Files saved before order dates were tracked don't have them, position dates are used
+    #[serde(default)]
+    pub date: Option<DateTime<Local>>,
 }
 
 impl FromModel<Order> for OrderStorageModel {
@@ -19,6 +24,7 @@ impl FromModel<Order> for OrderStorageModel {
             action: ActionStorageModel::from_model(model.action),
             amount: model.amount,
             value: model.value,
+            date: Some(model.date),
         }
     }
 }
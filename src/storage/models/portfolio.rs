@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Portfolio;
+
+use super::sort_by::SortByStorageModel;
+use super::{
+    CashEntryStorageModel, FromModel, PositionStorageModel, TargetWeightStorageModel, ToModel,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioStorageModel {
+    pub id: i32,
+    pub name: String,
+
+    #[serde(default)]
+    pub sort_positions_by: SortByStorageModel,
+
+    #[serde(default)]
+    pub then_sort_positions_by: Vec<SortByStorageModel>,
+
+    #[serde(default)]
+    pub move_closed_to_bottom: bool,
+
+    #[serde(default)]
+    pub positions_filter: Option<String>,
+
+    #[serde(default)]
+    pub cash_entries: Vec<CashEntryStorageModel>,
+
+    #[serde(default)]
+    pub allow_margin: bool,
+
+    #[serde(default)]
+    pub target_weights: Vec<TargetWeightStorageModel>,
+
+    pub positions: Vec<PositionStorageModel>,
+}
+
+impl FromModel<Portfolio> for PortfolioStorageModel {
+    fn from_model(model: Portfolio) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            sort_positions_by: SortByStorageModel::from_model(model.sort_positions_by),
+            then_sort_positions_by: model
+                .then_sort_positions_by
+                .into_iter()
+                .map(SortByStorageModel::from_model)
+                .collect(),
+            move_closed_to_bottom: model.move_closed_positions_to_bottom,
+            positions_filter: model.positions_filter,
+            cash_entries: model
+                .cash_entries
+                .into_iter()
+                .map(CashEntryStorageModel::from_model)
+                .collect(),
+            allow_margin: model.allow_margin,
+            target_weights: model
+                .target_weights
+                .into_iter()
+                .map(TargetWeightStorageModel::from_model)
+                .collect(),
+            positions: model
+                .positions
+                .into_iter()
+                .map(PositionStorageModel::from_model)
+                .collect(),
+        }
+    }
+}
+
+impl ToModel<Portfolio> for PortfolioStorageModel {
+    fn to_model(&self) -> Result<Portfolio, String> {
+        let mut positions = vec![];
+        for pos_model in &self.positions {
+            positions.push(pos_model.to_model()?);
+        }
+
+        let mut then_sort_positions_by = vec![];
+        for sort_by_model in &self.then_sort_positions_by {
+            then_sort_positions_by.push(sort_by_model.to_model()?);
+        }
+
+        let mut target_weights = vec![];
+        for weight_model in &self.target_weights {
+            target_weights.push(weight_model.to_model()?);
+        }
+
+        let mut cash_entries = vec![];
+        for entry_model in &self.cash_entries {
+            cash_entries.push(entry_model.to_model()?);
+        }
+
+        Ok(Portfolio {
+            id: self.id,
+            name: self.name.clone(),
+            sort_positions_by: self.sort_positions_by.to_model()?,
+            then_sort_positions_by,
+            move_closed_positions_to_bottom: self.move_closed_to_bottom,
+            positions_filter: self.positions_filter.clone(),
+            cash_entries,
+            allow_margin: self.allow_margin,
+            target_weights,
+            positions,
+        })
+    }
+}
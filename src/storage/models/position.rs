@@ -2,8 +2,12 @@ use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
 use crate::models::{Order, Position};
+use crate::tf;
 
-use super::{ActionStorageModel, FromModel, OrderStorageModel, ToModel};
+use super::{
+    ActionStorageModel, CorporateActionStorageModel, FromModel, IncomeEventStorageModel,
+    OrderStorageModel, ToModel,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PositionStorageModel {
@@ -14,51 +18,82 @@ pub struct PositionStorageModel {
 
     #[serde(default = "Local::now")]
     pub edited_at: DateTime<Local>,
+
+    // Files saved before creation date was tracked don't have it, last edit date is used instead
+    #[serde(default)]
+    pub created_at: Option<DateTime<Local>>,
+
+    #[serde(default)]
+    pub income_events: Vec<IncomeEventStorageModel>,
+
+    #[serde(default)]
+    pub corporate_actions: Vec<CorporateActionStorageModel>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub stop_loss: Option<f64>,
+
+    #[serde(default)]
+    pub take_profit: Option<f64>,
+
+    #[serde(default)]
+    pub market_price: Option<f64>,
+
+    #[serde(default)]
+    pub market_price_updated_at: Option<DateTime<Local>>,
 }
 
 impl ToModel<Position> for PositionStorageModel {
     fn to_model(&self) -> Result<Position, String> {
         let mut orders = self.orders.to_vec();
-        orders.sort_by(|first, second| first.id.cmp(&second.id));
+        orders.sort_by_key(|order| order.id);
 
         let first_order_model = match orders.first() {
             Some(model) => model,
-            None => {
-                return Err(format!(
-                    "Failed to parse position {} orders, perhaps it's empty",
-                    self.id
-                ))
-            }
+            None => return Err(tf!("error.empty_position_orders", self.id)),
         };
+        let created_at = self.created_at.unwrap_or(self.edited_at);
         let first_order = Order {
             id: first_order_model.id,
-            action: match first_order_model.action.to_model() {
-                Ok(action) => action,
-                Err(error) => return Err(error),
-            },
+            action: first_order_model.action.to_model()?,
             amount: first_order_model.amount,
             value: first_order_model.value,
             price: first_order_model.value / first_order_model.amount,
             income: 0f64,
+            date: first_order_model.date.unwrap_or(created_at),
         };
 
         let mut pos = Position::new(self.id, self.name.clone(), vec![first_order]);
         pos.edited_at = self.edited_at;
+        pos.created_at = created_at;
 
         orders.remove(0);
         for order_models in orders {
-            let action = match order_models.action.to_model() {
-                Ok(action) => action,
-                Err(error) => return Err(error),
-            };
-
-            pos.add_order(Order::new(
-                &pos,
-                action,
-                order_models.amount,
-                order_models.value,
-            ));
+            let action = order_models.action.to_model()?;
+
+            // Stored ids are kept, as income events and corporate actions are anchored to them
+            let mut order = Order::new(&pos, action, order_models.amount, order_models.value);
+            order.id = order_models.id;
+            order.date = order_models.date.unwrap_or(self.edited_at);
+            pos.add_order(order);
+        }
+
+        for event_model in &self.income_events {
+            pos.add_income_event(event_model.to_model()?);
+        }
+
+        // Name is stored already renamed, so it's restored after renames are applied
+        for action_model in &self.corporate_actions {
+            pos.add_corporate_action(action_model.to_model()?);
         }
+        pos.name = self.name.clone();
+        pos.tags = self.tags.clone();
+        pos.stop_loss = self.stop_loss;
+        pos.take_profit = self.take_profit;
+        pos.market_price = self.market_price;
+        pos.market_price_updated_at = self.market_price_updated_at;
 
         Ok(pos)
     }
@@ -74,9 +109,25 @@ impl FromModel<Position> for PositionStorageModel {
         PositionStorageModel {
             id: model.id,
             edited_at: model.edited_at,
+            created_at: Some(model.created_at),
             action: ActionStorageModel::from_model(model.action),
             name: model.name,
             orders: order_models,
+            income_events: model
+                .income_events
+                .into_iter()
+                .map(IncomeEventStorageModel::from_model)
+                .collect(),
+            corporate_actions: model
+                .corporate_actions
+                .into_iter()
+                .map(CorporateActionStorageModel::from_model)
+                .collect(),
+            tags: model.tags,
+            stop_loss: model.stop_loss,
+            take_profit: model.take_profit,
+            market_price: model.market_price,
+            market_price_updated_at: model.market_price_updated_at,
         }
     }
 }
@@ -34,11 +34,20 @@ pub enum SortByStorageModel {
     LastChange(SortDirectionStorageModel),
     AvgPrice(SortDirectionStorageModel),
     Income(SortDirectionStorageModel),
+    Name(SortDirectionStorageModel),
+    NaturalName(SortDirectionStorageModel),
+    Amount(SortDirectionStorageModel),
+    IncomePercent(SortDirectionStorageModel),
+    OrdersCount(SortDirectionStorageModel),
+    CreatedAt(SortDirectionStorageModel),
+    Status(SortDirectionStorageModel),
+    AnnualizedReturn(SortDirectionStorageModel),
+    Xirr(SortDirectionStorageModel),
 }
 
 impl Default for SortByStorageModel {
     fn default() -> Self {
-        SortByStorageModel::LastChange(SortDirectionStorageModel::D)
+        Self::from_model(SortBy::default())
     }
 }
 
@@ -50,6 +59,19 @@ impl ToModel<SortBy> for SortByStorageModel {
             Self::AvgValue(direction) => Ok(SortBy::AvgValue(direction.to_model().unwrap())),
             Self::Income(direction) => Ok(SortBy::Income(direction.to_model().unwrap())),
             Self::LastChange(direction) => Ok(SortBy::LastChange(direction.to_model().unwrap())),
+            Self::Name(direction) => Ok(SortBy::Name(direction.to_model().unwrap())),
+            Self::NaturalName(direction) => Ok(SortBy::NaturalName(direction.to_model().unwrap())),
+            Self::Amount(direction) => Ok(SortBy::Amount(direction.to_model().unwrap())),
+            Self::IncomePercent(direction) => {
+                Ok(SortBy::IncomePercent(direction.to_model().unwrap()))
+            }
+            Self::OrdersCount(direction) => Ok(SortBy::OrdersCount(direction.to_model().unwrap())),
+            Self::CreatedAt(direction) => Ok(SortBy::CreatedAt(direction.to_model().unwrap())),
+            Self::Status(direction) => Ok(SortBy::Status(direction.to_model().unwrap())),
+            Self::AnnualizedReturn(direction) => {
+                Ok(SortBy::AnnualizedReturn(direction.to_model().unwrap()))
+            }
+            Self::Xirr(direction) => Ok(SortBy::Xirr(direction.to_model().unwrap())),
         }
     }
 }
@@ -70,6 +92,29 @@ impl FromModel<SortBy> for SortByStorageModel {
                 Self::LastChange(SortDirectionStorageModel::from_model(direction))
             }
             SortBy::Id(direction) => Self::Id(SortDirectionStorageModel::from_model(direction)),
+            SortBy::Name(direction) => Self::Name(SortDirectionStorageModel::from_model(direction)),
+            SortBy::NaturalName(direction) => {
+                Self::NaturalName(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::Amount(direction) => {
+                Self::Amount(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::IncomePercent(direction) => {
+                Self::IncomePercent(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::OrdersCount(direction) => {
+                Self::OrdersCount(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::CreatedAt(direction) => {
+                Self::CreatedAt(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::Status(direction) => {
+                Self::Status(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::AnnualizedReturn(direction) => {
+                Self::AnnualizedReturn(SortDirectionStorageModel::from_model(direction))
+            }
+            SortBy::Xirr(direction) => Self::Xirr(SortDirectionStorageModel::from_model(direction)),
         }
     }
 }
@@ -2,16 +2,32 @@ use serde::{Deserialize, Serialize};
 
 use super::super::Storage;
 use super::sort_by::SortByStorageModel;
-use super::{FromModel, PositionStorageModel, ToModel};
+use super::{FromModel, PortfolioStorageModel, PositionStorageModel, ToModel};
+use crate::models::Portfolio;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StorageModel {
+    #[serde(default = "default::current_portfolio")]
+    pub current_portfolio: Option<i32>,
+
     #[serde(default)]
+    pub portfolios: Vec<PortfolioStorageModel>,
+
+    // Fields below are left from the format without portfolios. They are only read to migrate
+    // old storage files into the default portfolio and never written back.
+    #[serde(default, skip_serializing)]
     pub sort_positions_by: SortByStorageModel,
 
-    #[serde(default = "default::move_closed_to_bottom")]
+    #[serde(default, skip_serializing)]
+    pub then_sort_positions_by: Vec<SortByStorageModel>,
+
+    #[serde(default = "default::move_closed_to_bottom", skip_serializing)]
     pub move_closed_to_bottom: bool,
 
+    #[serde(default, skip_serializing)]
+    pub positions_filter: Option<String>,
+
+    #[serde(default, skip_serializing)]
     positions: Vec<PositionStorageModel>,
 }
 
@@ -19,40 +35,179 @@ mod default {
     pub fn move_closed_to_bottom() -> bool {
         false
     }
+
+    pub fn current_portfolio() -> Option<i32> {
+        Some(0)
+    }
 }
 
-impl FromModel<Storage> for StorageModel {
-    fn from_model(model: Storage) -> Self {
-        let mut position_models = vec![];
-        for pos in model.positions {
-            position_models.push(PositionStorageModel::from_model(pos));
+const DEFAULT_PORTFOLIO_NAME: &str = "Main";
+
+impl StorageModel {
+    fn to_legacy_portfolio(&self) -> Result<Portfolio, String> {
+        let mut positions = vec![];
+        for pos_model in &self.positions {
+            positions.push(pos_model.to_model()?);
         }
 
+        let mut then_sort_positions_by = vec![];
+        for sort_by_model in &self.then_sort_positions_by {
+            then_sort_positions_by.push(sort_by_model.to_model()?);
+        }
+
+        Ok(Portfolio {
+            id: 0,
+            name: String::from(DEFAULT_PORTFOLIO_NAME),
+            sort_positions_by: self.sort_positions_by.to_model()?,
+            then_sort_positions_by,
+            move_closed_positions_to_bottom: self.move_closed_to_bottom,
+            positions_filter: self.positions_filter.clone(),
+            cash_entries: vec![],
+            allow_margin: false,
+            target_weights: vec![],
+            positions,
+        })
+    }
+}
+
+impl FromModel<Storage> for StorageModel {
+    fn from_model(model: Storage) -> Self {
         Self {
-            positions: position_models,
-            sort_positions_by: SortByStorageModel::from_model(model.sort_positions_by),
-            move_closed_to_bottom: model.move_closed_positions_to_bottom,
+            current_portfolio: model.current_portfolio_id,
+            portfolios: model
+                .portfolios
+                .into_iter()
+                .map(PortfolioStorageModel::from_model)
+                .collect(),
+            sort_positions_by: SortByStorageModel::default(),
+            then_sort_positions_by: vec![],
+            move_closed_to_bottom: false,
+            positions_filter: None,
+            positions: vec![],
         }
     }
 }
 
 impl ToModel<Storage> for StorageModel {
     fn to_model(&self) -> Result<Storage, String> {
-        let mut positions = vec![];
-        for pos_model in &self.positions {
-            positions.push(match pos_model.to_model() {
-                Ok(value) => value,
-                Err(error) => return Err(error),
+        // Storage without portfolios was saved by older version of app, so its positions are
+        // moved to the default portfolio
+        if self.portfolios.is_empty() {
+            return Ok(Storage {
+                current_portfolio_id: Some(0),
+                portfolios: vec![self.to_legacy_portfolio()?],
             });
         }
 
+        let mut portfolios = vec![];
+        for portfolio_model in &self.portfolios {
+            portfolios.push(portfolio_model.to_model()?);
+        }
+
+        // Fall back to all accounts view if current portfolio was removed from file manually
+        let current_portfolio_id = self
+            .current_portfolio
+            .filter(|id| portfolios.iter().any(|portfolio| portfolio.id == *id));
+
         Ok(Storage {
-            positions,
-            sort_positions_by: match self.sort_positions_by.to_model() {
-                Ok(value) => value,
-                Err(error) => return Err(error),
-            },
-            move_closed_positions_to_bottom: self.move_closed_to_bottom,
+            current_portfolio_id,
+            portfolios,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::StorageModel;
+    use crate::storage::models::{FromModel, ToModel};
+
+    #[test]
+    fn test_storage_migrates_legacy_format() {
+        let legacy_json = r#"{
+            "positions": [
+                { "id": 0, "action": "L", "name": "BTC", "orders": [{ "id": 0, "action": "L", "amount": 1.0, "value": 100.0 }] }
+            ],
+            "sort_positions_by": { "Income": "A" },
+            "move_closed_to_bottom": true
+        }"#;
+
+        let storage = serde_json::from_str::<StorageModel>(legacy_json)
+            .unwrap()
+            .to_model()
+            .unwrap();
+
+        assert_eq!(storage.current_portfolio_id, Some(0));
+        assert_eq!(storage.portfolios.len(), 1);
+        assert_eq!(storage.portfolios[0].positions.len(), 1);
+        assert_eq!(storage.portfolios[0].positions[0].name, "BTC");
+        assert!(storage.portfolios[0].move_closed_positions_to_bottom);
+
+        // Legacy fields are not written back
+        let json = serde_json::to_value(StorageModel::from_model(storage)).unwrap();
+        assert!(json.get("positions").is_none());
+        assert_eq!(json["portfolios"][0]["positions"][0]["name"], "BTC");
+    }
+
+    #[test]
+    fn test_storage_loads_invalid_orders() {
+        let json = r#"{
+            "portfolios": [{ "id": 0, "name": "Main", "positions": [
+                { "id": 3, "action": "L", "name": "BTC", "orders": [
+                    { "id": 0, "action": "L", "amount": 1.0, "value": 100.0 },
+                    { "id": 1, "action": "S", "amount": 0.0, "value": 50.0 },
+                    { "id": 2, "action": "S", "amount": 0.5, "value": -1.0 }
+                ] }
+            ] }]
+        }"#;
+
+        // Storage is loaded, so invalid orders can be fixed or deleted in the app
+        let storage = serde_json::from_str::<StorageModel>(json)
+            .unwrap()
+            .to_model()
+            .unwrap();
+        let invalid_orders = storage.portfolios[0].positions[0].find_invalid_orders();
+
+        assert_eq!(invalid_orders.len(), 2);
+        assert!(invalid_orders[0]
+            .contains("position 3 (BTC), order 1: amount should be positive, got 0"));
+        assert!(invalid_orders[1]
+            .contains("position 3 (BTC), order 2: value should be positive, got -1"));
+    }
+
+    #[test]
+    fn test_storage_keeps_order_ids_after_order_deletion() {
+        let json = r#"{
+            "portfolios": [{ "id": 0, "name": "Main", "positions": [
+                { "id": 0, "action": "L", "name": "BTC", "orders": [
+                    { "id": 0, "action": "L", "amount": 10.0, "value": 1000.0 },
+                    { "id": 1, "action": "L", "amount": 10.0, "value": 1000.0 },
+                    { "id": 2, "action": "L", "amount": 10.0, "value": 1000.0 },
+                    { "id": 3, "action": "S", "amount": 20.0, "value": 2000.0 }
+                ], "income_events": [
+                    { "id": 0, "kind": "R", "after_order_id": 2, "units": 10.0,
+                      "date": "2024-01-01T00:00:00+00:00" }
+                ] }
+            ] }]
+        }"#;
+        let mut storage = serde_json::from_str::<StorageModel>(json)
+            .unwrap()
+            .to_model()
+            .unwrap();
+        let position = &mut storage.portfolios[0].positions[0];
+        position.remove_order(1).unwrap();
+        let (amount, income) = (position.amount, position.income);
+
+        let json = serde_json::to_string(&StorageModel::from_model(storage)).unwrap();
+        let storage = serde_json::from_str::<StorageModel>(&json)
+            .unwrap()
+            .to_model()
+            .unwrap();
+        let position = &storage.portfolios[0].positions[0];
+
+        let order_ids: Vec<i32> = position.orders.iter().map(|order| order.id).collect();
+        assert_eq!(order_ids, vec![0, 2, 3]);
+        assert_eq!(position.amount, amount);
+        assert_eq!(position.income, income);
+        assert!(income > 0f64);
+    }
+}
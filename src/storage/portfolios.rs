@@ -0,0 +1,25 @@
+use super::{load_storage, update_storage};
+use crate::i18n::t;
+use crate::models::Portfolio;
+
+// Message key of the error
+pub const NO_PORTFOLIO_SELECTED_ERROR: &str = "error.no_portfolio_selected";
+
+pub fn load_portfolios() -> Result<Vec<Portfolio>, String> {
+    Ok(load_storage()?.portfolios)
+}
+
+pub fn update_current_portfolio<F>(func: F) -> Result<(), String>
+where
+    F: Fn(&mut Portfolio),
+{
+    if load_storage()?.current_portfolio().is_none() {
+        return Err(t(NO_PORTFOLIO_SELECTED_ERROR));
+    }
+
+    update_storage(|storage| {
+        if let Some(portfolio) = storage.current_portfolio_mut() {
+            func(portfolio);
+        }
+    })
+}
@@ -1,22 +1,20 @@
-use super::update_storage;
+use super::update_current_portfolio;
 use crate::models::Position;
+use crate::tf;
 
-pub fn save_positions(positions: &Vec<Position>) -> Result<(), String> {
-    update_storage(|storage| storage.positions = positions.clone())
+pub fn save_positions(positions: &[Position]) -> Result<(), String> {
+    update_current_portfolio(|portfolio| portfolio.positions = positions.to_vec())
 }
 
 pub fn save_position(position: Position) -> Result<(), String> {
-    let mut positions = match load_positions() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
-    };
+    let mut positions = load_positions()?;
 
     let pos_index = match positions
         .iter()
         .position(|pos_candidate| pos_candidate.id == position.id)
     {
         Some(index) => index,
-        None => return Err(format!("Position {} not found", position.id)),
+        None => return Err(tf!("error.position_not_found", position.id)),
     };
 
     positions[pos_index] = position;
@@ -24,9 +22,11 @@ pub fn save_position(position: Position) -> Result<(), String> {
     save_positions(&positions)
 }
 
+// Returns positions of the current portfolio, all accounts view has no own positions
 pub fn load_positions() -> Result<Vec<Position>, String> {
-    match super::load_storage() {
-        Ok(storage) => Ok(storage.positions),
-        Err(error) => return Err(error),
-    }
+    let storage = super::load_storage()?;
+    Ok(storage
+        .current_portfolio()
+        .map(|portfolio| portfolio.positions.clone())
+        .unwrap_or_default())
 }
@@ -0,0 +1,398 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::widgets::TableState;
+
+use super::form::{Form, FormEvent, FormKind};
+use crate::commands::utils::commands::{find_cash_shortage, get_cash_shortage_message};
+use crate::commands::utils::sorting::{parse_sorting_methods, SortDirection};
+use crate::commands::{CommandHandler, CommandResult};
+use crate::i18n::t;
+use crate::models::validation::{validate_name, validate_order_values};
+use crate::models::{Action, Order, Position};
+use crate::options::get_options;
+use crate::options::model::Options;
+use crate::tf;
+
+// Action, which is applied after user confirms it in the modal
+pub enum PendingAction {
+    DeletePosition(i32),
+    DeleteOrder(i32),
+    AddPosition {
+        name: String,
+        action: Action,
+        amount: f64,
+        value: f64,
+    },
+    AddOrder {
+        action: Action,
+        amount: f64,
+        value: f64,
+    },
+}
+
+pub enum Modal {
+    Form(Form),
+    Confirm {
+        message: String,
+        action: PendingAction,
+    },
+    Help,
+}
+
+pub struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+}
+
+pub struct App {
+    pub handler: CommandHandler,
+    pub positions_state: TableState,
+    pub orders_state: TableState,
+    pub modal: Option<Modal>,
+    pub status: Option<StatusMessage>,
+    pub should_quit: bool,
+    // Read once, as options are applied at startup
+    pub options: Options,
+}
+
+impl App {
+    pub fn new(handler: CommandHandler) -> App {
+        let mut app = App {
+            handler,
+            positions_state: TableState::default(),
+            orders_state: TableState::default(),
+            modal: None,
+            status: None,
+            should_quit: false,
+            options: get_options(),
+        };
+        app.positions_state.select(Some(0));
+        let warnings = app.handler.global_manager().get_warnings();
+        if !warnings.is_empty() {
+            app.status = Some(StatusMessage {
+                text: warnings.join(" "),
+                is_error: true,
+            });
+        }
+        app
+    }
+
+    pub fn is_editing_position(&mut self) -> bool {
+        self.handler.position_manager().is_some()
+    }
+
+    /// Positions in order they are listed, the edited position is shown with its changes
+    pub fn get_listed_positions(&mut self) -> Vec<Position> {
+        let mut positions = self.handler.global_manager().get_visible_positions();
+        positions.reverse();
+
+        if let Some(manager) = self.handler.position_manager() {
+            let edited = manager.position.clone();
+            if let Some(position) = positions.iter_mut().find(|pos| pos.id == edited.id) {
+                *position = edited;
+            }
+        }
+
+        positions
+    }
+
+    pub fn get_selected_position(&mut self) -> Option<Position> {
+        if let Some(manager) = self.handler.position_manager() {
+            return Some(manager.position.clone());
+        }
+
+        let index = self.positions_state.selected()?;
+        self.get_listed_positions().get(index).cloned()
+    }
+
+    pub fn get_selected_order(&mut self) -> Option<Order> {
+        let position = self.get_selected_position()?;
+        if !self.is_editing_position() {
+            return position.orders.last().cloned();
+        }
+
+        let index = self.orders_state.selected()?;
+        position.orders.get(index).cloned()
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(modal) = self.modal.take() {
+            self.handle_modal_key(modal, key);
+            return;
+        }
+
+        self.status = None;
+        if self.is_editing_position() {
+            self.handle_position_key(key);
+        } else {
+            self.handle_global_key(key);
+        }
+    }
+
+    fn handle_global_key(&mut self, key: KeyEvent) {
+        let count = self.get_listed_positions().len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('?') => self.modal = Some(Modal::Help),
+            KeyCode::Char('a') => self.modal = Some(Modal::Form(Form::new(FormKind::AddPosition))),
+            KeyCode::Char('s') => self.modal = Some(Modal::Form(Form::new(FormKind::Sorting))),
+            KeyCode::Char('c') => {
+                let result = self.handler.global_manager().toggle_move_closed_to_bottom();
+                self.apply_result(result, &t("tui.sorting_changed"));
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if let Some(position) = self.get_selected_position() {
+                    self.modal = Some(Modal::Confirm {
+                        message: tf!("tui.confirm_delete_position", position.id, position.name),
+                        action: PendingAction::DeletePosition(position.id),
+                    });
+                }
+            }
+            KeyCode::Char('e') | KeyCode::Enter => {
+                if let Some(position) = self.get_selected_position() {
+                    let result = self.handler.global_manager().edit_position(position.id);
+                    self.apply_result(result, "");
+                    self.orders_state
+                        .select(Some(position.orders.len().saturating_sub(1)));
+                }
+            }
+            code => move_selection(&mut self.positions_state, code, count),
+        }
+    }
+
+    fn handle_position_key(&mut self, key: KeyEvent) {
+        let count = match self.get_selected_position() {
+            Some(position) => position.orders.len(),
+            None => 0,
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                let result = match self.handler.position_manager() {
+                    Some(manager) => manager.close(),
+                    None => return,
+                };
+                self.apply_result(result, "");
+            }
+            KeyCode::Char('?') => self.modal = Some(Modal::Help),
+            KeyCode::Char('a') => self.modal = Some(Modal::Form(Form::new(FormKind::AddOrder))),
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if let Some(order) = self.get_selected_order() {
+                    self.modal = Some(Modal::Confirm {
+                        message: tf!("tui.confirm_delete_order", order.id),
+                        action: PendingAction::DeleteOrder(order.id),
+                    });
+                }
+            }
+            code => move_selection(&mut self.orders_state, code, count),
+        }
+    }
+
+    fn handle_modal_key(&mut self, modal: Modal, key: KeyEvent) {
+        match modal {
+            Modal::Help => {}
+            Modal::Confirm { message, action } => match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => self.apply_pending_action(action),
+                KeyCode::Char('n') | KeyCode::Esc => {}
+                _ => self.modal = Some(Modal::Confirm { message, action }),
+            },
+            Modal::Form(mut form) => match form.handle_key(key) {
+                FormEvent::None => self.modal = Some(Modal::Form(form)),
+                FormEvent::Cancel => {}
+                FormEvent::Submit => {
+                    if let Err(error) = self.submit_form(&form) {
+                        form.error = Some(error);
+                        self.modal = Some(Modal::Form(form));
+                    }
+                }
+            },
+        }
+    }
+
+    // Errors of values keep the form open, errors of commands are shown in the status bar
+    fn submit_form(&mut self, form: &Form) -> Result<(), String> {
+        match form.kind {
+            FormKind::AddPosition => {
+                let name = form.field(0).value.trim().to_string();
+                validate_name(&name).map_err(|error| tf!("error.invalid_value", error))?;
+                let action = Action::from_string(form.field(1).value.trim().to_string())?;
+                let amount = form.field(2).parse::<f64>()?;
+                let value = form.field(3).parse::<f64>()?;
+                validate_order_values(amount, value)
+                    .map_err(|error| tf!("error.invalid_value", error))?;
+
+                self.confirm_spending(
+                    action,
+                    value,
+                    PendingAction::AddPosition {
+                        name,
+                        action,
+                        amount,
+                        value,
+                    },
+                )
+            }
+            FormKind::AddOrder => {
+                let position = match self.get_selected_position() {
+                    Some(position) => position,
+                    None => return Ok(()),
+                };
+                let action = Action::from_string(form.field(0).value.trim().to_string())?;
+                let amount = match form.field(1).parse::<f64>()? {
+                    0f64 => position.amount,
+                    amount => amount,
+                };
+                let value = form.field(2).parse::<f64>()?;
+                validate_order_values(amount, value)
+                    .map_err(|error| tf!("error.invalid_value", error))?;
+
+                self.confirm_spending(
+                    action,
+                    value,
+                    PendingAction::AddOrder {
+                        action,
+                        amount,
+                        value,
+                    },
+                )
+            }
+            FormKind::Sorting => {
+                let methods = parse_sorting_methods(&form.field(0).value)?;
+                let directions = form
+                    .field(1)
+                    .value
+                    .split(',')
+                    .map(SortDirection::from_string)
+                    .collect::<Result<Vec<SortDirection>, String>>()?;
+                if directions.len() != methods.len() {
+                    return Err(tf!(
+                        "error.sorting_directions_count",
+                        methods.len(),
+                        directions.len()
+                    ));
+                }
+
+                let sort_keys = methods
+                    .into_iter()
+                    .zip(directions)
+                    .map(|((_, to_sort_by), direction)| to_sort_by(direction))
+                    .collect();
+                let result = self.handler.global_manager().set_sorting(sort_keys);
+                self.apply_result(result, &t("tui.sorting_changed"));
+                Ok(())
+            }
+        }
+    }
+
+    // Buying is confirmed if it makes cash balance negative, like in the REPL
+    fn confirm_spending(
+        &mut self,
+        action: Action,
+        value: f64,
+        pending: PendingAction,
+    ) -> Result<(), String> {
+        let shortage = match action {
+            Action::Long => find_cash_shortage(value)?,
+            Action::Short => None,
+        };
+
+        match shortage {
+            Some(cash_balance) => {
+                let message = get_cash_shortage_message(cash_balance, value);
+                self.modal = Some(Modal::Confirm {
+                    message,
+                    action: pending,
+                });
+            }
+            None => self.apply_pending_action(pending),
+        }
+
+        Ok(())
+    }
+
+    fn apply_pending_action(&mut self, action: PendingAction) {
+        let (result, message) = match action {
+            PendingAction::DeletePosition(id) => (
+                self.handler.global_manager().delete_position(id),
+                tf!("tui.position_deleted", id),
+            ),
+            PendingAction::AddPosition {
+                name,
+                action,
+                amount,
+                value,
+            } => (
+                self.handler
+                    .global_manager()
+                    .add_position(name, action, amount, value),
+                t("tui.position_added"),
+            ),
+            PendingAction::DeleteOrder(id) => match self.handler.position_manager() {
+                Some(manager) => (manager.delete_order(id), tf!("tui.order_deleted", id)),
+                None => return,
+            },
+            PendingAction::AddOrder {
+                action,
+                amount,
+                value,
+            } => match self.handler.position_manager() {
+                Some(manager) => (
+                    manager.add_order(action, amount, value),
+                    t("tui.order_added"),
+                ),
+                None => return,
+            },
+        };
+
+        self.apply_result(result, &message);
+    }
+
+    fn apply_result(&mut self, result: CommandResult, message: &str) {
+        self.status = match self.handler.apply_result(result) {
+            Ok(_) if message.is_empty() => None,
+            Ok(_) => Some(StatusMessage {
+                text: message.to_string(),
+                is_error: false,
+            }),
+            Err(error) => Some(StatusMessage {
+                text: error,
+                is_error: true,
+            }),
+        };
+
+        let positions_count = self.get_listed_positions().len();
+        clamp_selection(&mut self.positions_state, positions_count);
+        let orders_count = match self.handler.position_manager() {
+            Some(manager) => manager.position.orders.len(),
+            None => 0,
+        };
+        clamp_selection(&mut self.orders_state, orders_count);
+    }
+}
+
+fn move_selection(state: &mut TableState, code: KeyCode, count: usize) {
+    if count == 0 {
+        return;
+    }
+
+    let selected = state.selected().unwrap_or(0);
+    let index = match code {
+        KeyCode::Up | KeyCode::Char('k') => selected.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => selected + 1,
+        KeyCode::PageUp => selected.saturating_sub(10),
+        KeyCode::PageDown => selected + 10,
+        KeyCode::Home | KeyCode::Char('g') => 0,
+        KeyCode::End | KeyCode::Char('G') => count - 1,
+        _ => return,
+    };
+
+    state.select(Some(index.min(count - 1)));
+}
+
+fn clamp_selection(state: &mut TableState, count: usize) {
+    match state.selected() {
+        Some(_) if count == 0 => state.select(None),
+        Some(index) if index >= count => state.select(Some(count - 1)),
+        None if count > 0 => state.select(Some(0)),
+        _ => {}
+    }
+}
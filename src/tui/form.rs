@@ -0,0 +1,117 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::i18n::t;
+use crate::tf;
+use crate::utils::locale::{parse_localized, LocalizedInput};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormKind {
+    AddPosition,
+    AddOrder,
+    Sorting,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: &'static str,
+    pub hint: &'static str,
+    pub value: String,
+}
+
+impl FormField {
+    fn new(label: &'static str, hint: &'static str) -> FormField {
+        FormField {
+            label,
+            hint,
+            value: String::new(),
+        }
+    }
+
+    pub fn parse<T: LocalizedInput>(&self) -> Result<T, String> {
+        match parse_localized::<T>(self.value.trim()) {
+            Some(value) => Ok(value),
+            None => Err(tf!(
+                "error.parse_field",
+                t(self.label).to_lowercase(),
+                self.value.trim()
+            )),
+        }
+    }
+}
+
+pub enum FormEvent {
+    None,
+    Submit,
+    Cancel,
+}
+
+/// Modal form, which replaces chained console prompts in the TUI
+#[derive(Debug, Clone)]
+pub struct Form {
+    pub kind: FormKind,
+    pub title: String,
+    pub fields: Vec<FormField>,
+    pub focused: usize,
+    pub error: Option<String>,
+}
+
+impl Form {
+    pub fn new(kind: FormKind) -> Form {
+        let (title, fields) = match kind {
+            FormKind::AddPosition => (
+                "tui.add_position_title",
+                vec![
+                    FormField::new("label.name", ""),
+                    FormField::new("label.type", "tui.position_type_hint"),
+                    FormField::new("label.amount", ""),
+                    FormField::new("label.value", ""),
+                ],
+            ),
+            FormKind::AddOrder => (
+                "tui.add_order_title",
+                vec![
+                    FormField::new("label.type", "tui.order_type_hint"),
+                    FormField::new("label.amount", "tui.order_amount_hint"),
+                    FormField::new("label.value", ""),
+                ],
+            ),
+            FormKind::Sorting => (
+                "tui.change_sorting_title",
+                vec![
+                    FormField::new("tui.methods", "tui.methods_hint"),
+                    FormField::new("tui.directions", "tui.directions_hint"),
+                ],
+            ),
+        };
+
+        Form {
+            kind,
+            title: t(title),
+            fields,
+            focused: 0,
+            error: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> FormEvent {
+        match key.code {
+            KeyCode::Esc => return FormEvent::Cancel,
+            KeyCode::Enter => return FormEvent::Submit,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % self.fields.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + self.fields.len() - 1) % self.fields.len()
+            }
+            KeyCode::Backspace => {
+                self.fields[self.focused].value.pop();
+            }
+            KeyCode::Char(char) => self.fields[self.focused].value.push(char),
+            _ => {}
+        }
+
+        FormEvent::None
+    }
+
+    pub fn field(&self, index: usize) -> &FormField {
+        &self.fields[index]
+    }
+}
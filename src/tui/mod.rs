@@ -0,0 +1,34 @@
+mod app;
+mod form;
+mod render;
+
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+use crate::commands::CommandHandler;
+use crate::tf;
+use app::App;
+
+/// Runs full-screen UI until user quits it. Commands are handled by the same managers as in REPL.
+pub fn run(command_handler: CommandHandler) -> Result<(), String> {
+    let mut terminal = ratatui::init();
+    let mut app = App::new(command_handler);
+
+    let result = loop {
+        if let Err(error) = terminal.draw(|frame| render::draw(frame, &mut app)) {
+            break Err(tf!("error.draw_ui", error));
+        }
+
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => app.handle_key(key),
+            Ok(_) => {}
+            Err(error) => break Err(tf!("error.read_terminal_event", error)),
+        }
+
+        if app.should_quit {
+            break Ok(());
+        }
+    };
+
+    ratatui::restore();
+    result
+}
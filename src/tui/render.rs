@@ -0,0 +1,484 @@
+use chrono::Local;
+use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap};
+use ratatui::Frame;
+
+use super::app::{App, Modal};
+use super::form::{Form, FormKind};
+use crate::commands::ui::columns::{
+    format_column_number, get_order_column_title, get_order_column_value, get_order_columns,
+    get_order_type, get_position_column_title, get_position_column_value, ColumnValue, NumberKind,
+};
+use crate::commands::utils::sorting::SORTING_METHODS;
+use crate::i18n::t;
+use crate::models::{Action, Order, Position, PriceLevel};
+use crate::options::model::{Options, OrderColumn, PositionColumn};
+use crate::tf;
+
+const GLOBAL_KEYS: &str = "tui.global_keys";
+const POSITION_KEYS: &str = "tui.position_keys";
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [header_area, main_area, status_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [positions_area, details_area] =
+        Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .areas(main_area);
+    let [summary_area, orders_area, order_area] = Layout::vertical([
+        Constraint::Length(6),
+        Constraint::Min(5),
+        Constraint::Length(9),
+    ])
+    .areas(details_area);
+
+    draw_header(frame, app, header_area);
+    draw_positions(frame, app, positions_area);
+
+    let position = app.get_selected_position();
+    let order = app.get_selected_order();
+    draw_summary(frame, &app.options, position.as_ref(), summary_area);
+    draw_orders(frame, app, position.as_ref(), orders_area);
+    draw_order_details(
+        frame,
+        &app.options,
+        position.as_ref(),
+        order.as_ref(),
+        order_area,
+    );
+    draw_status(frame, app, status_area);
+
+    match &app.modal {
+        Some(Modal::Form(form)) => draw_form(frame, form),
+        Some(Modal::Confirm { message, .. }) => draw_confirmation(frame, message),
+        Some(Modal::Help) => draw_help(frame),
+        None => {}
+    }
+}
+
+fn draw_header(frame: &mut Frame, app: &mut App, area: Rect) {
+    let manager = app.handler.global_manager();
+    let portfolio = match manager.get_current_portfolio() {
+        Some(portfolio) => portfolio.name,
+        None => t("tui.no_portfolio_selected"),
+    };
+
+    let mut spans = vec![
+        Span::from(t("label.portfolio_prefix")).dark_gray(),
+        Span::from(portfolio).bold(),
+        Span::from(format!("  {}", t("tui.sorting_prefix"))).dark_gray(),
+        Span::from(manager.get_sorting_description()).yellow(),
+    ];
+    if let Some(expression) = manager.get_filter_expression() {
+        spans.push(Span::from(format!("  {}", t("label.filter_prefix"))).dark_gray());
+        spans.push(Span::from(expression.to_string()).yellow());
+    }
+
+    frame.render_widget(Line::from(spans), area);
+}
+
+fn draw_positions(frame: &mut Frame, app: &mut App, area: Rect) {
+    let positions = app.get_listed_positions();
+    let editing = app.is_editing_position();
+    let options = &app.options;
+    let columns = options.get_position_columns();
+    let now = Local::now();
+
+    let rows = positions.iter().map(|position| {
+        Row::new(
+            columns
+                .iter()
+                .map(
+                    |column| match get_position_column_value(position, *column, options, now) {
+                        ColumnValue::Text(text) => Cell::from(text),
+                        ColumnValue::Signed(number, text) => {
+                            Cell::from(get_signed_span(number, text))
+                        }
+                        ColumnValue::Status => get_status_cell(position),
+                    },
+                )
+                .collect::<Vec<Cell>>(),
+        )
+    });
+
+    let table = Table::new(
+        rows,
+        columns.iter().map(|column| match column {
+            PositionColumn::Id => Constraint::Length(4),
+            _ => Constraint::Min(6),
+        }),
+    )
+    .header(
+        Row::new(
+            columns
+                .iter()
+                .map(|column| get_position_column_title(*column, options))
+                .collect::<Vec<String>>(),
+        )
+        .bold(),
+    )
+    .block(get_block(&t("label.positions"), !editing))
+    .row_highlight_style(get_highlight_style(!editing));
+
+    frame.render_stateful_widget(table, area, &mut app.positions_state);
+}
+
+fn draw_summary(frame: &mut Frame, options: &Options, position: Option<&Position>, area: Rect) {
+    let block = get_block(&t("label.position"), false);
+    let position = match position {
+        Some(position) => position,
+        None => {
+            frame.render_widget(Paragraph::new(t("label.no_positions")).block(block), area);
+            return;
+        }
+    };
+
+    let (action, color) = match position.action {
+        Action::Long => (t("label.long"), Color::Green),
+        Action::Short => (t("label.short"), Color::Red),
+    };
+    let format = |column: PositionColumn, kind: NumberKind, number: f64| {
+        format_column_number(options, column.name(), kind, number)
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::from(format!("#{} ", position.id)),
+            Span::from(action).fg(color).bold(),
+            Span::from(format!(" {}", position.name)).bold(),
+        ]),
+        Line::from(format!(
+            "{} {}  {} {}",
+            t("label.amount"),
+            format(PositionColumn::Amount, NumberKind::Amount, position.amount),
+            t("label.avg_price"),
+            format(
+                PositionColumn::AvgPrice,
+                NumberKind::Money,
+                position.avg_price
+            )
+        )),
+        Line::from(vec![
+            Span::from(format!("{} ", t("label.income"))),
+            get_signed_span(
+                position.income,
+                format(PositionColumn::Income, NumberKind::Money, position.income),
+            ),
+            Span::from(format!("  {} ", t("label.payouts"))),
+            get_signed_span(
+                position.events_income,
+                format(
+                    PositionColumn::Payouts,
+                    NumberKind::Money,
+                    position.events_income,
+                ),
+            ),
+        ]),
+    ];
+    if !position.tags.is_empty() {
+        lines.push(
+            Line::from(format!(
+                "{}{}",
+                t("label.tags_prefix"),
+                position.tags.join(", ")
+            ))
+            .yellow(),
+        );
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_orders(frame: &mut Frame, app: &mut App, position: Option<&Position>, area: Rect) {
+    let editing = app.is_editing_position();
+    let options = &app.options;
+    let orders = position
+        .map(|position| position.orders.clone())
+        .unwrap_or_default();
+    let columns = match position {
+        Some(position) => get_order_columns(position, options),
+        None => options.get_order_columns(),
+    };
+
+    let rows = orders.iter().map(|order| {
+        Row::new(
+            columns
+                .iter()
+                .map(|column| {
+                    let value = match position {
+                        Some(position) => get_order_column_value(position, order, *column, options),
+                        None => ColumnValue::Text(String::from("-")),
+                    };
+                    match value {
+                        ColumnValue::Text(text) => Cell::from(text),
+                        ColumnValue::Signed(number, text) => {
+                            Cell::from(get_signed_span(number, text))
+                        }
+                        ColumnValue::Status => Cell::from("-"),
+                    }
+                })
+                .collect::<Vec<Cell>>(),
+        )
+    });
+
+    let table = Table::new(
+        rows,
+        columns.iter().map(|column| match column {
+            OrderColumn::Id => Constraint::Length(4),
+            OrderColumn::Date => Constraint::Length(19),
+            OrderColumn::Type => Constraint::Length(8),
+            _ => Constraint::Min(8),
+        }),
+    )
+    .header(
+        Row::new(
+            columns
+                .iter()
+                .map(|column| get_order_column_title(*column))
+                .collect::<Vec<String>>(),
+        )
+        .bold(),
+    )
+    .block(get_block(&t("tui.orders"), editing))
+    .row_highlight_style(get_highlight_style(editing));
+
+    if editing {
+        frame.render_stateful_widget(table, area, &mut app.orders_state);
+    } else {
+        frame.render_widget(table, area);
+    }
+}
+
+fn draw_order_details(
+    frame: &mut Frame,
+    options: &Options,
+    position: Option<&Position>,
+    order: Option<&Order>,
+    area: Rect,
+) {
+    let block = get_block(&t("tui.order"), false);
+    let (position, order) = match (position, order) {
+        (Some(position), Some(order)) => (position, order),
+        _ => {
+            frame.render_widget(
+                Paragraph::new(t("tui.no_order_selected")).block(block),
+                area,
+            );
+            return;
+        }
+    };
+
+    // Same columns as in orders table of the REPL, each one on its own line
+    let mut columns = vec![
+        OrderColumn::Date,
+        OrderColumn::Amount,
+        OrderColumn::Value,
+        OrderColumn::Price,
+    ];
+    if order.action != position.action {
+        columns.push(OrderColumn::Income);
+    }
+    if position.get_split_factor_after(order.id) != 1f64 {
+        columns.extend([OrderColumn::AdjustedAmount, OrderColumn::AdjustedPrice]);
+    }
+
+    let mut lines =
+        vec![Line::from(tf!("tui.order_title", order.id, get_order_type(order))).bold()];
+    columns.into_iter().for_each(|column| {
+        let title = Span::from(format!("{}: ", get_order_column_title(column)));
+        lines.push(
+            match get_order_column_value(position, order, column, options) {
+                ColumnValue::Signed(number, text) => {
+                    Line::from(vec![title, get_signed_span(number, text)])
+                }
+                ColumnValue::Text(text) => Line::from(vec![title, Span::from(text)]),
+                ColumnValue::Status => Line::from(title),
+            },
+        );
+    });
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_status(frame: &mut Frame, app: &mut App, area: Rect) {
+    let editing = app.is_editing_position();
+    let line = match &app.status {
+        Some(status) if status.is_error => Line::from(status.text.clone()).white().on_red(),
+        Some(status) => Line::from(status.text.clone()).green(),
+        None if editing => Line::from(t(POSITION_KEYS)).dark_gray(),
+        None => Line::from(t(GLOBAL_KEYS)).dark_gray(),
+    };
+
+    frame.render_widget(line, area);
+}
+
+fn draw_form(frame: &mut Frame, form: &Form) {
+    let mut lines = vec![];
+    if form.kind == FormKind::Sorting {
+        SORTING_METHODS
+            .iter()
+            .enumerate()
+            .for_each(|(index, (label, _))| {
+                lines.push(Line::from(vec![
+                    Span::from(format!("{:>2}. ", index + 1)).yellow(),
+                    Span::from(t(label)),
+                ]))
+            });
+        lines.push(Line::default());
+    }
+
+    form.fields.iter().enumerate().for_each(|(index, field)| {
+        let focused = index == form.focused;
+        let mut spans = vec![
+            Span::from(format!("{}: ", t(field.label))).bold(),
+            Span::from(field.value.clone()),
+        ];
+        if focused {
+            spans.push(Span::from("_").add_modifier(Modifier::SLOW_BLINK));
+        }
+        if field.value.is_empty() && !field.hint.is_empty() {
+            spans.push(Span::from(format!(" ({})", t(field.hint))).dark_gray());
+        }
+
+        let line = Line::from(spans);
+        lines.push(if focused { line.on_dark_gray() } else { line });
+    });
+
+    lines.push(Line::default());
+    match &form.error {
+        Some(error) => lines.push(Line::from(error.clone()).red()),
+        None => lines.push(Line::from(t("tui.form_keys")).dark_gray()),
+    }
+
+    let height = lines.len() as u16 + 2;
+    let area = get_modal_area(frame.area(), 64, height);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(get_block(&form.title, true)),
+        area,
+    );
+}
+
+fn draw_confirmation(frame: &mut Frame, message: &str) {
+    let mut lines: Vec<Line> = message.lines().map(Line::from).collect();
+    lines.push(Line::default());
+    lines.push(Line::from(t("tui.confirm_keys")).dark_gray());
+
+    let area = get_modal_area(frame.area(), 64, lines.len() as u16 + 4);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(get_block(&t("tui.confirm"), true)),
+        area,
+    );
+}
+
+fn draw_help(frame: &mut Frame) {
+    let lines = vec![
+        Line::from(t("label.positions")).bold(),
+        Line::from(format!(
+            "  ↑/↓, j/k, PgUp/PgDn, Home/End - {}",
+            t("tui.select_position")
+        )),
+        Line::from(format!("  Enter, e - {}", t("tui.edit_position_orders"))),
+        Line::from(format!(
+            "  a - {}, d - {}",
+            t("tui.add_position"),
+            t("tui.delete_position")
+        )),
+        Line::from(format!(
+            "  s - {}, c - {}",
+            t("tui.change_sorting"),
+            t("tui.move_closed_to_bottom")
+        )),
+        Line::from(format!("  q, Esc - {}", t("tui.quit"))),
+        Line::default(),
+        Line::from(t("tui.orders")).bold(),
+        Line::from(format!("  ↑/↓, j/k - {}", t("tui.select_order"))),
+        Line::from(format!(
+            "  a - {}, d - {}",
+            t("tui.add_order"),
+            t("tui.delete_order")
+        )),
+        Line::from(format!("  q, Esc - {}", t("tui.back_to_positions"))),
+        Line::default(),
+        Line::from(t("tui.repl_commands")).dark_gray(),
+        Line::from(t("tui.press_any_key")).dark_gray(),
+    ];
+
+    let area = get_modal_area(frame.area(), 64, lines.len() as u16 + 2);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(get_block(&t("tui.help"), true)),
+        area,
+    );
+}
+
+fn get_modal_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::new(Direction::Horizontal, [Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+fn get_block(title: &str, focused: bool) -> Block<'static> {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", title));
+    if focused {
+        block.border_style(Style::default().fg(Color::Yellow))
+    } else {
+        block
+    }
+}
+
+fn get_highlight_style(focused: bool) -> Style {
+    if focused {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default().add_modifier(Modifier::BOLD)
+    }
+}
+
+// Positive numbers get plus sign, color depends on sign of the number, not of its text
+fn get_signed_span(number: f64, text: String) -> Span<'static> {
+    if number > 0f64 {
+        Span::from(format!("+{}", text)).green()
+    } else if number < 0f64 {
+        Span::from(text).red()
+    } else {
+        Span::from(text)
+    }
+}
+
+fn get_status_cell(position: &Position) -> Cell<'static> {
+    let status = if position.is_closed() {
+        t("label.closed")
+    } else {
+        t("label.active")
+    };
+
+    match position.get_triggered_level() {
+        Some(level) => {
+            let background = match level {
+                PriceLevel::StopLoss => Color::Red,
+                PriceLevel::TakeProfit => Color::Green,
+            };
+            Cell::from(format!("{} ({})", status, level))
+                .style(Style::default().bg(background).fg(Color::White).bold())
+        }
+        None if position.is_closed() => Cell::from(status).dark_gray(),
+        None => Cell::from(status),
+    }
+}
@@ -0,0 +1,260 @@
+use crate::i18n::t;
+
+// Message key of chart, which has nothing to draw
+pub const NOT_ENOUGH_DATA: &str = "info.not_enough_data";
+
+const MIN_PLOT_WIDTH: usize = 10;
+const STEP_LINE_CHAR: char = '-';
+const BAR_CHAR: char = '█';
+
+/// Points of one line on the chart. Step lines keep their value until the next point,
+/// other series are drawn only as markers.
+pub struct ChartSeries {
+    pub points: Vec<(f64, f64)>,
+    pub marker: char,
+    pub is_step_line: bool,
+}
+
+/// Draws series in a box of the given size, including axes and their labels.
+/// X values are formatted for the axis by `format_x`, e.g. timestamps as dates.
+pub fn draw_line_chart<F>(
+    series: &[ChartSeries],
+    width: usize,
+    height: usize,
+    format_x: F,
+) -> Vec<String>
+where
+    F: Fn(f64) -> String,
+{
+    let points: Vec<(f64, f64)> = series
+        .iter()
+        .flat_map(|series| series.points.iter().copied())
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    if points.is_empty() || height < 2 {
+        return vec![t(NOT_ENOUGH_DATA)];
+    }
+
+    let (min_x, max_x) = get_range(points.iter().map(|(x, _)| *x));
+    let (mut min_y, mut max_y) = get_range(points.iter().map(|(_, y)| *y));
+    // Single value is drawn in the middle of the chart
+    if max_y == min_y {
+        let padding = (max_y.abs() * 0.1).max(1f64);
+        min_y -= padding;
+        max_y += padding;
+    }
+
+    let labels = [
+        format_value(max_y),
+        format_value((max_y + min_y) / 2f64),
+        format_value(min_y),
+    ];
+    let label_width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let plot_width = width.saturating_sub(label_width + 2).max(MIN_PLOT_WIDTH);
+
+    let get_column = |x: f64| match max_x > min_x {
+        true => ((x - min_x) / (max_x - min_x) * (plot_width - 1) as f64).round() as usize,
+        false => plot_width / 2,
+    };
+    let get_row = |y: f64| ((max_y - y) / (max_y - min_y) * (height - 1) as f64).round() as usize;
+
+    let mut grid = vec![vec![' '; plot_width]; height];
+    for series in series.iter().filter(|series| series.is_step_line) {
+        let mut points = series.points.clone();
+        points.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+        for (index, (x, y)) in points.iter().enumerate() {
+            let end = match points.get(index + 1) {
+                Some((next_x, _)) => get_column(*next_x),
+                None => plot_width - 1,
+            };
+            (get_column(*x)..=end).for_each(|column| grid[get_row(*y)][column] = STEP_LINE_CHAR);
+        }
+    }
+
+    for series in series {
+        for (x, y) in &series.points {
+            grid[get_row(*y)][get_column(*x)] = series.marker;
+        }
+    }
+
+    let mut lines: Vec<String> = grid
+        .into_iter()
+        .enumerate()
+        .map(|(row, chars)| {
+            let label = match row {
+                0 => labels[0].as_str(),
+                row if row == height - 1 => labels[2].as_str(),
+                row if row == (height - 1) / 2 => labels[1].as_str(),
+                _ => "",
+            };
+            format!(
+                "{:>width$} │{}",
+                label,
+                chars.into_iter().collect::<String>(),
+                width = label_width
+            )
+        })
+        .collect();
+
+    lines.push(format!(
+        "{} └{}",
+        " ".repeat(label_width),
+        "─".repeat(plot_width)
+    ));
+
+    let start_label = format_x(min_x);
+    let end_label = if max_x > min_x {
+        format_x(max_x)
+    } else {
+        String::new()
+    };
+    lines.push(format!(
+        "{}  {}{:>width$}",
+        " ".repeat(label_width),
+        start_label,
+        end_label,
+        width = plot_width.saturating_sub(start_label.chars().count())
+    ));
+
+    lines
+}
+
+/// Draws horizontal bars, which are scaled to the largest value, with values and shares
+/// of the total. Non-positive values are drawn without bars.
+pub fn draw_bar_chart(bars: &[(String, f64)], width: usize) -> Vec<String> {
+    if bars.is_empty() {
+        return vec![t(NOT_ENOUGH_DATA)];
+    }
+
+    let total = bars
+        .iter()
+        .fold(0f64, |sum, (_, value)| sum + value.max(0f64));
+    let max_value = bars.iter().fold(0f64, |max, (_, value)| max.max(*value));
+
+    let values: Vec<String> = bars
+        .iter()
+        .map(|(_, value)| {
+            let share = if total > 0f64 {
+                value.max(0f64) / total * 100f64
+            } else {
+                0f64
+            };
+            format!("{} ({}%)", format_value(*value), round::round(share, 1))
+        })
+        .collect();
+    let label_width = bars
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let value_width = values
+        .iter()
+        .map(|value| value.chars().count())
+        .max()
+        .unwrap_or(0);
+    let bar_width = width
+        .saturating_sub(label_width + value_width + 4)
+        .max(MIN_PLOT_WIDTH);
+
+    bars.iter()
+        .zip(values)
+        .map(|((label, value), value_text)| {
+            let length = if *value > 0f64 && max_value > 0f64 {
+                ((value / max_value * bar_width as f64).round() as usize).max(1)
+            } else {
+                0
+            };
+            format!(
+                "{:<label_width$} │{:<bar_width$} {}",
+                label,
+                BAR_CHAR.to_string().repeat(length),
+                value_text,
+                label_width = label_width,
+                bar_width = bar_width
+            )
+        })
+        .collect()
+}
+
+fn get_range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+        (min.min(value), max.max(value))
+    })
+}
+
+fn format_value(value: f64) -> String {
+    // Avoids "-0" label for values rounded to zero
+    (round::round(value, 2) + 0f64).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{draw_bar_chart, draw_line_chart, ChartSeries, NOT_ENOUGH_DATA};
+    use crate::i18n::t;
+
+    #[test]
+    fn test_line_chart() {
+        let series = [
+            ChartSeries {
+                points: vec![(0f64, 10f64), (10f64, 20f64)],
+                marker: '-',
+                is_step_line: true,
+            },
+            ChartSeries {
+                points: vec![(0f64, 10f64), (10f64, 30f64)],
+                marker: 'B',
+                is_step_line: false,
+            },
+        ];
+
+        let lines = draw_line_chart(&series, 14, 3, |x| x.to_string());
+        assert_eq!(
+            lines,
+            vec![
+                "30 │         B",
+                "20 │         -",
+                "10 │B---------",
+                "   └──────────",
+                "    0       10",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_chart_few_points() {
+        assert_eq!(
+            draw_line_chart(&[], 40, 5, |x| x.to_string()),
+            vec![t(NOT_ENOUGH_DATA)]
+        );
+
+        // Single point is drawn in the middle of the chart without end label
+        let series = [ChartSeries {
+            points: vec![(5f64, 100f64)],
+            marker: '*',
+            is_step_line: false,
+        }];
+        let lines = draw_line_chart(&series, 10, 3, |x| x.to_string());
+        assert_eq!(lines[0], "110 │          ");
+        assert_eq!(lines[1], "100 │     *    ");
+        assert_eq!(lines[2], " 90 │          ");
+        assert_eq!(lines[4].trim(), "5");
+    }
+
+    #[test]
+    fn test_bar_chart() {
+        let bars = vec![(String::from("BTC"), 300f64), (String::from("ETH"), 100f64)];
+        assert_eq!(
+            draw_bar_chart(&bars, 30),
+            vec![
+                "BTC │██████████████ 300 (75%)",
+                "ETH │█████          100 (25%)",
+            ]
+        );
+        assert_eq!(draw_bar_chart(&[], 30), vec![t(NOT_ENOUGH_DATA)]);
+    }
+}
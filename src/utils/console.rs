@@ -1,7 +1,13 @@
-use std::str::FromStr;
+use std::fmt;
 
 use colored::Colorize;
 
+use super::line_editor::{read_line, Completions};
+use super::locale::{parse_localized, LocalizedInput};
+use super::theme::{is_plain_output, Paint, Role};
+use crate::i18n::t;
+use crate::tf;
+
 #[derive(PartialEq, Eq)]
 pub enum ConfirmationStatus {
     Confirmed,
@@ -10,19 +16,61 @@ pub enum ConfirmationStatus {
 
 pub fn ask_for_input<T>(question: &str) -> Result<T, String>
 where
-    T: FromStr,
+    T: LocalizedInput,
+{
+    ask_for_choice(question, &[])
+}
+
+// Same as ask_for_input, but completes the answer with one of the choices
+pub fn ask_for_choice<T>(question: &str, choices: &[&str]) -> Result<T, String>
+where
+    T: LocalizedInput,
 {
     println!("{}", question);
 
-    let mut input = String::new();
-    let input_result = std::io::stdin().read_line(&mut input);
-    if input_result.is_err() {
-        return Err(String::from("Failed to read input from console"));
+    let input = match read_line(Completions::new(choices), false)? {
+        Some(value) => value,
+        None => return Err(t("error.read_input")),
+    };
+
+    match parse_localized::<T>(input.trim()) {
+        Some(value) => Ok(value),
+        None => Err(tf!("error.parse_answer", input.trim())),
     }
+}
+
+// Asks question again until answer is parsed and passes validation. Empty answer cancels input.
+pub fn ask_for_valid_input<T, E, F>(question: &str, validate: F) -> Result<T, String>
+where
+    T: LocalizedInput,
+    E: fmt::Display,
+    F: Fn(&T) -> Result<(), E>,
+{
+    println!("{}", question);
+
+    loop {
+        let input = match read_line(Completions::default(), false)? {
+            Some(value) => value,
+            None => return Err(t("error.read_input")),
+        };
+
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(t("error.input_cancelled"));
+        }
 
-    match input.trim().parse::<T>() {
-        Ok(value) => Ok(value),
-        Err(_) => Err(format!("Failed to parse answer '{}'", input.trim())),
+        let value = match parse_localized::<T>(input) {
+            Some(value) => value,
+            None => {
+                print_error(tf!("error.parse_answer_retry", input));
+                continue;
+            }
+        };
+
+        match validate(&value) {
+            Ok(_) => return Ok(value),
+            Err(error) => print_error(tf!("error.invalid_value_retry", error)),
+        }
     }
 }
 
@@ -32,38 +80,50 @@ pub fn ask_confirmation(
 ) -> Result<ConfirmationStatus, String> {
     println!("{}", question);
 
-    let mut input = String::new();
-    if let Err(_) = std::io::stdin().read_line(&mut input) {
-        return Err(String::from("Failed to read input from console"));
-    }
+    // End of input is an empty answer, so default is used
+    let input = read_line(Completions::new(&["y", "n"]), false)?.unwrap_or_default();
 
     match input.trim().to_lowercase().as_str() {
         "y" => Ok(ConfirmationStatus::Confirmed),
         "n" => Ok(ConfirmationStatus::Rejected),
         "" => Ok(default),
-        _ => Err(format!("Unsupported confirmation answer {}", input.trim())),
+        _ => Err(tf!("error.unsupported_confirmation", input.trim())),
     }
 }
 
 pub fn wait_for_enter() -> Result<(), String> {
-    println!("{}", "Press enter to continue...".italic().bright_black());
-    let mut s = String::new();
-    if let Err(_) = std::io::stdin().read_line(&mut s) {
-        return Err(String::from("Failed to read input from console"));
-    }
-
+    println!("{}", t("info.press_enter").italic().paint(Role::Muted));
+    read_line(Completions::default(), false)?;
     Ok(())
 }
 
+// Screen is kept in plain mode, so previous output stays in logs
 pub fn clear_screen() -> std::io::Result<()> {
+    if is_plain_output() {
+        return Ok(());
+    }
+
     let term = console::Term::stdout();
     term.clear_screen()
 }
 
+// Default width is used if output is not a terminal
+pub fn get_terminal_width() -> usize {
+    console::Term::stdout().size().1 as usize
+}
+
+pub fn print_warning(warning: &str) {
+    println!(
+        "{}{}",
+        t("info.warning_prefix").bold().paint(Role::Accent),
+        warning.paint(Role::Accent)
+    );
+}
+
 pub fn print_error(error: String) {
     println!(
         "{}{}",
-        "ERROR: ".bold().white().on_red(),
-        error.bold().white().on_red()
+        t("info.error_prefix").bold().paint(Role::Alert),
+        error.bold().paint(Role::Alert)
     );
 }
@@ -0,0 +1,137 @@
+use super::math::round;
+use crate::options::model::Precision;
+
+/// Formats number by precision rule, optionally separating thousands of integer part
+pub fn format_number(number: f64, precision: Precision, thousands_separator: bool) -> String {
+    let text = match precision {
+        Precision::Auto => round(number).unwrap_or(number).to_string(),
+        Precision::Decimals(decimals) => format_decimals(number, decimals as i32),
+        Precision::Significant(digits) => {
+            if number == 0f64 {
+                return String::from("0");
+            }
+
+            let magnitude = number.abs().log10().floor() as i32;
+            format_decimals(number, digits.max(1) as i32 - 1 - magnitude)
+        }
+    };
+
+    if thousands_separator {
+        separate_thousands(&text)
+    } else {
+        text
+    }
+}
+
+/// Puts currency symbol after the sign, e.g. "-$5"
+pub fn add_currency_symbol(text: &str, symbol: &str) -> String {
+    match text.strip_prefix('-') {
+        Some(number) => format!("-{}{}", symbol, number),
+        None => format!("{}{}", symbol, text),
+    }
+}
+
+/// Quotes CSV field, if it contains separator, quote or line break
+pub fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Negative decimals round integer part, e.g. -2 rounds to hundreds
+fn format_decimals(number: f64, decimals: i32) -> String {
+    let rounded = if decimals >= 0 {
+        round::round(number, decimals)
+    } else {
+        let factor = 10f64.powi(-decimals);
+        (number / factor).round() * factor
+    };
+
+    // Adding zero avoids "-0" for small negative numbers
+    format!("{:.*}", decimals.max(0) as usize, rounded + 0f64)
+}
+
+fn separate_thousands(text: &str) -> String {
+    let (sign, number) = match text.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", text),
+    };
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    let mut separated = String::new();
+    integer.chars().enumerate().for_each(|(index, digit)| {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            separated.push(',');
+        }
+        separated.push(digit);
+    });
+
+    match fraction {
+        Some(fraction) => format!("{}{}.{}", sign, separated, fraction),
+        None => format!("{}{}", sign, separated),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_currency_symbol, escape_csv, format_number};
+    use crate::options::model::Precision;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(12345.678, Precision::Auto, false), "12346");
+        assert_eq!(
+            format_number(0.001234567, Precision::Auto, false),
+            "0.001235"
+        );
+        assert_eq!(
+            format_number(12345.678, Precision::Decimals(2), true),
+            "12,345.68"
+        );
+        assert_eq!(format_number(-0.001, Precision::Decimals(2), false), "0.00");
+        assert_eq!(
+            format_number(1234567f64, Precision::Decimals(0), true),
+            "1,234,567"
+        );
+        assert_eq!(format_number(-999f64, Precision::Decimals(0), true), "-999");
+        assert_eq!(
+            format_number(-1234.5, Precision::Decimals(1), true),
+            "-1,234.5"
+        );
+
+        assert_eq!(
+            format_number(12345.678, Precision::Significant(3), false),
+            "12300"
+        );
+        assert_eq!(
+            format_number(0.00123456, Precision::Significant(2), false),
+            "0.0012"
+        );
+        assert_eq!(
+            format_number(1.5, Precision::Significant(4), false),
+            "1.500"
+        );
+        assert_eq!(format_number(0f64, Precision::Significant(3), false), "0");
+    }
+
+    #[test]
+    fn test_add_currency_symbol() {
+        assert_eq!(add_currency_symbol("1,000", "$"), "$1,000");
+        assert_eq!(add_currency_symbol("-5.5", "$"), "-$5.5");
+        assert_eq!(add_currency_symbol("5", ""), "5");
+    }
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("BTC"), "BTC");
+        assert_eq!(escape_csv("BTC, ETH"), "\"BTC, ETH\"");
+        assert_eq!(escape_csv("5\" disk"), "\"5\"\" disk\"");
+        assert_eq!(escape_csv("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(escape_csv("line\rbreak"), "\"line\rbreak\"");
+    }
+}
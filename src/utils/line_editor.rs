@@ -0,0 +1,222 @@
+use std::cell::RefCell;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::FileHistory;
+use rustyline::validate::Validator;
+use rustyline::{CompletionType, Config, Context, Editor, Helper};
+
+use crate::i18n::t;
+use crate::tf;
+
+use crate::options::get_options;
+
+const HISTORY_FILE_NAME: &str = "history.txt";
+const MAX_HISTORY_SIZE: usize = 1000;
+
+/// Values offered by tab completion. Commands complete the first word of the line,
+/// arguments complete the second word after one of the listed commands.
+#[derive(Clone, Default)]
+pub struct Completions {
+    pub commands: Vec<String>,
+    pub arguments: Vec<(Vec<&'static str>, Vec<Pair>)>,
+}
+
+impl Completions {
+    pub fn new(commands: &[&str]) -> Completions {
+        Completions {
+            commands: commands.iter().map(|command| command.to_string()).collect(),
+            arguments: vec![],
+        }
+    }
+
+    pub fn with_arguments(mut self, commands: Vec<&'static str>, arguments: Vec<Pair>) -> Self {
+        self.arguments.push((commands, arguments));
+        self
+    }
+
+    // Arguments are matched by the start of any word of their displayed text
+    pub fn complete(&self, line: &str) -> (usize, Vec<Pair>) {
+        let words: Vec<&str> = line.split(' ').collect();
+        match words.as_slice() {
+            [word] => (0, find_candidates(word, &self.commands)),
+            [command, word] => {
+                let start = line.len() - word.len();
+                let candidates = self
+                    .arguments
+                    .iter()
+                    .filter(|(commands, _)| commands.contains(command))
+                    .flat_map(|(_, arguments)| arguments)
+                    .filter(|pair| {
+                        let word = word.to_lowercase();
+                        pair.display
+                            .to_lowercase()
+                            .split([' ', '(', ')'])
+                            .any(|part| part.starts_with(&word))
+                    })
+                    .cloned()
+                    .collect();
+                (start, candidates)
+            }
+            _ => (line.len(), vec![]),
+        }
+    }
+}
+
+pub fn get_argument_pair(replacement: String, description: &str) -> Pair {
+    Pair {
+        display: format!("{} ({})", replacement, description),
+        replacement,
+    }
+}
+
+fn find_candidates(word: &str, values: &[String]) -> Vec<Pair> {
+    values
+        .iter()
+        .filter(|value| value.starts_with(&word.to_lowercase()))
+        .map(|value| Pair {
+            display: value.clone(),
+            replacement: value.clone(),
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct InputHelper {
+    completions: Completions,
+}
+
+impl Completer for InputHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        Ok(self.completions.complete(&line[..pos]))
+    }
+}
+
+impl Hinter for InputHelper {
+    type Hint = String;
+}
+
+impl Highlighter for InputHelper {}
+
+impl Validator for InputHelper {}
+
+impl Helper for InputHelper {}
+
+type LineEditor = Editor<InputHelper, FileHistory>;
+
+thread_local! {
+    // Editor is created on first use, None means that input is not a terminal
+    static EDITOR: RefCell<Option<Option<LineEditor>>> = const { RefCell::new(None) };
+}
+
+// History is kept in the same directory as storage file
+fn get_history_path() -> PathBuf {
+    PathBuf::from(get_options().storage_file_path).with_file_name(HISTORY_FILE_NAME)
+}
+
+fn create_editor() -> Option<LineEditor> {
+    if !std::io::stdin().is_terminal() {
+        return None;
+    }
+
+    let config = Config::builder()
+        .max_history_size(MAX_HISTORY_SIZE)
+        .ok()?
+        .auto_add_history(false)
+        .completion_type(CompletionType::List)
+        .build();
+    let mut editor = LineEditor::with_config(config).ok()?;
+    editor.set_helper(Some(InputHelper::default()));
+    // History file doesn't exist before the first command
+    let _ = editor.load_history(&get_history_path());
+
+    Some(editor)
+}
+
+/// Reads line with editing and tab completion. Piped input is read without them.
+/// Returns None at the end of input.
+pub fn read_line(completions: Completions, add_to_history: bool) -> Result<Option<String>, String> {
+    EDITOR.with(|cell| {
+        let mut editor = cell.borrow_mut();
+        let editor = editor.get_or_insert_with(create_editor);
+
+        let editor = match editor {
+            Some(editor) => editor,
+            None => return read_piped_line(),
+        };
+
+        if let Some(helper) = editor.helper_mut() {
+            helper.completions = completions;
+        }
+
+        match editor.readline("") {
+            Ok(line) => {
+                if add_to_history && !line.trim().is_empty() {
+                    editor
+                        .add_history_entry(line.trim())
+                        .map_err(|error| tf!("error.add_history", error))?;
+                    editor
+                        .save_history(&get_history_path())
+                        .map_err(|error| tf!("error.save_history", error))?;
+                }
+                Ok(Some(line))
+            }
+            // Ctrl+C clears the line instead of closing the app
+            Err(ReadlineError::Interrupted) => Ok(Some(String::new())),
+            Err(ReadlineError::Eof) => Ok(None),
+            Err(error) => Err(tf!("error.read_input_with_reason", error)),
+        }
+    })
+}
+
+fn read_piped_line() -> Result<Option<String>, String> {
+    let mut input = String::new();
+    match std::io::stdin().read_line(&mut input) {
+        Ok(0) => Ok(None),
+        Ok(_) => Ok(Some(input)),
+        Err(_) => Err(t("error.read_input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_argument_pair, Completions};
+
+    #[test]
+    fn test_completions() {
+        let completions = Completions::new(&["a", "alloc", "d", "e"]).with_arguments(
+            vec!["d", "e"],
+            vec![
+                get_argument_pair(String::from("0"), "BTC"),
+                get_argument_pair(String::from("12"), "ETH"),
+            ],
+        );
+
+        let replacements = |line: &str| {
+            let (start, pairs) = completions.complete(line);
+            let values: Vec<String> = pairs.into_iter().map(|pair| pair.replacement).collect();
+            (start, values)
+        };
+
+        assert_eq!(
+            replacements("a"),
+            (0, vec![String::from("a"), String::from("alloc")])
+        );
+        assert_eq!(replacements("e 1"), (2, vec![String::from("12")]));
+        assert_eq!(replacements("d bt"), (2, vec![String::from("0")]));
+        assert_eq!(replacements("d 2"), (2, vec![]));
+        assert_eq!(replacements("a "), (2, vec![]));
+        assert_eq!(replacements("e 0 x"), (5, vec![]));
+    }
+}
@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Local, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::options::get_options;
+
+/// Separators of numbers, format of dates and first day of week
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en-GB")]
+    EnGb,
+    #[serde(rename = "en-US")]
+    EnUs,
+    #[serde(rename = "de-DE")]
+    DeDe,
+    #[serde(rename = "fr-FR")]
+    FrFr,
+    #[serde(rename = "ru-RU")]
+    RuRu,
+}
+
+impl Locale {
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Self::EnGb | Self::EnUs => '.',
+            Self::DeDe | Self::FrFr | Self::RuRu => ',',
+        }
+    }
+
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Self::EnGb | Self::EnUs => ',',
+            Self::DeDe => '.',
+            // Narrow no-break space keeps grouped number a single command argument
+            Self::FrFr | Self::RuRu => '\u{202f}',
+        }
+    }
+
+    pub fn date_format(&self) -> &'static str {
+        match self {
+            Self::EnGb | Self::FrFr => "%d/%m/%Y",
+            Self::EnUs => "%m/%d/%Y",
+            Self::DeDe | Self::RuRu => "%d.%m.%Y",
+        }
+    }
+
+    pub fn time_format(&self) -> &'static str {
+        match self {
+            Self::EnUs => "%I:%M %p",
+            _ => "%H:%M",
+        }
+    }
+
+    pub fn first_weekday(&self) -> Weekday {
+        match self {
+            Self::EnUs => Weekday::Sun,
+            _ => Weekday::Mon,
+        }
+    }
+
+    pub fn format_date(&self, date: &DateTime<Local>) -> String {
+        date.format(self.date_format()).to_string()
+    }
+
+    pub fn format_date_time(&self, date: &DateTime<Local>) -> String {
+        date.format(&format!("{} {}", self.date_format(), self.time_format()))
+            .to_string()
+    }
+
+    /// Replaces separators of number formatted as "1,234.5" with the ones of the locale
+    pub fn localize_number(&self, text: &str) -> String {
+        text.chars()
+            .map(|char| match char {
+                '.' => self.decimal_separator(),
+                ',' => self.thousands_separator(),
+                char => char,
+            })
+            .collect()
+    }
+
+    /// Converts number entered with separators of the locale to the format parsed by Rust,
+    /// e.g. "1.234,5" to "1234.5" in German. Other input is returned as is.
+    pub fn normalize_number(&self, input: &str) -> String {
+        let input = input.trim();
+        let (sign, number) = match input.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", input),
+        };
+        let (integer, fraction) = match number.split_once(self.decimal_separator()) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+
+        // Thousands separators are allowed only between groups of 3 digits
+        let groups: Vec<&str> = integer.split(self.thousands_separator()).collect();
+        let is_digits = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+        let are_groups_valid = groups.len() == 1
+            || (groups[0].len() <= 3 && groups[1..].iter().all(|group| group.len() == 3));
+        if !groups.iter().all(|group| is_digits(group))
+            || !are_groups_valid
+            || !fraction.is_none_or(is_digits)
+        {
+            return input.to_string();
+        }
+
+        match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, groups.concat(), fraction),
+            None => format!("{}{}", sign, groups.concat()),
+        }
+    }
+}
+
+/// Value entered by user. Only numbers are entered with separators of the locale, so text
+/// like tags "100,200" is kept as is.
+pub trait LocalizedInput: Sized {
+    fn parse_input(input: &str, locale: Locale) -> Option<Self>;
+}
+
+impl LocalizedInput for String {
+    fn parse_input(input: &str, _locale: Locale) -> Option<Self> {
+        Some(input.to_string())
+    }
+}
+
+macro_rules! impl_localized_number {
+    ($($number:ty),*) => {
+        $(
+            impl LocalizedInput for $number {
+                fn parse_input(input: &str, locale: Locale) -> Option<Self> {
+                    parse_number(input, locale)
+                }
+            }
+        )*
+    };
+}
+
+impl_localized_number!(f64, i32, usize);
+
+fn parse_number<T>(input: &str, locale: Locale) -> Option<T>
+where
+    T: FromStr,
+{
+    locale
+        .normalize_number(input)
+        .parse::<T>()
+        .or_else(|_| input.parse::<T>())
+        .ok()
+}
+
+/// Parses input, accepting numbers with separators of the locale set in options
+pub fn parse_localized<T>(input: &str) -> Option<T>
+where
+    T: LocalizedInput,
+{
+    T::parse_input(input, get_options().locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone, Weekday};
+
+    use super::{Locale, LocalizedInput};
+    use crate::options::model::Precision;
+    use crate::utils::format::format_number;
+
+    #[test]
+    fn test_round_trip_numbers() {
+        let numbers = [0f64, 0.5, -0.25, 1234.5, -1234567.125, 1000000f64];
+        let locales = [
+            Locale::EnGb,
+            Locale::EnUs,
+            Locale::DeDe,
+            Locale::FrFr,
+            Locale::RuRu,
+        ];
+
+        for locale in locales {
+            for number in numbers {
+                for thousands_separator in [false, true] {
+                    let text = format_number(number, Precision::Decimals(3), thousands_separator);
+                    let localized = locale.localize_number(&text);
+                    let parsed = locale.normalize_number(&localized).parse::<f64>();
+                    assert_eq!(parsed, Ok(number), "{:?} '{}'", locale, localized);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize_number() {
+        assert_eq!(Locale::DeDe.normalize_number("0,5"), "0.5");
+        assert_eq!(Locale::DeDe.normalize_number("1.234,5"), "1234.5");
+        assert_eq!(Locale::FrFr.normalize_number("-1\u{202f}234,5"), "-1234.5");
+        assert_eq!(Locale::EnGb.normalize_number("1,234.5"), "1234.5");
+
+        // Input, which is not a number of the locale, is kept as is
+        assert_eq!(Locale::DeDe.normalize_number("0.5"), "0.5");
+        assert_eq!(Locale::EnGb.normalize_number("1,2"), "1,2");
+        assert_eq!(
+            Locale::EnGb.normalize_number("crypto,stocks"),
+            "crypto,stocks"
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(f64::parse_input("1.500", Locale::DeDe), Some(1500f64));
+        assert_eq!(i32::parse_input("1,500", Locale::EnGb), Some(1500));
+        assert_eq!(
+            String::parse_input("100,200", Locale::EnGb).as_deref(),
+            Some("100,200")
+        );
+        assert_eq!(
+            String::parse_input("1.500", Locale::DeDe).as_deref(),
+            Some("1.500")
+        );
+    }
+
+    #[test]
+    fn test_format_dates() {
+        let date = Local.with_ymd_and_hms(2026, 3, 7, 15, 30, 0).unwrap();
+        assert_eq!(Locale::EnGb.format_date_time(&date), "07/03/2026 15:30");
+        assert_eq!(Locale::EnUs.format_date_time(&date), "03/07/2026 03:30 PM");
+        assert_eq!(Locale::DeDe.format_date(&date), "07.03.2026");
+        assert_eq!(Locale::EnUs.first_weekday(), Weekday::Sun);
+        assert_eq!(Locale::RuRu.first_weekday(), Weekday::Mon);
+    }
+}
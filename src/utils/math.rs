@@ -1,3 +1,5 @@
+use chrono::{DateTime, Local};
+
 pub fn round(number: f64) -> Result<f64, String> {
     let num_str = number.to_string();
     let num_parts: Vec<&str> = num_str.split(".").collect();
@@ -36,3 +38,88 @@ pub fn round(number: f64) -> Result<f64, String> {
 
     Ok(round::round(number, zeros_count + 4))
 }
+
+const DAYS_IN_YEAR: f64 = 365.25;
+
+pub fn years_between(from: DateTime<Local>, to: DateTime<Local>) -> f64 {
+    (to - from).num_seconds() as f64 / (DAYS_IN_YEAR * 24f64 * 3600f64)
+}
+
+/// Converts return over the given period to yearly return. Both returns are fractions.
+pub fn annualize_return(total_return: f64, years: f64) -> Option<f64> {
+    if years <= 0f64 || total_return <= -1f64 {
+        return None;
+    }
+
+    Some((1f64 + total_return).powf(1f64 / years) - 1f64)
+}
+
+/// Finds yearly rate, which makes net present value of cash flows zero.
+/// Returns None if cash flows don't have both investments and returns or rate is not found.
+pub fn calculate_xirr(cash_flows: &[(DateTime<Local>, f64)]) -> Option<f64> {
+    let has_investments = cash_flows.iter().any(|(_, amount)| *amount < 0f64);
+    let has_returns = cash_flows.iter().any(|(_, amount)| *amount > 0f64);
+    if !has_investments || !has_returns {
+        return None;
+    }
+
+    let start = cash_flows.iter().map(|(date, _)| *date).min()?;
+    let flows: Vec<(f64, f64)> = cash_flows
+        .iter()
+        .map(|(date, amount)| (years_between(start, *date), *amount))
+        .collect();
+
+    let npv = |rate: f64| -> f64 {
+        flows
+            .iter()
+            .map(|(years, amount)| amount / (1f64 + rate).powf(*years))
+            .sum()
+    };
+
+    // Npv falls as rate grows, so root is searched by bisection
+    let (mut low, mut high) = (-0.9999f64, 1f64);
+    while npv(high) > 0f64 {
+        high *= 2f64;
+        if high > 1e9 {
+            return None;
+        }
+    }
+    if npv(low) < 0f64 {
+        return None;
+    }
+
+    for _ in 0..200 {
+        let middle = (low + high) / 2f64;
+        if npv(middle) > 0f64 {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Some((low + high) / 2f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local};
+
+    use super::{annualize_return, calculate_xirr};
+
+    #[test]
+    fn test_calculate_xirr() {
+        let start = Local::now();
+        let year_later = start + Duration::seconds((365.25 * 24f64 * 3600f64) as i64);
+
+        let rate = calculate_xirr(&[(start, -100f64), (year_later, 110f64)]).unwrap();
+        assert!((rate - 0.1).abs() < 1e-6);
+
+        let rate = calculate_xirr(&[(start, -100f64), (year_later, 50f64)]).unwrap();
+        assert!((rate + 0.5).abs() < 1e-6);
+
+        assert_eq!(calculate_xirr(&[(start, -100f64)]), None);
+
+        let two_years = annualize_return(0.21, 2f64).unwrap();
+        assert!((two_years - 0.1).abs() < 1e-9);
+    }
+}
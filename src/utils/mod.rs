@@ -1,3 +1,8 @@
+pub mod chart;
 pub mod console;
+pub mod format;
+pub mod line_editor;
+pub mod locale;
 pub mod math;
 pub mod pagination;
+pub mod theme;
@@ -1,5 +1,8 @@
 use colored::Colorize;
 
+use super::theme::{Paint, Role};
+use crate::i18n::t;
+
 pub fn select_items_for_page<T>(mut items: Vec<T>, page: i32, items_per_page: i32) -> Vec<T>
 where
     T: Clone,
@@ -22,13 +25,13 @@ where
 }
 
 pub fn draw_page_counter(current_page: i32, pages_count: f64) {
-    print!("Page ");
+    print!("{}", t("info.page"));
 
     println!(
         "{}{}{}",
-        current_page.to_string().bold().black().on_white(),
-        "/".black().on_white(),
-        pages_count.to_string().bold().black().on_white()
+        current_page.to_string().bold().paint(Role::Inverted),
+        "/".paint(Role::Inverted),
+        pages_count.to_string().bold().paint(Role::Inverted)
     );
 }
 pub fn get_pages_count(items_length: usize, items_per_page: i32) -> f64 {
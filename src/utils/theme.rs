@@ -0,0 +1,165 @@
+use std::cell::Cell;
+use std::io::IsTerminal;
+
+use colored::{Color, ColoredString, Colorize};
+use prettytable::color as term_color;
+use serde::{Deserialize, Serialize};
+
+use crate::options::model::Options;
+
+// Plain output can be enabled for a single run without changing options
+const PLAIN_FLAG: &str = "--plain";
+
+/// Colors of the interface. Monochrome keeps only bold and italic text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    Monochrome,
+}
+
+/// Meaning of colored text, which is mapped to colors by theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Positive,
+    Negative,
+    // Commands, entered values and current settings
+    Accent,
+    // Labels and hints
+    Muted,
+    // Errors and triggered stop loss
+    Alert,
+    // Triggered take profit
+    Success,
+    // Page counter
+    Inverted,
+}
+
+impl Theme {
+    // Foreground and background colors of the role
+    fn colors(&self, role: Role) -> (Option<Color>, Option<Color>) {
+        match (self, role) {
+            (Self::Monochrome, _) => (None, None),
+            (_, Role::Positive) => (Some(Color::Green), None),
+            (_, Role::Negative) => (Some(Color::Red), None),
+            (Self::Dark, Role::Accent) => (Some(Color::Yellow), None),
+            (Self::Light, Role::Accent) => (Some(Color::Blue), None),
+            (_, Role::Muted) => (Some(Color::BrightBlack), None),
+            (_, Role::Alert) => (Some(Color::White), Some(Color::Red)),
+            (_, Role::Success) => (Some(Color::White), Some(Color::Green)),
+            (Self::Dark, Role::Inverted) => (Some(Color::Black), Some(Color::White)),
+            (Self::Light, Role::Inverted) => (Some(Color::White), Some(Color::Black)),
+        }
+    }
+}
+
+thread_local! {
+    // Monochrome is kept here, when colors are disabled by environment
+    static THEME: Cell<Theme> = const { Cell::new(Theme::Dark) };
+    static PLAIN: Cell<bool> = const { Cell::new(false) };
+    static STYLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Applies theme and plain mode from options. Styles are disabled in plain mode,
+/// when `NO_COLOR` is set or output is not a terminal.
+pub fn configure_output(options: &Options) {
+    let is_plain = options.plain_output || std::env::args().any(|arg| arg == PLAIN_FLAG);
+    let is_color_disabled = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+        || !std::io::stdout().is_terminal();
+    let is_styled = !is_plain && !is_color_disabled;
+    let theme = if is_styled {
+        options.theme
+    } else {
+        Theme::Monochrome
+    };
+
+    THEME.with(|current| current.set(theme));
+    PLAIN.with(|current| current.set(is_plain));
+    STYLED.with(|current| current.set(is_styled));
+    colored::control::set_override(is_styled);
+}
+
+/// Plain mode doesn't clear screen and draws tables without borders
+pub fn is_plain_output() -> bool {
+    PLAIN.with(|current| current.get())
+}
+
+/// Whether escape sequences of colors and styles can be printed
+pub fn is_styled_output() -> bool {
+    STYLED.with(|current| current.get())
+}
+
+fn current_theme() -> Theme {
+    THEME.with(|current| current.get())
+}
+
+/// Colors text by its role in the current theme, keeping bold and italic styles
+pub trait Paint {
+    fn paint(self, role: Role) -> ColoredString;
+}
+
+impl Paint for ColoredString {
+    fn paint(self, role: Role) -> ColoredString {
+        let (foreground, background) = current_theme().colors(role);
+        let text = match foreground {
+            Some(color) => self.color(color),
+            None => self,
+        };
+
+        match background {
+            Some(color) => text.on_color(color),
+            None => text,
+        }
+    }
+}
+
+impl Paint for &str {
+    fn paint(self, role: Role) -> ColoredString {
+        ColoredString::from(self).paint(role)
+    }
+}
+
+/// Colors of the role for table cells
+pub fn cell_colors(role: Role) -> (Option<term_color::Color>, Option<term_color::Color>) {
+    let (foreground, background) = current_theme().colors(role);
+    (
+        foreground.and_then(to_term_color),
+        background.and_then(to_term_color),
+    )
+}
+
+fn to_term_color(color: Color) -> Option<term_color::Color> {
+    match color {
+        Color::Black => Some(term_color::BLACK),
+        Color::Red => Some(term_color::RED),
+        Color::Green => Some(term_color::GREEN),
+        Color::Yellow => Some(term_color::YELLOW),
+        Color::Blue => Some(term_color::BLUE),
+        Color::White => Some(term_color::WHITE),
+        Color::BrightBlack => Some(term_color::BRIGHT_BLACK),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use colored::Color;
+
+    use super::{Role, Theme};
+
+    #[test]
+    fn test_theme_colors() {
+        assert_eq!(
+            Theme::Dark.colors(Role::Accent),
+            (Some(Color::Yellow), None)
+        );
+        assert_eq!(Theme::Light.colors(Role::Accent), (Some(Color::Blue), None));
+        assert_eq!(
+            Theme::Light.colors(Role::Inverted),
+            (Some(Color::White), Some(Color::Black))
+        );
+        assert_eq!(Theme::Monochrome.colors(Role::Alert), (None, None));
+    }
+}